@@ -0,0 +1,185 @@
+// =============================================================================
+// apca.rs - Contraste de luminosité APCA (brouillon WCAG 3)
+// apca.rs - APCA lightness contrast (WCAG 3 draft)
+// =============================================================================
+//
+// Implémentation de l'algorithme APCA-W3 0.0.98G-4g (SAPC).
+// Implementation of the APCA-W3 0.0.98G-4g (SAPC) algorithm.
+// Reference: https://github.com/Myndex/apca-w3
+
+use serde::{Deserialize, Serialize};
+
+// =============================================================================
+// CONSTANTES APCA-W3 0.0.98G-4g
+// APCA-W3 0.0.98G-4g CONSTANTS
+// =============================================================================
+
+/// Exposant de la courbe de transfert simplifiée
+/// Exponent of the simplified transfer curve
+const MAIN_TRC: f64 = 2.4;
+
+/// Coefficients de luminance sRGB
+/// sRGB luminance coefficients
+const S_R_CO: f64 = 0.212_672_9;
+const S_G_CO: f64 = 0.715_152_2;
+const S_B_CO: f64 = 0.072_175_0;
+
+/// Exposants pour la polarité normale (texte sombre sur fond clair)
+/// Exponents for normal polarity (dark text on light background)
+const NORM_BG: f64 = 0.56;
+const NORM_TXT: f64 = 0.57;
+
+/// Exposants pour la polarité inversée (texte clair sur fond sombre)
+/// Exponents for reverse polarity (light text on dark background)
+const REV_TXT: f64 = 0.62;
+const REV_BG: f64 = 0.65;
+
+/// Seuil et exposant du soft clamp des noirs
+/// Black soft clamp threshold and exponent
+const BLK_THRS: f64 = 0.022;
+const BLK_CLMP: f64 = 1.414;
+
+/// Facteurs d'échelle et décalages de sortie
+/// Output scale factors and offsets
+const SCALE_BOW: f64 = 1.14;
+const SCALE_WOB: f64 = 1.14;
+const LO_BOW_OFFSET: f64 = 0.027;
+const LO_WOB_OFFSET: f64 = 0.027;
+
+/// Différence de luminance minimale et seuil de coupure bas
+/// Minimum luminance difference and low clip threshold
+const DELTA_Y_MIN: f64 = 0.0005;
+const LO_CLIP: f64 = 0.1;
+
+// =============================================================================
+// STRUCTURES
+// =============================================================================
+
+/// Polarité d'une paire texte / fond
+/// Polarity of a text / background pair
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Polarity {
+    /// Texte sombre sur fond clair (Lc positif)
+    /// Dark text on light background (positive Lc)
+    DarkOnLight,
+
+    /// Texte clair sur fond sombre (Lc négatif)
+    /// Light text on dark background (negative Lc)
+    LightOnDark,
+}
+
+// =============================================================================
+// FONCTIONS DE CALCUL
+// CALCULATION FUNCTIONS
+// =============================================================================
+
+/// Calcule la luminance estimée APCA (Ys) d'une couleur sRGB
+/// Calculates the APCA estimated screen luminance (Ys) of an sRGB color
+///
+/// # Arguments
+/// * `rgb` - Composantes sRGB (0.0 - 1.0, non bornées) / sRGB components (0.0 - 1.0, unclamped)
+pub fn screen_luminance(rgb: [f64; 3]) -> f64 {
+    // Conserve le signe pour les couleurs hors gamut sRGB
+    // Keep the sign for colors outside the sRGB gamut
+    let channel = |c: f64| c.signum() * c.abs().powf(MAIN_TRC);
+    S_R_CO * channel(rgb[0]) + S_G_CO * channel(rgb[1]) + S_B_CO * channel(rgb[2])
+}
+
+/// Calcule le contraste Lc APCA entre deux luminances
+/// Calculates the APCA Lc contrast between two luminances
+///
+/// # Arguments
+/// * `text_y` - Luminance du texte / Text luminance
+/// * `background_y` - Luminance du fond / Background luminance
+///
+/// # Returns
+/// Lc signé : positif pour texte sombre sur fond clair, négatif sinon
+/// Signed Lc: positive for dark text on light background, negative otherwise
+pub fn contrast_from_luminance(text_y: f64, background_y: f64) -> f64 {
+    // Soft clamp des noirs / Black soft clamp
+    let soft_clamp = |y: f64| if y > BLK_THRS { y } else { y + (BLK_THRS - y).powf(BLK_CLMP) };
+    let text_y = soft_clamp(text_y);
+    let background_y = soft_clamp(background_y);
+
+    // Couleurs quasi identiques : pas de contraste
+    // Nearly identical colors: no contrast
+    if (background_y - text_y).abs() < DELTA_Y_MIN {
+        return 0.0;
+    }
+
+    let output = if background_y > text_y {
+        // Polarité normale / Normal polarity
+        let sapc = (background_y.powf(NORM_BG) - text_y.powf(NORM_TXT)) * SCALE_BOW;
+        if sapc < LO_CLIP { 0.0 } else { sapc - LO_BOW_OFFSET }
+    } else {
+        // Polarité inversée / Reverse polarity
+        let sapc = (background_y.powf(REV_BG) - text_y.powf(REV_TXT)) * SCALE_WOB;
+        if sapc > -LO_CLIP { 0.0 } else { sapc + LO_WOB_OFFSET }
+    };
+
+    output * 100.0
+}
+
+/// Calcule le contraste Lc APCA d'un texte sur un fond
+/// Calculates the APCA Lc contrast of a text on a background
+///
+/// # Arguments
+/// * `text` - Couleur sRGB du texte / Text sRGB color
+/// * `background` - Couleur sRGB du fond / Background sRGB color
+pub fn contrast(text: [f64; 3], background: [f64; 3]) -> f64 {
+    contrast_from_luminance(screen_luminance(text), screen_luminance(background))
+}
+
+/// Détermine la polarité d'une paire texte / fond
+/// Determines the polarity of a text / background pair
+pub fn polarity(text: [f64; 3], background: [f64; 3]) -> Polarity {
+    if screen_luminance(text) > screen_luminance(background) {
+        Polarity::LightOnDark
+    } else {
+        Polarity::DarkOnLight
+    }
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conversion::rgb8_to_srgb;
+
+    fn contrast(text: (u8, u8, u8), background: (u8, u8, u8)) -> f64 {
+        super::contrast(rgb8_to_srgb(text), rgb8_to_srgb(background))
+    }
+
+    fn polarity(text: (u8, u8, u8), background: (u8, u8, u8)) -> Polarity {
+        super::polarity(rgb8_to_srgb(text), rgb8_to_srgb(background))
+    }
+
+    #[test]
+    fn test_black_on_white() {
+        // Valeur de référence APCA-W3 / APCA-W3 reference value
+        assert!((contrast((0, 0, 0), (255, 255, 255)) - 106.04).abs() < 0.01);
+        assert_eq!(polarity((0, 0, 0), (255, 255, 255)), Polarity::DarkOnLight);
+    }
+
+    #[test]
+    fn test_white_on_black() {
+        assert!((contrast((255, 255, 255), (0, 0, 0)) + 107.88).abs() < 0.01);
+        assert_eq!(polarity((255, 255, 255), (0, 0, 0)), Polarity::LightOnDark);
+    }
+
+    #[test]
+    fn test_reference_pairs() {
+        // #888 sur #FFF et #FFF sur #888 / #888 on #FFF and #FFF on #888
+        assert!((contrast((136, 136, 136), (255, 255, 255)) - 63.06).abs() < 0.01);
+        assert!((contrast((255, 255, 255), (136, 136, 136)) + 68.54).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_identical_colors() {
+        assert_eq!(contrast((120, 120, 120), (120, 120, 120)), 0.0);
+    }
+}
@@ -0,0 +1,224 @@
+// =============================================================================
+// apca_fonts.rs - Tailles de police minimales APCA
+// apca_fonts.rs - APCA minimum font sizes
+// =============================================================================
+//
+// Table de correspondance APCA (fontLookupAPCA, apca-w3 0.1.x) : pour chaque
+// niveau de Lc et chaque graisse de 100 à 900, la plus petite taille de police
+// (px CSS) utilisable. La ligne retenue est celle dont le Lc est inférieur ou
+// égal au Lc de la paire (pas d'interpolation, donc toujours prudente).
+// APCA lookup table (fontLookupAPCA, apca-w3 0.1.x): for each Lc level and each
+// weight from 100 to 900, the smallest usable font size (CSS px). The row used
+// is the one whose Lc is lower than or equal to the pair Lc (no interpolation,
+// so always conservative).
+// Reference: https://github.com/Myndex/apca-w3 (src/apca-w3.js, fontLookupAPCA)
+
+use serde::{Deserialize, Serialize};
+use crate::store::AppState;
+
+/// Valeur de la table : usage interdit / Table value: use prohibited
+const PROHIBITED: f64 = 999.0;
+
+/// Valeur de la table : éléments non textuels uniquement / Table value: non-text elements only
+const NON_TEXT_ONLY: f64 = 777.0;
+
+/// Graisses de la table / Table weights
+pub const WEIGHTS: [u16; 9] = [100, 200, 300, 400, 500, 600, 700, 800, 900];
+
+/// Lc minimal pour le texte courant (colonnes de texte) / Minimum Lc for body text (text columns)
+const BODY_TEXT_LC: f64 = 75.0;
+
+/// Lc minimal pour le texte ponctuel (texte d'aide, désactivé…) / Minimum Lc for spot text (placeholder, disabled…)
+const SPOT_TEXT_LC: f64 = 30.0;
+
+/// Lc minimal pour les éléments non textuels discernables / Minimum Lc for discernible non-text elements
+const NON_TEXT_LC: f64 = 15.0;
+
+/// Table APCA : Lc, puis taille minimale (px) pour les graisses 100 à 900
+/// APCA table: Lc, then minimum size (px) for weights 100 to 900
+const FONT_MATRIX: [[f64; 10]; 25] = [
+    [0.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0],
+    [10.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0],
+    [15.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0],
+    [20.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0],
+    [25.0, 777.0, 777.0, 777.0, 120.0, 120.0, 108.0, 96.0, 96.0, 96.0],
+    [30.0, 777.0, 777.0, 120.0, 108.0, 108.0, 96.0, 72.0, 72.0, 72.0],
+    [35.0, 777.0, 120.0, 108.0, 96.0, 72.0, 60.0, 48.0, 48.0, 48.0],
+    [40.0, 120.0, 108.0, 96.0, 60.0, 48.0, 42.0, 32.0, 32.0, 32.0],
+    [45.0, 108.0, 96.0, 72.0, 42.0, 32.0, 28.0, 24.0, 24.0, 24.0],
+    [50.0, 96.0, 72.0, 60.0, 32.0, 28.0, 24.0, 21.0, 21.0, 21.0],
+    [55.0, 80.0, 60.0, 48.0, 28.0, 24.0, 21.0, 18.0, 18.0, 18.0],
+    [60.0, 72.0, 48.0, 42.0, 24.0, 21.0, 18.0, 16.0, 16.0, 18.0],
+    [65.0, 68.0, 46.0, 32.0, 21.75, 19.0, 17.0, 15.0, 16.0, 18.0],
+    [70.0, 64.0, 44.0, 28.0, 19.5, 18.0, 16.0, 14.5, 16.0, 18.0],
+    [75.0, 60.0, 42.0, 24.0, 18.0, 16.0, 15.0, 14.0, 16.0, 18.0],
+    [80.0, 56.0, 38.25, 23.0, 17.25, 15.81, 14.81, 14.0, 16.0, 18.0],
+    [85.0, 52.0, 34.5, 22.0, 16.5, 15.625, 14.625, 14.0, 16.0, 18.0],
+    [90.0, 48.0, 32.0, 21.0, 16.0, 15.5, 14.5, 14.0, 16.0, 18.0],
+    [95.0, 45.0, 28.0, 19.5, 15.5, 15.0, 14.0, 13.5, 16.0, 18.0],
+    [100.0, 42.0, 26.5, 18.5, 15.0, 14.5, 13.5, 13.0, 16.0, 18.0],
+    [105.0, 39.0, 25.0, 18.0, 14.0, 14.0, 13.0, 12.0, 16.0, 18.0],
+    [110.0, 36.0, 24.0, 18.0, 14.0, 13.0, 12.0, 11.0, 16.0, 18.0],
+    [115.0, 34.5, 22.5, 17.25, 12.5, 11.875, 11.25, 10.625, 14.5, 16.5],
+    [120.0, 33.0, 21.0, 16.5, 11.0, 10.75, 10.5, 10.25, 13.0, 15.0],
+    [125.0, 32.0, 20.0, 16.0, 10.0, 10.0, 10.0, 10.0, 12.0, 14.0],
+];
+
+// =============================================================================
+// STRUCTURES
+// =============================================================================
+
+/// Taille minimale pour une graisse / Minimum size for a weight
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct WeightSize {
+    /// Graisse (100 - 900) / Weight (100 - 900)
+    pub weight: u16,
+
+    /// Taille minimale en px CSS, None si le texte n'est pas utilisable
+    /// Minimum size in CSS px, None if text is not usable
+    pub min_size_px: Option<f64>,
+
+    /// Si seuls les éléments non textuels sont possibles à cette graisse
+    /// If only non-text elements are possible at this weight
+    pub non_text_only: bool,
+}
+
+/// Usages permis par le contraste / Use cases allowed by the contrast
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+pub struct UseCases {
+    /// Texte courant (Lc 75 ou plus) / Body text (Lc 75 or more)
+    pub body_text: bool,
+
+    /// Texte ponctuel : légendes, texte désactivé… (Lc 30 ou plus)
+    /// Spot text: captions, disabled text… (Lc 30 or more)
+    pub spot_text: bool,
+
+    /// Éléments non textuels discernables (Lc 15 ou plus)
+    /// Discernible non-text elements (Lc 15 or more)
+    pub non_text: bool,
+}
+
+/// Recommandations de police pour une paire / Font recommendations for a pair
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+pub struct FontReport {
+    /// Valeur absolue du Lc utilisé / Absolute value of the Lc used
+    pub lc: f64,
+
+    /// Taille minimale par graisse / Minimum size per weight
+    pub sizes: Vec<WeightSize>,
+
+    /// Usages permis / Allowed use cases
+    pub use_cases: UseCases,
+}
+
+// =============================================================================
+// FONCTIONS DE CALCUL
+// CALCULATION FUNCTIONS
+// =============================================================================
+
+/// Cherche les tailles minimales pour une valeur de Lc (le signe est ignoré)
+/// Looks up the minimum sizes for an Lc value (the sign is ignored)
+pub fn lookup(lc: f64) -> FontReport {
+    let lc = lc.abs();
+
+    // Dernière ligne dont le Lc ne dépasse pas celui de la paire
+    // Last row whose Lc does not exceed the pair's
+    let row = FONT_MATRIX.iter().rev().find(|row| row[0] <= lc).unwrap_or(&FONT_MATRIX[0]);
+
+    let sizes = WEIGHTS
+        .iter()
+        .zip(&row[1..])
+        .map(|(&weight, &size)| WeightSize {
+            weight,
+            min_size_px: (size != PROHIBITED && size != NON_TEXT_ONLY).then_some(size),
+            non_text_only: size == NON_TEXT_ONLY,
+        })
+        .collect();
+
+    FontReport {
+        lc,
+        sizes,
+        use_cases: UseCases {
+            body_text: lc >= BODY_TEXT_LC,
+            spot_text: lc >= SPOT_TEXT_LC,
+            non_text: lc >= NON_TEXT_LC,
+        },
+    }
+}
+
+// =============================================================================
+// COMMANDES TAURI
+// TAURI COMMANDS
+// =============================================================================
+
+/// Retourne les tailles de police minimales APCA de la paire du store
+/// Returns the APCA minimum font sizes of the store pair
+#[tauri::command]
+pub fn get_apca_font_sizes(state: tauri::State<AppState>) -> FontReport {
+    state.store.lock().unwrap().apca_fonts.clone()
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn size(report: &FontReport, weight: u16) -> Option<f64> {
+        report.sizes.iter().find(|s| s.weight == weight).unwrap().min_size_px
+    }
+
+    #[test]
+    fn test_lookup_rows() {
+        // Lc 77.3 utilise la ligne Lc 75 / Lc 77.3 uses the Lc 75 row
+        let report = lookup(-77.3);
+        assert_eq!(report.lc, 77.3);
+        assert_eq!(size(&report, 400), Some(18.0));
+        assert_eq!(size(&report, 700), Some(14.0));
+        assert!(report.use_cases.body_text);
+
+        // Lc 20 : éléments non textuels uniquement / Lc 20: non-text elements only
+        let report = lookup(20.0);
+        assert!(report.sizes.iter().all(|s| s.non_text_only && s.min_size_px.is_none()));
+        assert_eq!(report.use_cases, UseCases { body_text: false, spot_text: false, non_text: true });
+
+        // Lc 5 : rien n'est permis / Lc 5: nothing is allowed
+        assert_eq!(lookup(5.0).use_cases, UseCases::default());
+    }
+
+    #[test]
+    fn test_row_boundary() {
+        // Lc 74.9 reste sur la ligne Lc 70, sans interpolation
+        // Lc 74.9 stays on the Lc 70 row, without interpolation
+        let below = lookup(74.9);
+        assert_eq!((size(&below, 400), size(&below, 700)), (Some(19.5), Some(14.5)));
+        assert!(!below.use_cases.body_text);
+
+        let at = lookup(75.0);
+        assert_eq!((size(&at, 400), size(&at, 700)), (Some(18.0), Some(14.0)));
+        assert!(at.use_cases.body_text);
+    }
+
+    #[test]
+    fn test_reverse_polarity() {
+        // Texte clair sur fond sombre : Lc négatif, même ligne que sa valeur absolue
+        // Light text on a dark background: negative Lc, same row as its absolute value
+        let negative = lookup(-90.0);
+        assert_eq!(negative, lookup(90.0));
+        assert_eq!((negative.lc, size(&negative, 100), size(&negative, 900)), (90.0, Some(48.0), Some(18.0)));
+    }
+
+    #[test]
+    fn test_below_non_text() {
+        // Sous Lc 15, aucune taille et pas même les éléments non textuels
+        // Below Lc 15, no size and not even non-text elements
+        for lc in [0.0, 10.0, 14.9, -14.9] {
+            let report = lookup(lc);
+            assert!(report.sizes.iter().all(|s| s.min_size_px.is_none() && !s.non_text_only), "Lc {}", lc);
+            assert!(!report.use_cases.non_text);
+        }
+        assert!(lookup(15.0).sizes.iter().all(|s| s.min_size_px.is_none() && s.non_text_only));
+    }
+}
@@ -3,9 +3,46 @@
 // =============================================================================
 
 use bigcolor::BigColor;
+use serde::{Deserialize, Serialize};
 use crate::store::ResultStore;
-use crate::picker::common::ColorPickerResult;
+use crate::picker::common::{format_hex_color, ColorPickerResult};
 use crate::config;
+use crate::apca;
+use crate::apca_fonts;
+use crate::compliance;
+use crate::conversion::{self, ColorModels, PreciseColor};
+use crate::surfaces::{self, Surface};
+
+/// Différences entre deux couleurs selon quatre formules
+/// Differences between two colors according to four formulas
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct ColorDifference {
+    /// CIE76 dans CIE Lab / CIE76 in CIE Lab
+    pub delta_e_76: f64,
+
+    /// CIE94 (arts graphiques) dans CIE Lab / CIE94 (graphic arts) in CIE Lab
+    pub delta_e_94: f64,
+
+    /// CIEDE2000 dans CIE Lab / CIEDE2000 in CIE Lab
+    pub delta_e_2000: f64,
+
+    /// Distance euclidienne dans OKLab / Euclidean distance in OKLab
+    pub delta_e_ok: f64,
+}
+
+/// Différence entre une case du store et la couleur de référence
+/// Difference between a store slot and the reference color
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ReferenceDifference {
+    /// "foreground", "background", "page" ou "adjacent" / "foreground", "background", "page" or "adjacent"
+    pub key: String,
+
+    /// Couleur de la case au format hexadécimal / Slot color in hexadecimal format
+    pub hex: String,
+
+    /// Écarts entre la case et la référence / Differences between the slot and the reference
+    pub difference: ColorDifference,
+}
 
 /// Met à jour les résultats du store à partir du résultat du picker
 /// Updates the store results from picker result
@@ -14,28 +51,283 @@ use crate::config;
 /// * `store` - Le store à mettre à jour / The store to update
 /// * `result` - Le résultat du color picker / The color picker result
 pub fn update_results_from_picker(store: &mut ResultStore, result: &ColorPickerResult) {
-    // Met à jour foreground si sélectionné
-    // Update foreground if selected
-    if let Some((r, g, b)) = result.foreground {
-        store.foreground_rgb = (r, g, b);
-        store.foreground_hex = format!("#{:02X}{:02X}{:02X}", r, g, b);
-        store.foreground = BigColor::from_rgb(r, g, b, 1.0);
-        store.foreground_is_dark = store.foreground.is_dark();
+    // Met à jour foreground si sélectionné (les pixels de l'écran sont opaques)
+    // Update foreground if selected (screen pixels are opaque)
+    if let Some(color) = result.foreground {
+        set_color(store, "foreground", color, Some(1.0));
     }
 
-    // Met à jour background si sélectionné
-    // Update background if selected
-    if let Some((r, g, b)) = result.background {
-        store.background_rgb = (r, g, b);
-        store.background_hex = format!("#{:02X}{:02X}{:02X}", r, g, b);
-        store.background = BigColor::from_rgb(r, g, b, 1.0);
-        store.background_is_dark = store.background.is_dark();
+    // Met à jour background si sélectionné (les pixels de l'écran sont opaques)
+    // Update background if selected (screen pixels are opaque)
+    if let Some(color) = result.background {
+        set_color(store, "background", color, Some(1.0));
     }
 
-    // Calcule le ratio de contraste
-    // Calculate contrast ratio
-    store.contrast_ratio_raw = store.foreground.get_contrast_ratio(&store.background);
+    // Recalcule les valeurs de contraste
+    // Recalculate contrast values
+    update_contrast(store);
+}
+
+/// Met à jour une couleur du store sans recalculer le contraste
+/// Updates a store color without recalculating contrast
+///
+/// Les valeurs RGB / hex sont ramenées dans le gamut sRGB pour l'affichage ;
+/// la valeur exacte est conservée dans `*_precise`.
+/// RGB / hex values are gamut mapped into sRGB for display;
+/// the exact value is kept in `*_precise`.
+///
+/// # Arguments
+/// * `store` - Le store à mettre à jour / The store to update
+/// * `key` - "foreground", "background", "page", "adjacent", "surface" ou "reference" / "foreground", "background", "page", "adjacent", "surface" or "reference"
+/// * `color` - Couleur exacte étiquetée avec son espace / Exact color tagged with its space
+/// * `alpha` - Opacité (0.0 - 1.0), None pour conserver l'actuelle / Opacity (0.0 - 1.0), None to keep the current one
+///
+/// # Returns
+/// `false` si la clé est inconnue / `false` if the key is unknown
+pub fn set_color(store: &mut ResultStore, key: &str, color: PreciseColor, alpha: Option<f64>) -> bool {
+    let rgb = color.to_rgb8();
+    let out_of_srgb = !color.in_srgb_gamut();
+    let (r, g, b) = rgb;
+    match key {
+        "foreground" => {
+            store.foreground_alpha = alpha.unwrap_or(store.foreground_alpha).clamp(0.0, 1.0);
+            store.foreground_rgb = rgb;
+            store.foreground_hex = format_hex_color(r, g, b);
+            store.foreground = BigColor::from_rgb(r, g, b, store.foreground_alpha as f32);
+            store.foreground_models = ColorModels::from_srgb(color.to_srgb());
+            store.foreground_precise = color;
+            store.foreground_out_of_srgb = out_of_srgb;
+        }
+        "background" => {
+            store.background_alpha = alpha.unwrap_or(store.background_alpha).clamp(0.0, 1.0);
+            store.background_rgb = rgb;
+            store.background_hex = format_hex_color(r, g, b);
+            store.background = BigColor::from_rgb(r, g, b, store.background_alpha as f32);
+            store.background_models = ColorModels::from_srgb(color.to_srgb());
+            store.background_precise = color;
+            store.background_out_of_srgb = out_of_srgb;
+        }
+        // La couleur de page est toujours opaque
+        // The page colour is always opaque
+        "page" => {
+            store.page_rgb = rgb;
+            store.page_hex = format_hex_color(r, g, b);
+        }
+        // Le texte adjacent (texte courant autour d'un lien) est toujours opaque
+        // The adjacent text (body text around a link) is always opaque
+        "adjacent" => {
+            store.adjacent_rgb = Some(rgb);
+            store.adjacent_hex = Some(format_hex_color(r, g, b));
+            store.adjacent_precise = Some(color);
+        }
+        // Chaque surface est ajoutée à la liste, avec son opacité
+        // Each surface is appended to the list, with its opacity
+        "surface" => {
+            store.surfaces.push(Surface {
+                hex: format_hex_color(r, g, b),
+                alpha: alpha.unwrap_or(1.0).clamp(0.0, 1.0),
+                precise: color,
+            });
+        }
+        // La couleur de référence (charte, spécification) est toujours opaque
+        // The reference colour (brand spec, specification) is always opaque
+        "reference" => {
+            store.reference_hex = Some(format_hex_color(r, g, b));
+            store.reference_precise = Some(color);
+        }
+        _ => return false,
+    }
+    true
+}
+
+/// Compose une couleur translucide sur une couleur opaque (opérateur source-over)
+/// Composites a translucent color over an opaque color (source-over operator)
+///
+/// # Arguments
+/// * `top` - Couleur sRGB du dessus / Top sRGB color
+/// * `alpha` - Opacité de la couleur du dessus (0.0 - 1.0) / Top color opacity (0.0 - 1.0)
+/// * `bottom` - Couleur sRGB opaque du dessous / Opaque bottom sRGB color
+pub fn composite(top: [f64; 3], alpha: f64, bottom: [f64; 3]) -> [f64; 3] {
+    [0, 1, 2].map(|i| top[i] * alpha + bottom[i] * (1.0 - alpha))
+}
+
+/// Calcule le ratio de contraste WCAG 2 entre deux couleurs sRGB étendues
+/// Calculates the WCAG 2 contrast ratio between two extended sRGB colors
+pub fn contrast_ratio(a: [f64; 3], b: [f64; 3]) -> f64 {
+    // Les luminances hors gamut sont bornées à la plage affichable
+    // Out-of-gamut luminances are bounded to the displayable range
+    let la = conversion::srgb_to_luminance(a).clamp(0.0, 1.0);
+    let lb = conversion::srgb_to_luminance(b).clamp(0.0, 1.0);
+    (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+}
+
+/// Arrondit un ratio de contraste pour l'affichage / Rounds a contrast ratio for display
+pub fn round_ratio(ratio: f64) -> f32 {
+    (ratio as f32 * config::ROUNDING_FACTOR).round() / config::ROUNDING_FACTOR
+}
+
+/// Calcule les différences deltaE76, deltaE94, CIEDE2000 et deltaEOK entre deux couleurs sRGB étendues
+/// Calculates the deltaE76, deltaE94, CIEDE2000 and deltaEOK differences between two extended sRGB colors
+///
+/// CIE94 n'est pas symétrique : `reference` sert de couleur de référence.
+/// CIE94 is not symmetric: `reference` is used as the reference color.
+pub fn color_difference(reference: [f64; 3], sample: [f64; 3]) -> ColorDifference {
+    let lab1 = conversion::xyz_to_lab(conversion::srgb_to_xyz(reference));
+    let lab2 = conversion::xyz_to_lab(conversion::srgb_to_xyz(sample));
+    ColorDifference {
+        delta_e_76: conversion::delta_e_76_lab(lab1, lab2),
+        delta_e_94: conversion::delta_e_94_lab(lab1, lab2),
+        delta_e_2000: conversion::delta_e_2000_lab(lab1, lab2),
+        delta_e_ok: conversion::delta_e_ok(reference, sample),
+    }
+}
+
+/// Recalcule toutes les valeurs de contraste du store (WCAG 2 et APCA)
+/// Recalculates all contrast values of the store (WCAG 2 and APCA)
+///
+/// Les couleurs sont d'abord composées : l'arrière-plan sur la couleur de page,
+/// puis le premier plan sur l'arrière-plan composé. Le contraste est calculé
+/// deux fois : avec les valeurs exactes et avec les valeurs ramenées dans sRGB.
+/// Colors are composited first: the background over the page colour,
+/// then the foreground over the composited background. Contrast is calculated
+/// twice: with the exact values and with the values gamut mapped into sRGB.
+///
+/// # Arguments
+/// * `store` - Le store à mettre à jour / The store to update
+pub fn update_contrast(store: &mut ResultStore) {
+    // Compose les couleurs translucides à partir des valeurs exactes
+    // Composite translucent colors from the exact values
+    let page = conversion::rgb8_to_srgb(store.page_rgb);
+    let background = composite(store.background_precise.to_srgb(), store.background_alpha, page);
+    let foreground = composite(store.foreground_precise.to_srgb(), store.foreground_alpha, background);
+    store.background_composited_srgb = background;
+    store.foreground_composited_srgb = foreground;
+
+    // Compose les couleurs ramenées dans le gamut sRGB (couleurs affichées)
+    // Composite the colors gamut mapped into sRGB (displayed colors)
+    let background_mapped = composite(store.background_precise.to_srgb_mapped(), store.background_alpha, page);
+    let foreground_mapped = composite(store.foreground_precise.to_srgb_mapped(), store.foreground_alpha, background_mapped);
+
+    // Valeurs 8 bits pour l'affichage
+    // 8-bit values for display
+    let (br, bg, bb) = conversion::srgb_to_rgb8(background_mapped);
+    let (fr, fg, fb) = conversion::srgb_to_rgb8(foreground_mapped);
+    store.background_composited_rgb = (br, bg, bb);
+    store.background_composited_hex = format_hex_color(br, bg, bb);
+    store.foreground_composited_rgb = (fr, fg, fb);
+    store.foreground_composited_hex = format_hex_color(fr, fg, fb);
+
+    // Un arrière-plan translucide rend le résultat dépendant de ce qui se trouve dessous
+    // A translucent background makes the result depend on what is underneath
+    store.depends_on_backdrop = store.background_alpha < 1.0;
+
+    // Couleurs visibles, utilisées pour la détection clair / sombre
+    // Visible colors, used for light / dark detection
+    let fc = BigColor::from_rgb(fr, fg, fb, 1.0);
+    let bc = BigColor::from_rgb(br, bg, bb, 1.0);
+    store.foreground_is_dark = fc.is_dark();
+    store.background_is_dark = bc.is_dark();
+
+    // Calcule le ratio de contraste à partir des valeurs exactes
+    // Calculate contrast ratio from the exact values
+    store.contrast_ratio_raw = contrast_ratio(foreground, background);
 
     // Round the contrast ratio, to 3 decimal
-    store.contrast_ratio_rounded = (store.contrast_ratio_raw * config::ROUNDING_FACTOR).round() / config::ROUNDING_FACTOR;
+    store.contrast_ratio_rounded = round_ratio(store.contrast_ratio_raw);
+
+    // Évalue les critères avec le ratio non arrondi (WCAG n'autorise aucun arrondi),
+    // en marquant ceux qui s'appliquent au texte testé
+    // Evaluate the criteria with the unrounded ratio (WCAG allows no rounding),
+    // marking those that apply to the tested text
+    store.compliance = compliance::evaluate(&store.profile.criteria, store.contrast_ratio_raw, store.text_spec.as_ref().map(|t| t.large));
+    store.profile_id = store.profile.id.clone();
+
+    // Calcule le contraste APCA dans les deux sens
+    // Calculate APCA contrast in both directions
+    store.apca_lc_raw = apca::contrast(foreground, background);
+    store.apca_lc_reverse_raw = apca::contrast(background, foreground);
+    store.apca_polarity = apca::polarity(foreground, background);
+
+    // Arrondit les valeurs Lc
+    // Round the Lc values
+    store.apca_lc_rounded = (store.apca_lc_raw * config::APCA_ROUNDING_FACTOR).round() / config::APCA_ROUNDING_FACTOR;
+    store.apca_lc_reverse_rounded = (store.apca_lc_reverse_raw * config::APCA_ROUNDING_FACTOR).round() / config::APCA_ROUNDING_FACTOR;
+
+    // Tailles de police minimales pour le Lc non arrondi
+    // Minimum font sizes for the unrounded Lc
+    store.apca_fonts = apca_fonts::lookup(store.apca_lc_raw);
+
+    // Lien (premier plan) face au texte adjacent et à l'arrière-plan (G183)
+    // Link (foreground) against the adjacent text and the background (G183)
+    store.link_contrast = store.adjacent_precise.as_ref().map(|adjacent| {
+        let text = adjacent.to_srgb();
+        compliance::evaluate_link(store.contrast_ratio_raw, contrast_ratio(foreground, text), contrast_ratio(text, background))
+    });
+
+    // Différences entre les valeurs exactes des cases (sans composition), puis
+    // entre chaque case et la couleur de référence
+    // Differences between the exact slot values (without compositing), then
+    // between each slot and the reference colour
+    store.color_difference = color_difference(store.background_precise.to_srgb(), store.foreground_precise.to_srgb());
+    store.reference_differences = match store.reference_precise {
+        Some(reference) => {
+            let reference = reference.to_srgb();
+            let slots = [
+                ("foreground", Some(store.foreground_precise.to_srgb())),
+                ("background", Some(store.background_precise.to_srgb())),
+                ("page", Some(page)),
+                ("adjacent", store.adjacent_precise.map(|c| c.to_srgb())),
+            ];
+            slots
+                .into_iter()
+                .filter_map(|(key, srgb)| srgb.map(|srgb| (key, srgb)))
+                .map(|(key, srgb)| {
+                    let (r, g, b) = conversion::srgb_to_rgb8(conversion::gamut_map_srgb(srgb));
+                    ReferenceDifference {
+                        key: key.to_string(),
+                        hex: format_hex_color(r, g, b),
+                        difference: color_difference(reference, srgb),
+                    }
+                })
+                .collect()
+        }
+        None => Vec::new(),
+    };
+
+    // Premier plan sur l'arrière-plan et chaque surface, avec le pire cas
+    // Foreground over the background and each surface, with the worst case
+    store.surface_report = surfaces::evaluate(store);
+
+    // Contraste après gamut mapping (identique si aucune couleur n'est hors gamut)
+    // Contrast after gamut mapping (identical when no color is out of gamut)
+    store.contrast_ratio_mapped_raw = contrast_ratio(foreground_mapped, background_mapped);
+    store.contrast_ratio_mapped_rounded = round_ratio(store.contrast_ratio_mapped_raw);
+    store.apca_lc_mapped_raw = apca::contrast(foreground_mapped, background_mapped);
+    store.apca_lc_mapped_rounded = (store.apca_lc_mapped_raw * config::APCA_ROUNDING_FACTOR).round() / config::APCA_ROUNDING_FACTOR;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Page grise non blanche / Non-white grey page
+    const PAGE: [f64; 3] = [0.2, 0.4, 0.6];
+
+    #[test]
+    fn test_composite_opaque() {
+        assert_eq!(composite([1.0, 0.0, 0.5], 1.0, PAGE), [1.0, 0.0, 0.5]);
+    }
+
+    #[test]
+    fn test_composite_transparent() {
+        assert_eq!(composite([1.0, 0.0, 0.5], 0.0, PAGE), PAGE);
+    }
+
+    #[test]
+    fn test_composite_half() {
+        let mixed = composite([1.0, 0.0, 0.5], 0.5, PAGE);
+        for (value, expected) in mixed.into_iter().zip([0.6, 0.2, 0.55]) {
+            assert!((value - expected).abs() < 1e-12);
+        }
+    }
 }
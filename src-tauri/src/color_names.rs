@@ -3,6 +3,9 @@
 // 148 named colors with nearest-color lookup
 // =============================================================================
 
+use crate::conversion;
+use crate::i18n;
+
 /// A CSS named color with its RGB components.
 struct NamedColor {
     name: &'static str,
@@ -156,30 +159,17 @@ static CSS_COLORS: &[NamedColor] = &[
     NamedColor { name: "yellowgreen", r: 154, g: 205, b: 50 },
 ];
 
-/// Returns the nearest CSS color name for a given RGB value.
-///
-/// Uses Euclidean distance in RGB space. Returns the exact name
-/// if there is a perfect match, otherwise the closest one.
-pub fn nearest_color_name(r: u8, g: u8, b: u8) -> &'static str {
-    let (r, g, b) = (r as i32, g as i32, b as i32);
-    let mut best_name = "black";
-    let mut best_dist = i32::MAX;
-
-    for c in CSS_COLORS {
-        let dr = r - c.r as i32;
-        let dg = g - c.g as i32;
-        let db = b - c.b as i32;
-        let dist = dr * dr + dg * dg + db * db;
-        if dist == 0 {
-            return c.name;
-        }
-        if dist < best_dist {
-            best_dist = dist;
-            best_name = c.name;
-        }
-    }
+/// Iterates over the CSS named colors as (name, RGB) pairs.
+pub fn css_colors() -> impl Iterator<Item = (&'static str, (u8, u8, u8))> {
+    CSS_COLORS.iter().map(|c| (c.name, (c.r, c.g, c.b)))
+}
 
-    best_name
+/// Returns the RGB value of a CSS color name, or None.
+///
+/// The lookup is case-insensitive and accepts both "gray" and "grey" spellings.
+pub fn color_by_name(name: &str) -> Option<(u8, u8, u8)> {
+    let name = name.to_ascii_lowercase().replace("grey", "gray");
+    CSS_COLORS.iter().find(|c| c.name == name).map(|c| (c.r, c.g, c.b))
 }
 
 /// Returns the exact CSS color name if the RGB value matches one, or None.
@@ -187,24 +177,116 @@ pub fn exact_color_name(r: u8, g: u8, b: u8) -> Option<&'static str> {
     CSS_COLORS.iter().find(|c| c.r == r && c.g == g && c.b == b).map(|c| c.name)
 }
 
+/// OKLCH chroma below which a colour is named as a grey, black or white.
+const ACHROMATIC_CHROMA: f64 = 0.03;
+
+/// OKLCH chroma from which a grey is called warm or cool.
+const TINTED_CHROMA: f64 = 0.01;
+
+/// OKLCH chroma below which a colour is called desaturated.
+const DESATURATED_CHROMA: f64 = 0.07;
+
+/// OKLCH chroma from which a colour is called vivid.
+const VIVID_CHROMA: f64 = 0.2;
+
+/// OKLCH lightness below which orange and yellow are named brown and olive.
+const EARTH_LIGHTNESS: f64 = 0.55;
+
+/// Upper bounds of the lightness buckets, from "very dark" to "light";
+/// anything above the last one is "very light".
+const LIGHTNESS_BUCKETS: [(f64, &str); 4] = [(0.3, "very_dark"), (0.45, "dark"), (0.7, ""), (0.85, "light")];
+
+/// Hue buckets as (start angle in OKLCH degrees, name); red starts at 15.
+const HUE_BUCKETS: [(f64, &str); 10] = [
+    (15.0, "red"),
+    (45.0, "orange"),
+    (80.0, "yellow"),
+    (115.0, "lime"),
+    (135.0, "green"),
+    (175.0, "cyan"),
+    (220.0, "blue"),
+    (285.0, "violet"),
+    (320.0, "magenta"),
+    (345.0, "pink"),
+];
+
+/// Returns a descriptive name built from OKLCH lightness, chroma and hue
+/// buckets, e.g. "dark desaturated blue" or "light warm grey".
+///
+/// The vocabulary and word order come from the locale catalog
+/// (`i18n::name_t`); unknown locales fall back to English.
+pub fn descriptive_color_name(r: u8, g: u8, b: u8, locale: &str) -> String {
+    let [lightness, chroma, hue] = conversion::srgb_to_oklch(conversion::rgb8_to_srgb((r, g, b)));
+    let hue = hue.rem_euclid(360.0);
+
+    let lightness_key = LIGHTNESS_BUCKETS
+        .iter()
+        .find(|(max, _)| lightness < *max)
+        .map_or("very_light", |(_, key)| *key);
+    let hue_key = HUE_BUCKETS
+        .iter()
+        .rev()
+        .find(|(start, _)| hue >= *start)
+        .map_or("pink", |(_, key)| *key);
+
+    let (lightness_key, chroma_key, hue_key) = if chroma < ACHROMATIC_CHROMA {
+        // Near the ends of the lightness axis, a grey is just black or white
+        match lightness_key {
+            "very_dark" if lightness < 0.15 => ("", "", "black"),
+            "very_light" if lightness > 0.97 => ("", "", "white"),
+            _ => {
+                let temperature = match hue_key {
+                    _ if chroma < TINTED_CHROMA => "",
+                    "red" | "orange" | "yellow" => "warm",
+                    "cyan" | "blue" => "cool",
+                    _ => "",
+                };
+                (lightness_key, temperature, "grey")
+            }
+        }
+    } else {
+        let chroma_key = if chroma < DESATURATED_CHROMA {
+            "desaturated"
+        } else if chroma >= VIVID_CHROMA {
+            "vivid"
+        } else {
+            ""
+        };
+        let hue_key = match hue_key {
+            "orange" if lightness < EARTH_LIGHTNESS => "brown",
+            "yellow" if lightness < EARTH_LIGHTNESS => "olive",
+            key => key,
+        };
+        (lightness_key, chroma_key, hue_key)
+    };
+
+    let word = |key: &str| if key.is_empty() { "" } else { i18n::name_t(locale, key) };
+    i18n::name_t(locale, "pattern")
+        .replace("{l}", word(lightness_key))
+        .replace("{c}", word(chroma_key))
+        .replace("{h}", word(hue_key))
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns the exact CSS name if there is one, otherwise the descriptive name.
+pub fn color_name(r: u8, g: u8, b: u8, locale: &str) -> String {
+    match exact_color_name(r, g, b) {
+        Some(name) => name.to_string(),
+        None => descriptive_color_name(r, g, b, locale),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_exact_match() {
-        assert_eq!(nearest_color_name(255, 0, 0), "red");
-        assert_eq!(nearest_color_name(0, 0, 0), "black");
-        assert_eq!(nearest_color_name(255, 255, 255), "white");
-        assert_eq!(nearest_color_name(255, 165, 0), "orange");
-    }
-
-    #[test]
-    fn test_nearest_match() {
-        // Close to red
-        assert_eq!(nearest_color_name(254, 1, 1), "red");
-        // Close to navy
-        assert_eq!(nearest_color_name(0, 0, 130), "navy");
+    fn test_color_by_name() {
+        assert_eq!(color_by_name("RebeccaPurple"), Some((102, 51, 153)));
+        assert_eq!(color_by_name("lightslategrey"), Some((119, 136, 153)));
+        assert_eq!(color_by_name("notacolor"), None);
     }
 
     #[test]
@@ -212,4 +294,17 @@ mod tests {
         assert_eq!(exact_color_name(255, 0, 0), Some("red"));
         assert_eq!(exact_color_name(254, 0, 0), None);
     }
+
+    #[test]
+    fn test_descriptive_color_name() {
+        assert_eq!(descriptive_color_name(0x33, 0x44, 0x66, "en"), "dark desaturated blue");
+        assert_eq!(descriptive_color_name(0x33, 0x44, 0x66, "fr"), "bleu désaturé foncé");
+        assert_eq!(descriptive_color_name(0xC8, 0xC2, 0xB8, "en"), "light warm grey");
+        assert_eq!(descriptive_color_name(0x8B, 0x45, 0x13, "en"), "brown");
+        assert_eq!(descriptive_color_name(0x05, 0x05, 0x05, "de"), "black");
+
+        // Exact CSS names take precedence
+        assert_eq!(color_name(0x66, 0x33, 0x99, "fr"), "rebeccapurple");
+        assert_eq!(color_name(0x66, 0x33, 0x98, "en"), "dark violet");
+    }
 }
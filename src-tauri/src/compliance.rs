@@ -0,0 +1,281 @@
+// =============================================================================
+// compliance.rs - Évaluation de la conformité WCAG
+// compliance.rs - WCAG compliance evaluation
+// =============================================================================
+//
+// Compare le ratio de contraste NON arrondi aux seuils des critères : WCAG
+// n'autorise aucun arrondi, un ratio de 4.46:1 échoue donc à 4.5:1 même s'il
+// s'affiche « 4.5 ».
+// Compares the UNROUNDED contrast ratio with the criteria thresholds: WCAG
+// allows no rounding, so a 4.46:1 ratio fails 4.5:1 even if it displays as "4.5".
+// Reference: https://www.w3.org/WAI/WCAG22/Understanding/contrast-minimum
+
+use serde::{Deserialize, Serialize};
+use crate::color;
+
+/// Ratio minimal entre un lien et le texte qui l'entoure (G183)
+/// Minimum ratio between a link and its surrounding text (G183)
+const LINK_VS_TEXT_THRESHOLD: f64 = 3.0;
+
+/// Ratio minimal entre le texte (lien ou non) et l'arrière-plan (G183)
+/// Minimum ratio between text (link or not) and the background (G183)
+const TEXT_VS_BACKGROUND_THRESHOLD: f64 = 4.5;
+
+// =============================================================================
+// STRUCTURES
+// =============================================================================
+
+/// Niveau de conformité / Conformance level
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Level {
+    #[serde(rename = "A")]
+    A,
+    #[serde(rename = "AA")]
+    Aa,
+    #[serde(rename = "AAA")]
+    Aaa,
+}
+
+/// Type de contenu auquel s'applique un seuil
+/// Kind of content a threshold applies to
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum ContentKind {
+    /// Texte de taille normale / Regular size text
+    Regular,
+    /// Grand texte (18pt, ou 14pt gras) / Large text (18pt, or 14pt bold)
+    Large,
+    /// Composants d'interface et objets graphiques / UI components and graphical objects
+    NonText,
+}
+
+/// Critère de contraste et son seuil / Contrast criterion and its threshold
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Criterion {
+    /// Identifiant du critère (par ex. "1.4.3") / Criterion id (e.g. "1.4.3")
+    pub id: String,
+
+    /// Niveau de conformité / Conformance level
+    pub level: Level,
+
+    /// Contenu concerné / Content concerned
+    pub content: ContentKind,
+
+    /// Ratio minimal / Minimum ratio
+    pub threshold: f64,
+
+    /// Libellé affiché (par ex. "9.1.4.3 Contrast (minimum)") / Displayed label (e.g. "9.1.4.3 Contrast (minimum)")
+    #[serde(default)]
+    pub label: String,
+}
+
+/// Résultat de l'évaluation d'un critère / Result of a criterion evaluation
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct CriterionResult {
+    /// Identifiant du critère (par ex. "1.4.3") / Criterion id (e.g. "1.4.3")
+    pub id: String,
+
+    /// Niveau de conformité / Conformance level
+    pub level: Level,
+
+    /// Contenu concerné / Content concerned
+    pub content: ContentKind,
+
+    /// Ratio minimal / Minimum ratio
+    pub threshold: f64,
+
+    /// Libellé affiché / Displayed label
+    pub label: String,
+
+    /// Si le ratio non arrondi atteint le seuil / If the unrounded ratio reaches the threshold
+    pub pass: bool,
+
+    /// Si le critère s'applique à l'élément testé (tous s'appliquent sans taille de texte)
+    /// If the criterion applies to the tested element (all apply without a text size)
+    pub applies: bool,
+
+    /// Écart entre le ratio non arrondi et le seuil (négatif en cas d'échec)
+    /// Difference between the unrounded ratio and the threshold (negative on failure)
+    pub margin: f64,
+}
+
+impl Criterion {
+    /// Clé courte du critère : "1.4.3" ou "1.4.3-large" pour le grand texte
+    /// Short key of the criterion: "1.4.3", or "1.4.3-large" for large text
+    pub fn key(&self) -> String {
+        match self.content {
+            ContentKind::Large => format!("{}-large", self.id),
+            _ => self.id.clone(),
+        }
+    }
+}
+
+/// Résultat de la technique G183 (échec F73) : lien distingué par la couleur seule
+/// G183 technique result (failure F73): link distinguished by colour alone
+///
+/// Le premier plan est le lien, le texte adjacent est le texte courant qui l'entoure.
+/// The foreground is the link, the adjacent text is the body text around it.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct LinkContrast {
+    /// Lien / arrière-plan, arrondi / Link vs background, rounded
+    pub link_vs_background_rounded: f32,
+    pub link_vs_background_pass: bool,
+
+    /// Lien / texte adjacent, arrondi / Link vs adjacent text, rounded
+    pub link_vs_text_rounded: f32,
+    pub link_vs_text_pass: bool,
+
+    /// Texte adjacent / arrière-plan, arrondi / Adjacent text vs background, rounded
+    pub text_vs_background_rounded: f32,
+    pub text_vs_background_pass: bool,
+
+    /// Résultat combiné : G183 satisfaite, donc pas d'échec F73
+    /// Combined result: G183 met, hence no F73 failure
+    pub pass: bool,
+}
+
+// =============================================================================
+// CRITÈRES
+// CRITERIA
+// =============================================================================
+
+/// Construit un critère / Builds a criterion
+pub fn criterion(id: &str, level: Level, content: ContentKind, threshold: f64, label: &str) -> Criterion {
+    Criterion { id: id.to_string(), level, content, threshold, label: label.to_string() }
+}
+
+/// Critères de contraste WCAG 2.2 / WCAG 2.2 contrast criteria
+pub fn wcag_criteria() -> Vec<Criterion> {
+    vec![
+        criterion("1.4.3", Level::Aa, ContentKind::Regular, 4.5, "1.4.3 Contrast (Minimum)"),
+        criterion("1.4.3", Level::Aa, ContentKind::Large, 3.0, "1.4.3 Contrast (Minimum), large text"),
+        criterion("1.4.6", Level::Aaa, ContentKind::Regular, 7.0, "1.4.6 Contrast (Enhanced)"),
+        criterion("1.4.6", Level::Aaa, ContentKind::Large, 4.5, "1.4.6 Contrast (Enhanced), large text"),
+        criterion("1.4.11", Level::Aa, ContentKind::NonText, 3.0, "1.4.11 Non-text Contrast"),
+    ]
+}
+
+/// Cherche un critère par sa clé courte ("1.4.3", "1.4.6-large"…)
+/// Finds a criterion by its short key ("1.4.3", "1.4.6-large"…)
+pub fn find<'a>(criteria: &'a [Criterion], key: &str) -> Option<&'a Criterion> {
+    criteria.iter().find(|criterion| criterion.key() == key)
+}
+
+// =============================================================================
+// FONCTIONS D'ÉVALUATION
+// EVALUATION FUNCTIONS
+// =============================================================================
+
+/// Évalue des critères avec un ratio de contraste non arrondi
+/// Evaluates criteria against an unrounded contrast ratio
+///
+/// # Arguments
+/// * `criteria` - Critères à évaluer / Criteria to evaluate
+/// * `ratio_raw` - Ratio de contraste non arrondi / Unrounded contrast ratio
+/// * `large_text` - Si l'élément testé est un grand texte, None sans taille de texte
+///   If the tested element is large text, None without a text size
+pub fn evaluate(criteria: &[Criterion], ratio_raw: f64, large_text: Option<bool>) -> Vec<CriterionResult> {
+    criteria
+        .iter()
+        .map(|criterion| CriterionResult {
+            applies: match (large_text, criterion.content) {
+                (None, _) => true,
+                (Some(large), ContentKind::Large) => large,
+                (Some(large), ContentKind::Regular) => !large,
+                (Some(_), ContentKind::NonText) => false,
+            },
+            id: criterion.id.clone(),
+            level: criterion.level,
+            content: criterion.content,
+            threshold: criterion.threshold,
+            label: criterion.label.clone(),
+            pass: ratio_raw >= criterion.threshold,
+            margin: ratio_raw - criterion.threshold,
+        })
+        .collect()
+}
+
+/// Évalue la technique G183 à partir des ratios non arrondis
+/// Evaluates the G183 technique from the unrounded ratios
+///
+/// # Arguments
+/// * `link_vs_background` - Ratio lien / arrière-plan / Link vs background ratio
+/// * `link_vs_text` - Ratio lien / texte adjacent / Link vs adjacent text ratio
+/// * `text_vs_background` - Ratio texte adjacent / arrière-plan / Adjacent text vs background ratio
+pub fn evaluate_link(link_vs_background: f64, link_vs_text: f64, text_vs_background: f64) -> LinkContrast {
+    let link_vs_background_pass = link_vs_background >= TEXT_VS_BACKGROUND_THRESHOLD;
+    let link_vs_text_pass = link_vs_text >= LINK_VS_TEXT_THRESHOLD;
+    let text_vs_background_pass = text_vs_background >= TEXT_VS_BACKGROUND_THRESHOLD;
+
+    LinkContrast {
+        link_vs_background_rounded: color::round_ratio(link_vs_background),
+        link_vs_background_pass,
+        link_vs_text_rounded: color::round_ratio(link_vs_text),
+        link_vs_text_pass,
+        text_vs_background_rounded: color::round_ratio(text_vs_background),
+        text_vs_background_pass,
+        pass: link_vs_background_pass && link_vs_text_pass && text_vs_background_pass,
+    }
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pass(results: &[CriterionResult], id: &str, content: ContentKind) -> bool {
+        results.iter().find(|r| r.id == id && r.content == content).unwrap().pass
+    }
+
+    #[test]
+    fn test_no_rounding_before_threshold() {
+        // 4.46 s'affiche « 4.5 » mais échoue à 1.4.3
+        // 4.46 displays as "4.5" but fails 1.4.3
+        let results = evaluate(&wcag_criteria(), 4.46, None);
+        assert!(!pass(&results, "1.4.3", ContentKind::Regular));
+        assert!(pass(&results, "1.4.3", ContentKind::Large));
+        assert!(pass(&results, "1.4.11", ContentKind::NonText));
+        assert!(!pass(&results, "1.4.6", ContentKind::Large));
+    }
+
+    #[test]
+    fn test_exact_threshold_and_margin() {
+        let results = evaluate(&wcag_criteria(), 7.0, None);
+        assert!(results.iter().all(|r| r.pass));
+        let aaa = results.iter().find(|r| r.id == "1.4.6" && r.content == ContentKind::Regular).unwrap();
+        assert_eq!(aaa.margin, 0.0);
+        assert_eq!(aaa.level, Level::Aaa);
+    }
+
+    #[test]
+    fn test_applicable_criteria() {
+        // Un grand texte n'est concerné que par les seuils « grand texte »
+        // Large text is only concerned by the "large text" thresholds
+        let results = evaluate(&wcag_criteria(), 4.0, Some(true));
+        let applicable: Vec<String> = results.iter().filter(|r| r.applies).map(|r| format!("{}-{:?}", r.id, r.content)).collect();
+        assert_eq!(applicable, ["1.4.3-Large", "1.4.6-Large"]);
+    }
+
+    #[test]
+    fn test_link_contrast() {
+        // Lien à 4.6:1 mais seulement 2.9:1 face au texte : échec F73
+        // Link at 4.6:1 but only 2.9:1 against the text: F73 failure
+        let link = evaluate_link(4.6, 2.99, 15.0);
+        assert!(link.link_vs_background_pass && link.text_vs_background_pass);
+        assert!(!link.link_vs_text_pass);
+        assert_eq!(link.link_vs_text_rounded, 3.0);
+        assert!(!link.pass);
+        assert!(evaluate_link(4.5, 3.0, 4.5).pass);
+    }
+
+    #[test]
+    fn test_find_by_key() {
+        let criteria = wcag_criteria();
+        assert_eq!(find(&criteria, "1.4.6-large").unwrap().threshold, 4.5);
+        assert_eq!(find(&criteria, "1.4.11").unwrap().threshold, 3.0);
+        assert!(find(&criteria, "1.4.11-large").is_none());
+    }
+}
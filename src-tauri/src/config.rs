@@ -47,6 +47,12 @@ pub const DEFAULT_FOREGROUND_RGB: (u8, u8, u8) = (0, 0, 0);
 /// Valeur RGB par défaut pour la couleur d'arrière-plan (blanc)
 pub const DEFAULT_BACKGROUND_RGB: (u8, u8, u8) = (255, 255, 255);
 
+/// Default page color RGB value, under a translucent background (white)
+/// Valeur RGB par défaut de la couleur de page, sous un arrière-plan translucide (blanc)
+pub const DEFAULT_PAGE_RGB: (u8, u8, u8) = (255, 255, 255);
+
 /// Value for rounding
 pub const ROUNDING_FACTOR: f32 = 10.0; // 1 decimals;
 
+/// Value for rounding APCA Lc values
+pub const APCA_ROUNDING_FACTOR: f64 = 10.0; // 1 decimals;
@@ -0,0 +1,924 @@
+// =============================================================================
+// conversion.rs - Conversions entre modèles de couleur
+// conversion.rs - Color model conversions
+// =============================================================================
+//
+// Les formules et matrices suivent CSS Color Module Level 4 :
+// Lab et LCH sont relatifs à D50 (adaptation Bradford), XYZ et OKLab à D65.
+// Formulas and matrices follow CSS Color Module Level 4:
+// Lab and LCH are relative to D50 (Bradford adaptation), XYZ and OKLab to D65.
+// Reference: https://www.w3.org/TR/css-color-4/#color-conversion-code
+
+use serde::{Deserialize, Serialize};
+
+// =============================================================================
+// MATRICES DE CONVERSION
+// CONVERSION MATRICES
+// =============================================================================
+
+/// Matrice 3x3 (lignes) / 3x3 matrix (rows)
+type Matrix = [[f64; 3]; 3];
+
+/// sRGB linéaire vers XYZ D65 / Linear sRGB to XYZ D65
+const LINEAR_SRGB_TO_XYZ: Matrix = [
+    [0.412_390_799_265_959_34, 0.357_584_339_383_878, 0.180_480_788_401_834_3],
+    [0.212_639_005_871_510_27, 0.715_168_678_767_756, 0.072_192_315_360_733_71],
+    [0.019_330_818_715_591_82, 0.119_194_779_794_625_98, 0.950_532_152_249_660_7],
+];
+
+/// XYZ D65 vers sRGB linéaire / XYZ D65 to linear sRGB
+const XYZ_TO_LINEAR_SRGB: Matrix = [
+    [3.240_969_941_904_522_6, -1.537_383_177_570_094, -0.498_610_760_293_003_4],
+    [-0.969_243_636_280_879_6, 1.875_967_501_507_720_2, 0.041_555_057_407_175_59],
+    [0.055_630_079_696_993_66, -0.203_976_958_888_976_52, 1.056_971_514_242_878_6],
+];
+
+/// Adaptation chromatique Bradford D65 vers D50 / Bradford chromatic adaptation D65 to D50
+const D65_TO_D50: Matrix = [
+    [1.047_929_820_840_548_8, 0.022_946_793_341_019_088, -0.050_192_229_543_135_57],
+    [0.029_627_815_688_159_344, 0.990_434_484_573_249, -0.017_073_825_029_385_14],
+    [-0.009_243_058_152_591_178, 0.015_055_144_896_577_895, 0.751_874_289_958_000_8],
+];
+
+/// Adaptation chromatique Bradford D50 vers D65 / Bradford chromatic adaptation D50 to D65
+const D50_TO_D65: Matrix = [
+    [0.955_473_452_704_218_2, -0.023_098_536_874_261_423, 0.063_259_308_661_021_7],
+    [-0.028_369_706_963_208_136, 1.009_995_458_005_822_6, 0.021_041_398_966_943_008],
+    [0.012_314_001_688_319_899, -0.020_507_696_433_477_912, 1.330_365_936_608_075_3],
+];
+
+/// XYZ D65 vers LMS (OKLab) / XYZ D65 to LMS (OKLab)
+const XYZ_TO_LMS: Matrix = [
+    [0.819_022_437_996_703, 0.361_906_260_052_890_4, -0.128_873_781_520_987_9],
+    [0.032_983_653_932_388_5, 0.929_286_861_586_343_4, 0.036_144_666_350_642_4],
+    [0.048_177_189_359_624_2, 0.264_239_531_730_730_8, 0.633_547_828_469_430_9],
+];
+
+/// LMS non linéaire vers OKLab / Non-linear LMS to OKLab
+const LMS_TO_OKLAB: Matrix = [
+    [0.210_454_268_309_314, 0.793_617_774_702_305_4, -0.004_072_043_011_619_3],
+    [1.977_998_532_431_168_4, -2.428_592_242_048_58, 0.450_593_709_617_411],
+    [0.025_904_042_465_547_8, 0.782_771_712_457_529_6, -0.808_675_754_923_077_4],
+];
+
+/// OKLab vers LMS non linéaire / OKLab to non-linear LMS
+const OKLAB_TO_LMS: Matrix = [
+    [1.0, 0.396_337_777_376_174_9, 0.215_803_757_309_913_6],
+    [1.0, -0.105_561_345_815_658_6, -0.063_854_172_825_813_3],
+    [1.0, -0.089_484_177_529_811_9, -1.291_485_548_019_409_2],
+];
+
+/// LMS vers XYZ D65 / LMS to XYZ D65
+const LMS_TO_XYZ: Matrix = [
+    [1.226_879_875_845_924_3, -0.557_814_994_460_217_1, 0.281_391_045_665_964_7],
+    [-0.040_575_745_214_800_8, 1.112_286_803_280_317, -0.071_711_058_065_516_4],
+    [-0.076_372_936_674_660_1, -0.421_493_332_402_243_2, 1.586_924_019_836_781_6],
+];
+
+/// Display P3 linéaire vers XYZ D65 / Linear Display P3 to XYZ D65
+const LINEAR_P3_TO_XYZ: Matrix = [
+    [0.486_570_948_648_216_2, 0.265_667_693_169_093_06, 0.198_217_285_234_362_5],
+    [0.228_974_564_069_748_8, 0.691_738_521_836_506_4, 0.079_286_914_093_745],
+    [0.0, 0.045_113_381_858_902_64, 1.043_944_368_900_976],
+];
+
+/// Rec. 2020 linéaire vers XYZ D65 / Linear Rec. 2020 to XYZ D65
+const LINEAR_REC2020_TO_XYZ: Matrix = [
+    [0.636_958_048_301_291_4, 0.144_616_903_586_208_32, 0.168_880_975_164_172_1],
+    [0.262_700_212_011_267_1, 0.677_998_071_518_870_8, 0.059_301_716_469_861_96],
+    [0.0, 0.028_072_693_049_087_428, 1.060_985_057_710_791],
+];
+
+/// A98 RGB linéaire vers XYZ D65 / Linear A98 RGB to XYZ D65
+const LINEAR_A98_TO_XYZ: Matrix = [
+    [0.576_669_042_910_130_5, 0.185_558_237_906_546_3, 0.188_228_646_234_994_7],
+    [0.297_344_975_250_536_05, 0.627_363_566_255_466_1, 0.075_291_458_493_997_88],
+    [0.027_031_361_386_412_34, 0.070_688_852_535_827_23, 0.991_337_536_837_638_8],
+];
+
+/// ProPhoto RGB linéaire vers XYZ D50 / Linear ProPhoto RGB to XYZ D50
+const LINEAR_PROPHOTO_TO_XYZ_D50: Matrix = [
+    [0.797_760_489_672_302_7, 0.135_185_837_175_740_31, 0.031_349_349_581_524_8],
+    [0.288_071_128_229_293_4, 0.711_843_217_810_101_4, 0.000_085_653_960_605_259_02],
+    [0.0, 0.0, 0.825_104_602_510_460_1],
+];
+
+/// Constantes de la courbe de transfert Rec. 2020
+/// Rec. 2020 transfer curve constants
+const REC2020_ALPHA: f64 = 1.099_296_826_809_44;
+const REC2020_BETA: f64 = 0.018_053_968_510_807;
+
+/// Point blanc D50 (XYZ) / D50 white point (XYZ)
+const D50_WHITE: [f64; 3] = [0.3457 / 0.3585, 1.0, (1.0 - 0.3457 - 0.3585) / 0.3585];
+
+/// Constantes CIE Lab / CIE Lab constants
+const LAB_EPSILON: f64 = 216.0 / 24389.0;
+const LAB_KAPPA: f64 = 24389.0 / 27.0;
+
+/// Tolérance pour décider qu'une couleur est dans le gamut sRGB
+/// (absorbe les erreurs d'arrondi des matrices)
+/// Tolerance used to decide that a color is inside the sRGB gamut
+/// (absorbs matrix rounding errors)
+const SRGB_GAMUT_TOLERANCE: f64 = 1e-4;
+
+/// Différence juste perceptible (deltaEOK) de l'algorithme de gamut mapping CSS
+/// Just noticeable difference (deltaEOK) of the CSS gamut mapping algorithm
+const GAMUT_MAPPING_JND: f64 = 0.02;
+
+/// Précision de la recherche de chroma du gamut mapping CSS
+/// Chroma search precision of the CSS gamut mapping algorithm
+const GAMUT_MAPPING_EPSILON: f64 = 0.0001;
+
+/// Nombre de décimales pour les valeurs exposées au frontend
+/// Number of decimals for values exposed to the frontend
+const HUE_DECIMALS: i32 = 1;
+const PERCENT_DECIMALS: i32 = 1;
+const LAB_DECIMALS: i32 = 2;
+const OKLAB_DECIMALS: i32 = 4;
+const XYZ_DECIMALS: i32 = 4;
+
+// =============================================================================
+// STRUCTURES
+// =============================================================================
+
+/// Représentations d'une couleur dans tous les modèles supportés
+/// Representations of a color in every supported model
+///
+/// Les teintes sont en degrés, saturation / clarté / CMJN en pourcentages,
+/// Lab / LCH en unités CIE (D50), OKLab / OKLCH et XYZ (D65) en 0-1.
+/// Hues are in degrees, saturation / lightness / CMYK in percentages,
+/// Lab / LCH in CIE units (D50), OKLab / OKLCH and XYZ (D65) in 0-1.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct ColorModels {
+    /// Teinte, saturation, luminosité / Hue, saturation, lightness
+    pub hsl: [f64; 3],
+    /// Teinte, saturation, valeur / Hue, saturation, value
+    pub hsv: [f64; 3],
+    /// Teinte, blancheur, noirceur / Hue, whiteness, blackness
+    pub hwb: [f64; 3],
+    /// CIE Lab (D50)
+    pub lab: [f64; 3],
+    /// CIE LCH (D50)
+    pub lch: [f64; 3],
+    /// OKLab
+    pub oklab: [f64; 3],
+    /// OKLCH
+    pub oklch: [f64; 3],
+    /// CIE XYZ (D65)
+    pub xyz: [f64; 3],
+    /// Cyan, magenta, jaune, noir / Cyan, magenta, yellow, black
+    pub cmyk: [f64; 4],
+}
+
+impl ColorModels {
+    /// Calcule toutes les représentations d'une couleur sRGB (0.0 - 1.0)
+    /// Computes every representation of an sRGB color (0.0 - 1.0)
+    pub fn from_srgb(rgb: [f64; 3]) -> Self {
+        let hsl = srgb_to_hsl(rgb);
+        let hsv = srgb_to_hsv(rgb);
+        let hwb = srgb_to_hwb(rgb);
+        let xyz = srgb_to_xyz(rgb);
+        let lab = xyz_to_lab(xyz);
+        let lch = lab_to_lch(lab);
+        let oklab = xyz_to_oklab(xyz);
+        let oklch = lab_to_lch(oklab);
+        let cmyk = srgb_to_cmyk(rgb);
+
+        Self {
+            hsl: [round_to(hsl[0], HUE_DECIMALS), round_to(hsl[1], PERCENT_DECIMALS), round_to(hsl[2], PERCENT_DECIMALS)],
+            hsv: [round_to(hsv[0], HUE_DECIMALS), round_to(hsv[1], PERCENT_DECIMALS), round_to(hsv[2], PERCENT_DECIMALS)],
+            hwb: [round_to(hwb[0], HUE_DECIMALS), round_to(hwb[1], PERCENT_DECIMALS), round_to(hwb[2], PERCENT_DECIMALS)],
+            lab: lab.map(|v| round_to(v, LAB_DECIMALS)),
+            lch: [round_to(lch[0], LAB_DECIMALS), round_to(lch[1], LAB_DECIMALS), round_to(lch[2], HUE_DECIMALS)],
+            oklab: oklab.map(|v| round_to(v, OKLAB_DECIMALS)),
+            oklch: [round_to(oklch[0], OKLAB_DECIMALS), round_to(oklch[1], OKLAB_DECIMALS), round_to(oklch[2], HUE_DECIMALS)],
+            xyz: xyz.map(|v| round_to(v, XYZ_DECIMALS)),
+            cmyk: cmyk.map(|v| round_to(v, PERCENT_DECIMALS)),
+        }
+    }
+
+    /// Calcule toutes les représentations d'une couleur RGB 8 bits
+    /// Computes every representation of an 8-bit RGB color
+    pub fn from_rgb8(rgb: (u8, u8, u8)) -> Self {
+        Self::from_srgb(rgb8_to_srgb(rgb))
+    }
+}
+
+/// Espaces RGB prédéfinis de CSS Color 4 (fonction `color()`)
+/// CSS Color 4 predefined RGB spaces (`color()` function)
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum RgbSpace {
+    Srgb,
+    SrgbLinear,
+    DisplayP3,
+    A98Rgb,
+    ProphotoRgb,
+    Rec2020,
+}
+
+impl RgbSpace {
+    /// Retrouve un espace à partir de son nom CSS / Finds a space from its CSS name
+    pub fn from_css_name(name: &str) -> Option<Self> {
+        match name {
+            "srgb" => Some(RgbSpace::Srgb),
+            "srgb-linear" => Some(RgbSpace::SrgbLinear),
+            "display-p3" => Some(RgbSpace::DisplayP3),
+            "a98-rgb" => Some(RgbSpace::A98Rgb),
+            "prophoto-rgb" => Some(RgbSpace::ProphotoRgb),
+            "rec2020" => Some(RgbSpace::Rec2020),
+            _ => None,
+        }
+    }
+
+    /// Convertit des composantes (0.0 - 1.0) de cet espace vers XYZ D65
+    /// Converts components (0.0 - 1.0) of this space to XYZ D65
+    pub fn to_xyz(self, rgb: [f64; 3]) -> [f64; 3] {
+        match self {
+            RgbSpace::Srgb => srgb_to_xyz(rgb),
+            RgbSpace::SrgbLinear => multiply(&LINEAR_SRGB_TO_XYZ, rgb),
+            RgbSpace::DisplayP3 => multiply(&LINEAR_P3_TO_XYZ, rgb.map(srgb_to_linear)),
+            RgbSpace::A98Rgb => multiply(&LINEAR_A98_TO_XYZ, rgb.map(|c| c.signum() * c.abs().powf(563.0 / 256.0))),
+            RgbSpace::ProphotoRgb => {
+                let linear = rgb.map(|c| if c.abs() <= 16.0 / 512.0 { c / 16.0 } else { c.signum() * c.abs().powf(1.8) });
+                multiply(&D50_TO_D65, multiply(&LINEAR_PROPHOTO_TO_XYZ_D50, linear))
+            }
+            RgbSpace::Rec2020 => {
+                let linear = rgb.map(|c| {
+                    let abs = c.abs();
+                    if abs < REC2020_BETA * 4.5 {
+                        c / 4.5
+                    } else {
+                        c.signum() * ((abs + REC2020_ALPHA - 1.0) / REC2020_ALPHA).powf(1.0 / 0.45)
+                    }
+                });
+                multiply(&LINEAR_REC2020_TO_XYZ, linear)
+            }
+        }
+    }
+
+    /// Convertit XYZ D65 vers des composantes de cet espace (non bornées)
+    /// Converts XYZ D65 to components of this space (unclamped)
+    pub fn xyz_to_components(self, xyz: [f64; 3]) -> [f64; 3] {
+        match self {
+            RgbSpace::Srgb => xyz_to_srgb(xyz),
+            RgbSpace::SrgbLinear => multiply(&XYZ_TO_LINEAR_SRGB, xyz),
+            RgbSpace::DisplayP3 => multiply(&invert(&LINEAR_P3_TO_XYZ), xyz).map(linear_to_srgb),
+            RgbSpace::A98Rgb => multiply(&invert(&LINEAR_A98_TO_XYZ), xyz).map(|c| c.signum() * c.abs().powf(256.0 / 563.0)),
+            RgbSpace::ProphotoRgb => {
+                let linear = multiply(&invert(&LINEAR_PROPHOTO_TO_XYZ_D50), multiply(&D65_TO_D50, xyz));
+                linear.map(|c| if c.abs() < 1.0 / 512.0 { c * 16.0 } else { c.signum() * c.abs().powf(1.0 / 1.8) })
+            }
+            RgbSpace::Rec2020 => multiply(&invert(&LINEAR_REC2020_TO_XYZ), xyz).map(|c| {
+                let abs = c.abs();
+                if abs < REC2020_BETA {
+                    c * 4.5
+                } else {
+                    c.signum() * (REC2020_ALPHA * abs.powf(0.45) - (REC2020_ALPHA - 1.0))
+                }
+            }),
+        }
+    }
+}
+
+/// Couleur en virgule flottante, étiquetée avec son espace RGB
+/// Floating point color, tagged with its RGB space
+///
+/// Conserve la valeur exacte saisie ou capturée (par ex. Display P3) au lieu
+/// de l'arrondir immédiatement en sRGB 8 bits.
+/// Keeps the exact entered or captured value (e.g. Display P3) instead of
+/// rounding it to 8-bit sRGB straight away.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct PreciseColor {
+    /// Espace des composantes / Space of the components
+    pub space: RgbSpace,
+
+    /// Composantes encodées (0.0 - 1.0) / Encoded components (0.0 - 1.0)
+    pub components: [f64; 3],
+}
+
+impl Default for PreciseColor {
+    fn default() -> Self {
+        Self::from_srgb([0.0; 3])
+    }
+}
+
+impl PreciseColor {
+    /// Crée une couleur sRGB (non bornée) / Creates an sRGB color (unclamped)
+    pub fn from_srgb(rgb: [f64; 3]) -> Self {
+        Self { space: RgbSpace::Srgb, components: rgb }
+    }
+
+    /// Crée une couleur sRGB à partir de composantes 8 bits
+    /// Creates an sRGB color from 8-bit components
+    pub fn from_rgb8(rgb: (u8, u8, u8)) -> Self {
+        Self::from_srgb(rgb8_to_srgb(rgb))
+    }
+
+    /// Convertit en sRGB étendu (non borné, peut sortir de 0.0 - 1.0)
+    /// Converts to extended sRGB (unclamped, may fall outside 0.0 - 1.0)
+    pub fn to_srgb(self) -> [f64; 3] {
+        match self.space {
+            RgbSpace::Srgb => self.components,
+            space => xyz_to_srgb(space.to_xyz(self.components)),
+        }
+    }
+
+    /// Convertit en sRGB 8 bits, après gamut mapping (affichage uniquement)
+    /// Converts to 8-bit sRGB, after gamut mapping (display only)
+    pub fn to_rgb8(self) -> (u8, u8, u8) {
+        srgb_to_rgb8(self.to_srgb_mapped())
+    }
+
+    /// Indique si la couleur est représentable en sRGB
+    /// Tells whether the color can be represented in sRGB
+    pub fn in_srgb_gamut(self) -> bool {
+        srgb_in_gamut(self.to_srgb())
+    }
+
+    /// Ramène la couleur dans le gamut sRGB (CSS Color 4, réduction de chroma OKLCH)
+    /// Brings the color into the sRGB gamut (CSS Color 4, OKLCH chroma reduction)
+    pub fn to_srgb_mapped(self) -> [f64; 3] {
+        gamut_map_srgb(self.to_srgb())
+    }
+}
+
+/// Couleur saisie dans l'un des modèles supportés
+/// Color entered in one of the supported models
+///
+/// Sérialisée sous la forme `{ "model": "hsl", "values": [210, 50, 40] }`,
+/// avec les mêmes unités que `ColorModels` (RGB en 0-255).
+/// Serialized as `{ "model": "hsl", "values": [210, 50, 40] }`,
+/// with the same units as `ColorModels` (RGB in 0-255).
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(tag = "model", content = "values", rename_all = "lowercase")]
+pub enum ColorInput {
+    Rgb([f64; 3]),
+    Hsl([f64; 3]),
+    Hsv([f64; 3]),
+    Hwb([f64; 3]),
+    Lab([f64; 3]),
+    Lch([f64; 3]),
+    Oklab([f64; 3]),
+    Oklch([f64; 3]),
+    Xyz([f64; 3]),
+    Cmyk([f64; 4]),
+}
+
+impl ColorInput {
+    /// Convertit la saisie en sRGB (0.0 - 1.0, non borné)
+    /// Converts the input to sRGB (0.0 - 1.0, unclamped)
+    pub fn to_srgb(self) -> [f64; 3] {
+        match self {
+            ColorInput::Rgb(rgb) => rgb.map(|c| c / 255.0),
+            ColorInput::Hsl(hsl) => hsl_to_srgb(hsl),
+            ColorInput::Hsv(hsv) => hsv_to_srgb(hsv),
+            ColorInput::Hwb(hwb) => hwb_to_srgb(hwb),
+            ColorInput::Lab(lab) => xyz_to_srgb(lab_to_xyz(lab)),
+            ColorInput::Lch(lch) => xyz_to_srgb(lab_to_xyz(lch_to_lab(lch))),
+            ColorInput::Oklab(oklab) => xyz_to_srgb(oklab_to_xyz(oklab)),
+            ColorInput::Oklch(oklch) => xyz_to_srgb(oklab_to_xyz(lch_to_lab(oklch))),
+            ColorInput::Xyz(xyz) => xyz_to_srgb(xyz),
+            ColorInput::Cmyk(cmyk) => cmyk_to_srgb(cmyk),
+        }
+    }
+}
+
+// =============================================================================
+// FONCTIONS UTILITAIRES
+// UTILITY FUNCTIONS
+// =============================================================================
+
+/// Multiplie une matrice par un vecteur / Multiplies a matrix by a vector
+fn multiply(m: &Matrix, v: [f64; 3]) -> [f64; 3] {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+
+/// Inverse une matrice 3×3 (cofacteurs) / Inverts a 3×3 matrix (cofactors)
+fn invert(m: &Matrix) -> Matrix {
+    let cofactor = |r: usize, c: usize| {
+        let (r1, r2) = ((r + 1) % 3, (r + 2) % 3);
+        let (c1, c2) = ((c + 1) % 3, (c + 2) % 3);
+        m[r1][c1] * m[r2][c2] - m[r1][c2] * m[r2][c1]
+    };
+    let determinant = (0..3).map(|c| m[0][c] * cofactor(0, c)).sum::<f64>();
+    // Transposée des cofacteurs / Transposed cofactors
+    [0, 1, 2].map(|r| [0, 1, 2].map(|c| cofactor(c, r) / determinant))
+}
+
+/// Arrondit une valeur au nombre de décimales donné / Rounds a value to the given decimals
+fn round_to(value: f64, decimals: i32) -> f64 {
+    let factor = 10f64.powi(decimals);
+    let rounded = (value * factor).round() / factor;
+    // Évite "-0" dans l'affichage / Avoid "-0" in display
+    if rounded == 0.0 { 0.0 } else { rounded }
+}
+
+/// Normalise une teinte dans [0, 360) / Normalizes a hue into [0, 360)
+fn normalize_hue(hue: f64) -> f64 {
+    hue.rem_euclid(360.0)
+}
+
+/// Convertit une couleur RGB 8 bits en sRGB (0.0 - 1.0)
+/// Converts an 8-bit RGB color to sRGB (0.0 - 1.0)
+pub fn rgb8_to_srgb(rgb: (u8, u8, u8)) -> [f64; 3] {
+    [rgb.0 as f64 / 255.0, rgb.1 as f64 / 255.0, rgb.2 as f64 / 255.0]
+}
+
+/// Convertit une couleur sRGB (0.0 - 1.0) en RGB 8 bits, avec écrêtage
+/// Converts an sRGB color (0.0 - 1.0) to 8-bit RGB, with clipping
+pub fn srgb_to_rgb8(rgb: [f64; 3]) -> (u8, u8, u8) {
+    let to_u8 = |c: f64| (c * 255.0).round().clamp(0.0, 255.0) as u8;
+    (to_u8(rgb[0]), to_u8(rgb[1]), to_u8(rgb[2]))
+}
+
+// =============================================================================
+// sRGB, XYZ, LAB, OKLAB
+// =============================================================================
+
+/// Linéarise une composante sRGB (conserve le signe)
+/// Linearizes an sRGB component (sign preserving)
+pub fn srgb_to_linear(c: f64) -> f64 {
+    let abs = c.abs();
+    if abs <= 0.04045 {
+        c / 12.92
+    } else {
+        c.signum() * ((abs + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Applique la courbe de transfert sRGB (conserve le signe)
+/// Applies the sRGB transfer curve (sign preserving)
+pub fn linear_to_srgb(c: f64) -> f64 {
+    let abs = c.abs();
+    if abs <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        c.signum() * (1.055 * abs.powf(1.0 / 2.4) - 0.055)
+    }
+}
+
+/// sRGB vers XYZ D65 / sRGB to XYZ D65
+pub fn srgb_to_xyz(rgb: [f64; 3]) -> [f64; 3] {
+    multiply(&LINEAR_SRGB_TO_XYZ, rgb.map(srgb_to_linear))
+}
+
+/// XYZ D65 vers sRGB / XYZ D65 to sRGB
+pub fn xyz_to_srgb(xyz: [f64; 3]) -> [f64; 3] {
+    multiply(&XYZ_TO_LINEAR_SRGB, xyz).map(linear_to_srgb)
+}
+
+/// Luminance relative (Y) d'une couleur sRGB étendue
+/// Relative luminance (Y) of an extended sRGB color
+pub fn srgb_to_luminance(rgb: [f64; 3]) -> f64 {
+    srgb_to_xyz(rgb)[1]
+}
+
+/// Indique si une couleur sRGB étendue est dans le gamut sRGB
+/// Tells whether an extended sRGB color is inside the sRGB gamut
+pub fn srgb_in_gamut(rgb: [f64; 3]) -> bool {
+    rgb.iter().all(|c| (-SRGB_GAMUT_TOLERANCE..=1.0 + SRGB_GAMUT_TOLERANCE).contains(c))
+}
+
+/// Gamut mapping CSS Color 4 vers sRGB : réduit la chroma OKLCH jusqu'à ce que
+/// la couleur écrêtée soit à moins d'une différence juste perceptible (deltaEOK)
+/// CSS Color 4 gamut mapping to sRGB: reduces the OKLCH chroma until the clipped
+/// color is within a just noticeable difference (deltaEOK)
+/// Reference: https://www.w3.org/TR/css-color-4/#binsearch
+pub fn gamut_map_srgb(rgb: [f64; 3]) -> [f64; 3] {
+    let clip = |rgb: [f64; 3]| rgb.map(|c| c.clamp(0.0, 1.0));
+    if srgb_in_gamut(rgb) {
+        return clip(rgb);
+    }
+
+    // Les clartés extrêmes donnent blanc ou noir
+    // Extreme lightnesses give white or black
+    let origin = srgb_to_oklch(rgb);
+    if origin[0] >= 1.0 {
+        return [1.0; 3];
+    }
+    if origin[0] <= 0.0 {
+        return [0.0; 3];
+    }
+
+    let mut min = 0.0;
+    let mut max = origin[1];
+    let mut min_in_gamut = true;
+    let mut current = origin;
+    let mut clipped = clip(rgb);
+    if delta_e_ok(clipped, oklch_to_srgb(current)) < GAMUT_MAPPING_JND {
+        return clipped;
+    }
+
+    // Recherche dichotomique de la chroma / Binary search on chroma
+    while max - min > GAMUT_MAPPING_EPSILON {
+        let chroma = (min + max) / 2.0;
+        current[1] = chroma;
+        let candidate = oklch_to_srgb(current);
+        if min_in_gamut && srgb_in_gamut(candidate) {
+            min = chroma;
+            continue;
+        }
+        clipped = clip(candidate);
+        let error = delta_e_ok(clipped, candidate);
+        if error < GAMUT_MAPPING_JND {
+            if GAMUT_MAPPING_JND - error < GAMUT_MAPPING_EPSILON {
+                return clipped;
+            }
+            min_in_gamut = false;
+            min = chroma;
+        } else {
+            max = chroma;
+        }
+    }
+
+    clipped
+}
+
+/// sRGB étendu vers OKLCH / Extended sRGB to OKLCH
+pub fn srgb_to_oklch(rgb: [f64; 3]) -> [f64; 3] {
+    lab_to_lch(xyz_to_oklab(srgb_to_xyz(rgb)))
+}
+
+/// OKLCH vers sRGB étendu (non borné) / OKLCH to extended sRGB (unclamped)
+pub fn oklch_to_srgb(oklch: [f64; 3]) -> [f64; 3] {
+    xyz_to_srgb(oklab_to_xyz(lch_to_lab(oklch)))
+}
+
+/// Différence de couleur deltaEOK (distance euclidienne dans OKLab)
+/// deltaEOK color difference (Euclidean distance in OKLab)
+pub fn delta_e_ok(a: [f64; 3], b: [f64; 3]) -> f64 {
+    let a = xyz_to_oklab(srgb_to_xyz(a));
+    let b = xyz_to_oklab(srgb_to_xyz(b));
+    ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt()
+}
+
+/// Différence de couleur CIE76 (distance euclidienne dans CIE Lab)
+/// CIE76 color difference (Euclidean distance in CIE Lab)
+pub fn delta_e_76_lab(lab1: [f64; 3], lab2: [f64; 3]) -> f64 {
+    ((lab1[0] - lab2[0]).powi(2) + (lab1[1] - lab2[1]).powi(2) + (lab1[2] - lab2[2]).powi(2)).sqrt()
+}
+
+/// Différence de couleur CIE94 (arts graphiques : kL = 1, K1 = 0.045, K2 = 0.015)
+/// CIE94 color difference (graphic arts: kL = 1, K1 = 0.045, K2 = 0.015)
+///
+/// La formule n'est pas symétrique : `lab1` est la couleur de référence.
+/// The formula is not symmetric: `lab1` is the reference color.
+pub fn delta_e_94_lab(lab1: [f64; 3], lab2: [f64; 3]) -> f64 {
+    let c1 = lab1[1].hypot(lab1[2]);
+    let c2 = lab2[1].hypot(lab2[2]);
+    let delta_l = lab1[0] - lab2[0];
+    let delta_c = c1 - c2;
+
+    // ΔH² = Δa² + Δb² - ΔC², borné à zéro contre les erreurs d'arrondi
+    // ΔH² = Δa² + Δb² - ΔC², bounded at zero against rounding errors
+    let delta_h_squared = ((lab1[1] - lab2[1]).powi(2) + (lab1[2] - lab2[2]).powi(2) - delta_c.powi(2)).max(0.0);
+
+    let s_c = 1.0 + 0.045 * c1;
+    let s_h = 1.0 + 0.015 * c1;
+    (delta_l.powi(2) + (delta_c / s_c).powi(2) + delta_h_squared / s_h.powi(2)).sqrt()
+}
+
+/// Différence de couleur CIEDE2000 entre deux couleurs CIE Lab
+/// CIEDE2000 color difference between two CIE Lab colors
+///
+/// Reference: Sharma, Wu, Dalal (2005), « The CIEDE2000 Color-Difference Formula »
+pub fn delta_e_2000_lab(lab1: [f64; 3], lab2: [f64; 3]) -> f64 {
+    let [l1, a1, b1] = lab1;
+    let [l2, a2, b2] = lab2;
+
+    // Correction de a* près de l'axe neutre / a* correction near the neutral axis
+    let c_mean = ((a1.hypot(b1) + a2.hypot(b2)) / 2.0).powi(7);
+    let g = 0.5 * (1.0 - (c_mean / (c_mean + 25f64.powi(7))).sqrt());
+    let (a1, a2) = (a1 * (1.0 + g), a2 * (1.0 + g));
+    let (c1, c2) = (a1.hypot(b1), a2.hypot(b2));
+    let hue = |a: f64, b: f64| if a == 0.0 && b == 0.0 { 0.0 } else { normalize_hue(b.atan2(a).to_degrees()) };
+    let (h1, h2) = (hue(a1, b1), hue(a2, b2));
+
+    // Différences de clarté, chroma et teinte / Lightness, chroma and hue differences
+    let delta_l = l2 - l1;
+    let delta_c = c2 - c1;
+    let delta_h = if c1 * c2 == 0.0 {
+        0.0
+    } else if (h2 - h1).abs() <= 180.0 {
+        h2 - h1
+    } else if h2 - h1 > 180.0 {
+        h2 - h1 - 360.0
+    } else {
+        h2 - h1 + 360.0
+    };
+    let delta_h = 2.0 * (c1 * c2).sqrt() * (delta_h / 2.0).to_radians().sin();
+
+    // Moyennes / Means
+    let l_mean = (l1 + l2) / 2.0;
+    let c_mean = (c1 + c2) / 2.0;
+    let h_mean = if c1 * c2 == 0.0 {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180.0 {
+        (h1 + h2) / 2.0
+    } else if h1 + h2 < 360.0 {
+        (h1 + h2 + 360.0) / 2.0
+    } else {
+        (h1 + h2 - 360.0) / 2.0
+    };
+
+    // Fonctions de pondération / Weighting functions
+    let t = 1.0 - 0.17 * (h_mean - 30.0).to_radians().cos()
+        + 0.24 * (2.0 * h_mean).to_radians().cos()
+        + 0.32 * (3.0 * h_mean + 6.0).to_radians().cos()
+        - 0.20 * (4.0 * h_mean - 63.0).to_radians().cos();
+    let s_l = 1.0 + 0.015 * (l_mean - 50.0).powi(2) / (20.0 + (l_mean - 50.0).powi(2)).sqrt();
+    let s_c = 1.0 + 0.045 * c_mean;
+    let s_h = 1.0 + 0.015 * c_mean * t;
+    let rotation = 30.0 * (-((h_mean - 275.0) / 25.0).powi(2)).exp();
+    let r_c = 2.0 * (c_mean.powi(7) / (c_mean.powi(7) + 25f64.powi(7))).sqrt();
+    let r_t = -r_c * (2.0 * rotation).to_radians().sin();
+
+    let (l, c, h) = (delta_l / s_l, delta_c / s_c, delta_h / s_h);
+    (l * l + c * c + h * h + r_t * c * h).sqrt()
+}
+
+/// XYZ D65 vers CIE Lab D50 / XYZ D65 to CIE Lab D50
+pub fn xyz_to_lab(xyz: [f64; 3]) -> [f64; 3] {
+    let d50 = multiply(&D65_TO_D50, xyz);
+    let f = |t: f64| if t > LAB_EPSILON { t.cbrt() } else { (LAB_KAPPA * t + 16.0) / 116.0 };
+    let fx = f(d50[0] / D50_WHITE[0]);
+    let fy = f(d50[1] / D50_WHITE[1]);
+    let fz = f(d50[2] / D50_WHITE[2]);
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+/// XYZ D65 vers XYZ D50 (adaptation Bradford) / XYZ D65 to XYZ D50 (Bradford adaptation)
+pub fn xyz_d65_to_d50(xyz: [f64; 3]) -> [f64; 3] {
+    multiply(&D65_TO_D50, xyz)
+}
+
+/// XYZ D50 vers XYZ D65 (adaptation Bradford) / XYZ D50 to XYZ D65 (Bradford adaptation)
+pub fn xyz_d50_to_d65(xyz: [f64; 3]) -> [f64; 3] {
+    multiply(&D50_TO_D65, xyz)
+}
+
+/// CIE Lab D50 vers XYZ D65 / CIE Lab D50 to XYZ D65
+pub fn lab_to_xyz(lab: [f64; 3]) -> [f64; 3] {
+    let fy = (lab[0] + 16.0) / 116.0;
+    let fx = lab[1] / 500.0 + fy;
+    let fz = fy - lab[2] / 200.0;
+    let x = if fx.powi(3) > LAB_EPSILON { fx.powi(3) } else { (116.0 * fx - 16.0) / LAB_KAPPA };
+    let y = if lab[0] > LAB_KAPPA * LAB_EPSILON { fy.powi(3) } else { lab[0] / LAB_KAPPA };
+    let z = if fz.powi(3) > LAB_EPSILON { fz.powi(3) } else { (116.0 * fz - 16.0) / LAB_KAPPA };
+    multiply(&D50_TO_D65, [x * D50_WHITE[0], y * D50_WHITE[1], z * D50_WHITE[2]])
+}
+
+/// XYZ D65 vers OKLab / XYZ D65 to OKLab
+pub fn xyz_to_oklab(xyz: [f64; 3]) -> [f64; 3] {
+    let lms = multiply(&XYZ_TO_LMS, xyz).map(f64::cbrt);
+    multiply(&LMS_TO_OKLAB, lms)
+}
+
+/// OKLab vers XYZ D65 / OKLab to XYZ D65
+pub fn oklab_to_xyz(oklab: [f64; 3]) -> [f64; 3] {
+    let lms = multiply(&OKLAB_TO_LMS, oklab).map(|c| c.powi(3));
+    multiply(&LMS_TO_XYZ, lms)
+}
+
+/// Forme rectangulaire (Lab, OKLab) vers forme polaire (LCH, OKLCH)
+/// Rectangular form (Lab, OKLab) to polar form (LCH, OKLCH)
+pub fn lab_to_lch(lab: [f64; 3]) -> [f64; 3] {
+    let chroma = (lab[1] * lab[1] + lab[2] * lab[2]).sqrt();
+    let hue = normalize_hue(lab[2].atan2(lab[1]).to_degrees());
+    [lab[0], chroma, hue]
+}
+
+/// Forme polaire (LCH, OKLCH) vers forme rectangulaire (Lab, OKLab)
+/// Polar form (LCH, OKLCH) to rectangular form (Lab, OKLab)
+pub fn lch_to_lab(lch: [f64; 3]) -> [f64; 3] {
+    let hue = lch[2].to_radians();
+    [lch[0], lch[1] * hue.cos(), lch[1] * hue.sin()]
+}
+
+// =============================================================================
+// HSL, HSV, HWB, CMYK
+// =============================================================================
+
+/// Teinte (degrés), minimum et maximum d'une couleur sRGB
+/// Hue (degrees), minimum and maximum of an sRGB color
+fn hue_min_max(rgb: [f64; 3]) -> (f64, f64, f64) {
+    let [r, g, b] = rgb;
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    // Teinte indéfinie pour les gris, 0 par convention
+    // Hue is undefined for greys, 0 by convention
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / delta)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+
+    (normalize_hue(hue), min, max)
+}
+
+/// sRGB vers HSL / sRGB to HSL
+pub fn srgb_to_hsl(rgb: [f64; 3]) -> [f64; 3] {
+    let (hue, min, max) = hue_min_max(rgb);
+    let lightness = (max + min) / 2.0;
+    let saturation = if max == min || lightness <= 0.0 || lightness >= 1.0 {
+        0.0
+    } else {
+        (max - lightness) / lightness.min(1.0 - lightness)
+    };
+    [hue, saturation * 100.0, lightness * 100.0]
+}
+
+/// HSL vers sRGB / HSL to sRGB
+pub fn hsl_to_srgb(hsl: [f64; 3]) -> [f64; 3] {
+    let hue = normalize_hue(hsl[0]);
+    let saturation = hsl[1] / 100.0;
+    let lightness = hsl[2] / 100.0;
+    let f = |n: f64| {
+        let k = (n + hue / 30.0) % 12.0;
+        let a = saturation * lightness.min(1.0 - lightness);
+        lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+    [f(0.0), f(8.0), f(4.0)]
+}
+
+/// sRGB vers HSV / sRGB to HSV
+pub fn srgb_to_hsv(rgb: [f64; 3]) -> [f64; 3] {
+    let (hue, min, max) = hue_min_max(rgb);
+    let saturation = if max == 0.0 { 0.0 } else { (max - min) / max };
+    [hue, saturation * 100.0, max * 100.0]
+}
+
+/// HSV vers sRGB / HSV to sRGB
+pub fn hsv_to_srgb(hsv: [f64; 3]) -> [f64; 3] {
+    let hue = normalize_hue(hsv[0]);
+    let saturation = hsv[1] / 100.0;
+    let value = hsv[2] / 100.0;
+    let f = |n: f64| {
+        let k = (n + hue / 60.0) % 6.0;
+        value - value * saturation * k.min(4.0 - k).clamp(0.0, 1.0)
+    };
+    [f(5.0), f(3.0), f(1.0)]
+}
+
+/// sRGB vers HWB / sRGB to HWB
+pub fn srgb_to_hwb(rgb: [f64; 3]) -> [f64; 3] {
+    let (hue, min, max) = hue_min_max(rgb);
+    [hue, min * 100.0, (1.0 - max) * 100.0]
+}
+
+/// HWB vers sRGB / HWB to sRGB
+pub fn hwb_to_srgb(hwb: [f64; 3]) -> [f64; 3] {
+    let whiteness = hwb[1] / 100.0;
+    let blackness = hwb[2] / 100.0;
+
+    // Au-delà de 100 % au total, la couleur est un gris
+    // Beyond 100% in total, the color is a grey
+    if whiteness + blackness >= 1.0 {
+        let grey = whiteness / (whiteness + blackness);
+        return [grey; 3];
+    }
+
+    hsl_to_srgb([hwb[0], 100.0, 50.0]).map(|c| c * (1.0 - whiteness - blackness) + whiteness)
+}
+
+/// sRGB vers CMJN naïf (sans profil d'impression) / sRGB to naive CMYK (no print profile)
+pub fn srgb_to_cmyk(rgb: [f64; 3]) -> [f64; 4] {
+    let [r, g, b] = rgb.map(|c| c.clamp(0.0, 1.0));
+    let k = 1.0 - r.max(g).max(b);
+    if k >= 1.0 {
+        return [0.0, 0.0, 0.0, 100.0];
+    }
+    let c = (1.0 - r - k) / (1.0 - k);
+    let m = (1.0 - g - k) / (1.0 - k);
+    let y = (1.0 - b - k) / (1.0 - k);
+    [c * 100.0, m * 100.0, y * 100.0, k * 100.0]
+}
+
+/// CMJN naïf vers sRGB / Naive CMYK to sRGB
+pub fn cmyk_to_srgb(cmyk: [f64; 4]) -> [f64; 3] {
+    let [c, m, y, k] = cmyk.map(|v| (v / 100.0).clamp(0.0, 1.0));
+    [(1.0 - c) * (1.0 - k), (1.0 - m) * (1.0 - k), (1.0 - y) * (1.0 - k)]
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: [f64; 3], b: [f64; 3], tolerance: f64) {
+        for i in 0..3 {
+            assert!((a[i] - b[i]).abs() < tolerance, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_white_and_black() {
+        let white = ColorModels::from_rgb8((255, 255, 255));
+        assert_eq!(white.lab, [100.0, 0.0, 0.0]);
+        assert_eq!(white.oklab, [1.0, 0.0, 0.0]);
+        assert_eq!(white.hsl, [0.0, 0.0, 100.0]);
+
+        let black = ColorModels::from_rgb8((0, 0, 0));
+        assert_eq!(black.cmyk, [0.0, 0.0, 0.0, 100.0]);
+        assert_eq!(black.hwb, [0.0, 0.0, 100.0]);
+    }
+
+    #[test]
+    fn test_known_values() {
+        // Valeurs de référence CSS Color 4 / CSS Color 4 reference values
+        let red = ColorModels::from_rgb8((255, 0, 0));
+        assert_eq!(red.hsl, [0.0, 100.0, 50.0]);
+        assert_eq!(red.lab, [54.29, 80.8, 69.89]);
+        assert_eq!(red.oklch, [0.628, 0.2577, 29.2]);
+    }
+
+    #[test]
+    fn test_round_trips() {
+        let rgb = [0.2, 0.6, 0.8];
+        assert_close(hsl_to_srgb(srgb_to_hsl(rgb)), rgb, 1e-9);
+        assert_close(hsv_to_srgb(srgb_to_hsv(rgb)), rgb, 1e-9);
+        assert_close(hwb_to_srgb(srgb_to_hwb(rgb)), rgb, 1e-9);
+        assert_close(xyz_to_srgb(lab_to_xyz(xyz_to_lab(srgb_to_xyz(rgb)))), rgb, 1e-6);
+        assert_close(xyz_to_srgb(oklab_to_xyz(xyz_to_oklab(srgb_to_xyz(rgb)))), rgb, 1e-6);
+        assert_close(ColorInput::Oklch(lab_to_lch(xyz_to_oklab(srgb_to_xyz(rgb)))).to_srgb(), rgb, 1e-6);
+
+        // Aller-retour par chaque espace RGB prédéfini / Round trip through each predefined RGB space
+        for space in [RgbSpace::SrgbLinear, RgbSpace::DisplayP3, RgbSpace::A98Rgb, RgbSpace::ProphotoRgb, RgbSpace::Rec2020] {
+            assert_close(space.to_xyz(space.xyz_to_components(srgb_to_xyz(rgb))), srgb_to_xyz(rgb), 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_precise_gamut() {
+        // Le vert P3 pur sort du gamut sRGB, le blanc P3 non
+        // Pure P3 green is outside the sRGB gamut, P3 white is not
+        let p3_green = PreciseColor { space: RgbSpace::DisplayP3, components: [0.0, 1.0, 0.0] };
+        assert!(!p3_green.in_srgb_gamut());
+        assert!(p3_green.to_srgb()[1] > 1.0);
+        let p3_white = PreciseColor { space: RgbSpace::DisplayP3, components: [1.0, 1.0, 1.0] };
+        assert!(p3_white.in_srgb_gamut());
+        assert_eq!(p3_white.to_rgb8(), (255, 255, 255));
+        assert!((srgb_to_luminance(p3_white.to_srgb()) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_delta_e_2000() {
+        // Paires de test de Sharma et al. / Sharma et al. test pairs
+        let pairs = [
+            ([50.0, 2.6772, -79.7751], [50.0, 0.0, -82.7485], 2.0425),
+            ([50.0, 2.5, 0.0], [73.0, 25.0, -18.0], 27.1492),
+            ([50.0, 2.5, 0.0], [50.0, 0.0, -2.5], 4.3065),
+            ([2.0776, 0.0795, -1.135], [0.9033, -0.0636, -0.5514], 0.9082),
+        ];
+        for (lab1, lab2, expected) in pairs {
+            assert!((delta_e_2000_lab(lab1, lab2) - expected).abs() < 1e-4);
+            assert!((delta_e_2000_lab(lab2, lab1) - expected).abs() < 1e-4);
+        }
+        assert_eq!(delta_e_2000_lab([50.0, 2.5, 0.0], [50.0, 2.5, 0.0]), 0.0);
+    }
+
+    #[test]
+    fn test_delta_e_76_94() {
+        let (lab1, lab2) = ([50.0, 2.5, 0.0], [73.0, 25.0, -18.0]);
+        assert!((delta_e_76_lab(lab1, lab2) - 36.8680).abs() < 1e-4);
+        assert!((delta_e_94_lab(lab1, lab2) - 34.6892).abs() < 1e-4);
+
+        // Seule la clarté diffère : les trois formules s'accordent
+        // Only the lightness differs: the three formulas agree
+        let (lab1, lab2) = ([40.0, 0.0, 0.0], [45.0, 0.0, 0.0]);
+        assert_eq!(delta_e_76_lab(lab1, lab2), 5.0);
+        assert_eq!(delta_e_94_lab(lab1, lab2), 5.0);
+    }
+
+    #[test]
+    fn test_gamut_mapping() {
+        // Les couleurs dans le gamut sont inchangées
+        // In-gamut colors are unchanged
+        assert_close(gamut_map_srgb([0.2, 0.6, 0.8]), [0.2, 0.6, 0.8], 1e-12);
+
+        // Le vert P3 est ramené dans sRGB en conservant à peu près sa teinte OKLCH
+        // P3 green is brought into sRGB while roughly keeping its OKLCH hue
+        let p3_green = PreciseColor { space: RgbSpace::DisplayP3, components: [0.0, 1.0, 0.0] };
+        let mapped = p3_green.to_srgb_mapped();
+        assert!(srgb_in_gamut(mapped));
+        let hue = |rgb: [f64; 3]| lab_to_lch(xyz_to_oklab(srgb_to_xyz(rgb)))[2];
+        assert!((hue(mapped) - hue(p3_green.to_srgb())).abs() < 3.0);
+
+        // Au-delà du blanc / Beyond white
+        assert_eq!(gamut_map_srgb([1.2, 1.2, 1.2]), [1.0; 3]);
+    }
+}
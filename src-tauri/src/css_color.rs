@@ -0,0 +1,699 @@
+// =============================================================================
+// css_color.rs - Analyseur de couleurs CSS Color Level 4
+// css_color.rs - CSS Color Level 4 color parser
+// =============================================================================
+//
+// Analyseur strict pour la saisie libre : couleurs hexadécimales (3, 4, 6 ou
+// 8 chiffres), noms CSS, rgb(), hsl(), hwb(), lab(), lch(), oklab(), oklch()
+// et color(). Les erreurs indiquent la position (en caractères) de la partie
+// invalide de la saisie.
+// Strict parser for free text entry: hex colors (3, 4, 6 or 8 digits), CSS
+// names, rgb(), hsl(), hwb(), lab(), lch(), oklab(), oklch() and color().
+// Errors point at the position (in characters) of the invalid part of the input.
+// Reference: https://www.w3.org/TR/css-color-4/
+
+use serde::Serialize;
+use crate::color_names;
+use crate::conversion::{self, PreciseColor, RgbSpace};
+
+// =============================================================================
+// STRUCTURES
+// =============================================================================
+
+/// Couleur analysée / Parsed color
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ParsedColor {
+    /// Couleur exacte ; `color()` conserve son espace (Display P3, Rec. 2020…)
+    /// Exact color; `color()` keeps its space (Display P3, Rec. 2020…)
+    pub color: PreciseColor,
+
+    /// Opacité (0.0 - 1.0) / Opacity (0.0 - 1.0)
+    pub alpha: f64,
+}
+
+/// Type d'erreur d'analyse / Parse error kind
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum ParseErrorKind {
+    /// Saisie vide / Empty input
+    Empty,
+    /// Caractère inattendu / Unexpected character
+    UnexpectedCharacter,
+    /// Couleur hexadécimale invalide / Invalid hex color
+    InvalidHex,
+    /// Nom de couleur inconnu / Unknown color name
+    UnknownColorName,
+    /// Fonction inconnue / Unknown function
+    UnknownFunction,
+    /// Espace colorimétrique inconnu dans color() / Unknown color space in color()
+    UnknownColorSpace,
+    /// Composante de type invalide / Component of invalid type
+    InvalidComponent,
+    /// Nombre de composantes incorrect / Wrong number of components
+    WrongComponentCount,
+    /// Virgules et espaces mélangés / Mixed commas and spaces
+    MixedSyntax,
+    /// Parenthèse fermante manquante / Missing closing parenthesis
+    MissingCloseParenthesis,
+    /// Élément inattendu / Unexpected token
+    UnexpectedToken,
+    /// Case du store inconnue / Unknown store slot
+    UnknownKey,
+}
+
+/// Erreur d'analyse avec la position de la partie invalide
+/// Parse error with the position of the invalid part
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct ParseError {
+    /// Type d'erreur (identifiant stable pour la traduction)
+    /// Error kind (stable identifier for translation)
+    pub kind: ParseErrorKind,
+
+    /// Message lisible / Human-readable message
+    pub message: String,
+
+    /// Début de la partie invalide (index de caractère)
+    /// Start of the invalid part (character index)
+    pub start: usize,
+
+    /// Fin de la partie invalide (index de caractère, exclusif)
+    /// End of the invalid part (character index, exclusive)
+    pub end: usize,
+}
+
+impl ParseError {
+    pub(crate) fn new(kind: ParseErrorKind, message: impl Into<String>, start: usize, end: usize) -> Self {
+        Self { kind, message: message.into(), start, end }
+    }
+}
+
+/// Élément lexical / Lexical token
+#[derive(Clone, Debug, PartialEq)]
+enum TokenKind {
+    Ident(String),
+    Function(String),
+    Number(f64),
+    Percentage(f64),
+    Dimension(f64, String),
+    Hash(String),
+    Comma,
+    Slash,
+    CloseParen,
+}
+
+/// Élément lexical et sa position / Lexical token and its position
+#[derive(Clone, Debug)]
+struct Token {
+    kind: TokenKind,
+    start: usize,
+    end: usize,
+}
+
+/// Valeur d'une composante avant résolution
+/// Component value before resolution
+#[derive(Clone, Copy, Debug)]
+enum Component {
+    Number(f64),
+    Percentage(f64),
+    /// Angle en degrés / Angle in degrees
+    Angle(f64),
+    /// Mot-clé `none` / `none` keyword
+    None,
+}
+
+/// Composante et sa position / Component and its position
+#[derive(Clone, Copy, Debug)]
+struct Arg {
+    value: Component,
+    start: usize,
+    end: usize,
+}
+
+// =============================================================================
+// ANALYSE LEXICALE
+// TOKENIZER
+// =============================================================================
+
+/// Découpe la saisie en éléments lexicaux / Splits the input into tokens
+fn tokenize(input: &str) -> Result<Vec<Token>, ParseError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    let is_ident_start = |c: char| c.is_ascii_alphabetic() || c == '_';
+    let is_ident_char = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_';
+
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        let starts_number = c.is_ascii_digit()
+            || (c == '.' && chars.get(i + 1).is_some_and(|n| n.is_ascii_digit()))
+            || ((c == '+' || c == '-')
+                && (chars.get(i + 1).is_some_and(|n| n.is_ascii_digit())
+                    || (chars.get(i + 1) == Some(&'.') && chars.get(i + 2).is_some_and(|n| n.is_ascii_digit()))));
+
+        let kind = if starts_number {
+            // Nombre : signe, partie entière, décimale et exposant
+            // Number: sign, integer part, decimals and exponent
+            if c == '+' || c == '-' {
+                i += 1;
+            }
+            while i < chars.len() && chars[i].is_ascii_digit() {
+                i += 1;
+            }
+            if i < chars.len() && chars[i] == '.' && chars.get(i + 1).is_some_and(|n| n.is_ascii_digit()) {
+                i += 1;
+                while i < chars.len() && chars[i].is_ascii_digit() {
+                    i += 1;
+                }
+            }
+            if i < chars.len() && (chars[i] == 'e' || chars[i] == 'E') {
+                let mut j = i + 1;
+                if j < chars.len() && (chars[j] == '+' || chars[j] == '-') {
+                    j += 1;
+                }
+                if j < chars.len() && chars[j].is_ascii_digit() {
+                    i = j;
+                    while i < chars.len() && chars[i].is_ascii_digit() {
+                        i += 1;
+                    }
+                }
+            }
+            let text: String = chars[start..i].iter().collect();
+            let value: f64 = text.parse().map_err(|_| {
+                ParseError::new(ParseErrorKind::InvalidComponent, format!("Invalid number \"{}\"", text), start, i)
+            })?;
+
+            // Suffixe : pourcentage ou unité / Suffix: percentage or unit
+            if i < chars.len() && chars[i] == '%' {
+                i += 1;
+                TokenKind::Percentage(value)
+            } else if i < chars.len() && is_ident_start(chars[i]) {
+                let unit_start = i;
+                while i < chars.len() && is_ident_char(chars[i]) {
+                    i += 1;
+                }
+                TokenKind::Dimension(value, chars[unit_start..i].iter().collect::<String>().to_ascii_lowercase())
+            } else {
+                TokenKind::Number(value)
+            }
+        } else if is_ident_start(c) || (c == '-' && chars.get(i + 1).is_some_and(|n| is_ident_start(*n) || *n == '-')) {
+            // Identifiant ou fonction / Identifier or function
+            while i < chars.len() && is_ident_char(chars[i]) {
+                i += 1;
+            }
+            let name = chars[start..i].iter().collect::<String>().to_ascii_lowercase();
+            if i < chars.len() && chars[i] == '(' {
+                i += 1;
+                TokenKind::Function(name)
+            } else {
+                TokenKind::Ident(name)
+            }
+        } else if c == '#' {
+            // Couleur hexadécimale (validée plus tard) / Hex color (validated later)
+            i += 1;
+            while i < chars.len() && chars[i].is_ascii_alphanumeric() {
+                i += 1;
+            }
+            TokenKind::Hash(chars[start + 1..i].iter().collect())
+        } else {
+            i += 1;
+            match c {
+                ',' => TokenKind::Comma,
+                '/' => TokenKind::Slash,
+                ')' => TokenKind::CloseParen,
+                _ => {
+                    return Err(ParseError::new(
+                        ParseErrorKind::UnexpectedCharacter,
+                        format!("Unexpected character \"{}\"", c),
+                        start,
+                        i,
+                    ))
+                }
+            }
+        };
+
+        tokens.push(Token { kind, start, end: i });
+    }
+
+    Ok(tokens)
+}
+
+// =============================================================================
+// ANALYSE SYNTAXIQUE
+// PARSER
+// =============================================================================
+
+/// Analyse une couleur CSS / Parses a CSS color
+///
+/// # Arguments
+/// * `input` - Saisie libre, par ex. `rgb(12 34 56 / 50%)` / Free text, e.g. `rgb(12 34 56 / 50%)`
+pub fn parse(input: &str) -> Result<ParsedColor, ParseError> {
+    let tokens = tokenize(input)?;
+    let length = input.chars().count();
+
+    let first = match tokens.first() {
+        Some(token) => token,
+        None => return Err(ParseError::new(ParseErrorKind::Empty, "Empty colour", 0, length)),
+    };
+
+    let (color, consumed) = match &first.kind {
+        TokenKind::Hash(digits) => (parse_hex(digits, first)?, 1),
+        TokenKind::Ident(name) => (parse_name(name, first)?, 1),
+        TokenKind::Function(name) => parse_function(name, &tokens)?,
+        _ => {
+            return Err(ParseError::new(ParseErrorKind::UnexpectedToken, "Expected a colour", first.start, first.end))
+        }
+    };
+
+    // Rien ne doit suivre la couleur / Nothing may follow the color
+    if let Some(extra) = tokens.get(consumed) {
+        return Err(ParseError::new(
+            ParseErrorKind::UnexpectedToken,
+            "Unexpected input after the colour",
+            extra.start,
+            tokens.last().map_or(extra.end, |t| t.end),
+        ));
+    }
+
+    Ok(color)
+}
+
+/// Analyse une couleur hexadécimale (#rgb, #rgba, #rrggbb, #rrggbbaa)
+/// Parses a hex color (#rgb, #rgba, #rrggbb, #rrggbbaa)
+fn parse_hex(digits: &str, token: &Token) -> Result<ParsedColor, ParseError> {
+    if let Some(offset) = digits.chars().position(|c| !c.is_ascii_hexdigit()) {
+        let position = token.start + 1 + offset;
+        return Err(ParseError::new(ParseErrorKind::InvalidHex, "Invalid hexadecimal digit", position, position + 1));
+    }
+
+    let values: Vec<u8> = match digits.len() {
+        // Forme courte : chaque chiffre est doublé / Short form: each digit is doubled
+        3 | 4 => digits.chars().map(|c| (c.to_digit(16).unwrap() * 17) as u8).collect(),
+        6 | 8 => (0..digits.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&digits[i..i + 2], 16).unwrap())
+            .collect(),
+        _ => {
+            return Err(ParseError::new(
+                ParseErrorKind::InvalidHex,
+                "Hex colours must have 3, 4, 6 or 8 digits",
+                token.start,
+                token.end,
+            ))
+        }
+    };
+
+    Ok(ParsedColor {
+        color: PreciseColor::from_rgb8((values[0], values[1], values[2])),
+        alpha: values.get(3).map_or(1.0, |a| *a as f64 / 255.0),
+    })
+}
+
+/// Analyse un nom de couleur CSS / Parses a CSS color name
+fn parse_name(name: &str, token: &Token) -> Result<ParsedColor, ParseError> {
+    if name == "transparent" {
+        return Ok(ParsedColor { color: PreciseColor::default(), alpha: 0.0 });
+    }
+
+    match color_names::color_by_name(name) {
+        Some(rgb) => Ok(ParsedColor { color: PreciseColor::from_rgb8(rgb), alpha: 1.0 }),
+        None => Err(ParseError::new(
+            ParseErrorKind::UnknownColorName,
+            format!("Unknown colour name \"{}\"", name),
+            token.start,
+            token.end,
+        )),
+    }
+}
+
+/// Analyse une fonction de couleur et retourne le nombre d'éléments consommés
+/// Parses a color function and returns the number of consumed tokens
+fn parse_function(name: &str, tokens: &[Token]) -> Result<(ParsedColor, usize), ParseError> {
+    let function = &tokens[0];
+
+    // Trouve la parenthèse fermante / Find the closing parenthesis
+    let close = match tokens.iter().position(|t| t.kind == TokenKind::CloseParen) {
+        Some(index) => index,
+        None => {
+            return Err(ParseError::new(
+                ParseErrorKind::MissingCloseParenthesis,
+                "Missing closing parenthesis",
+                function.start,
+                tokens.last().map_or(function.end, |t| t.end),
+            ))
+        }
+    };
+    let body = &tokens[1..close];
+
+    // Fonctions imbriquées (calc(), var()…) non supportées
+    // Nested functions (calc(), var()…) are not supported
+    if let Some(nested) = body.iter().find(|t| matches!(t.kind, TokenKind::Function(_))) {
+        return Err(ParseError::new(ParseErrorKind::UnknownFunction, "Nested functions are not supported", nested.start, nested.end));
+    }
+
+    let color = match name {
+        "rgb" | "rgba" => parse_rgb(function, body)?,
+        "hsl" | "hsla" => parse_hsl(function, body)?,
+        "hwb" => parse_hwb(function, body)?,
+        "lab" | "lch" | "oklab" | "oklch" => parse_lab_like(name, function, body)?,
+        "color" => parse_color_function(function, body)?,
+        _ => {
+            return Err(ParseError::new(
+                ParseErrorKind::UnknownFunction,
+                format!("Unknown colour function \"{}()\"", name),
+                function.start,
+                function.end,
+            ))
+        }
+    };
+
+    Ok((color, close + 1))
+}
+
+/// Convertit un élément en composante / Converts a token into a component
+fn to_arg(token: &Token) -> Result<Arg, ParseError> {
+    let value = match &token.kind {
+        TokenKind::Number(v) => Component::Number(*v),
+        TokenKind::Percentage(v) => Component::Percentage(*v),
+        TokenKind::Dimension(v, unit) => match unit.as_str() {
+            "deg" => Component::Angle(*v),
+            "rad" => Component::Angle(v.to_degrees()),
+            "grad" => Component::Angle(v * 0.9),
+            "turn" => Component::Angle(v * 360.0),
+            _ => {
+                return Err(ParseError::new(
+                    ParseErrorKind::InvalidComponent,
+                    format!("Unknown unit \"{}\"", unit),
+                    token.start,
+                    token.end,
+                ))
+            }
+        },
+        TokenKind::Ident(name) if name == "none" => Component::None,
+        _ => return Err(ParseError::new(ParseErrorKind::UnexpectedToken, "Expected a number", token.start, token.end)),
+    };
+    Ok(Arg { value, start: token.start, end: token.end })
+}
+
+/// Arguments d'une fonction : composantes, opacité et syntaxe utilisée
+/// Function arguments: components, alpha and syntax used
+struct Args {
+    components: Vec<Arg>,
+    alpha: Option<Arg>,
+    legacy: bool,
+}
+
+/// Découpe le corps d'une fonction en composantes et opacité
+/// Splits a function body into components and alpha
+///
+/// # Arguments
+/// * `allow_legacy` - Autorise la syntaxe à virgules (rgb, hsl) / Allows the comma syntax (rgb, hsl)
+fn split_args(function: &Token, body: &[Token], count: usize, allow_legacy: bool) -> Result<Args, ParseError> {
+    let end = body.last().map_or(function.end, |t| t.end);
+    let has_comma = body.iter().any(|t| t.kind == TokenKind::Comma);
+
+    let (components, alpha) = if has_comma {
+        if !allow_legacy {
+            let comma = body.iter().find(|t| t.kind == TokenKind::Comma).unwrap();
+            return Err(ParseError::new(ParseErrorKind::MixedSyntax, "Commas are not allowed in this function", comma.start, comma.end));
+        }
+        // Syntaxe historique : a, b, c[, alpha]
+        // Legacy syntax: a, b, c[, alpha]
+        let mut values = Vec::new();
+        for (index, token) in body.iter().enumerate() {
+            let expect_value = index % 2 == 0;
+            match (&token.kind, expect_value) {
+                (TokenKind::Comma, false) => {}
+                (TokenKind::Comma, true) | (TokenKind::Slash, _) => {
+                    return Err(ParseError::new(ParseErrorKind::MixedSyntax, "Unexpected separator", token.start, token.end))
+                }
+                (_, true) => values.push(to_arg(token)?),
+                (_, false) => {
+                    return Err(ParseError::new(ParseErrorKind::MixedSyntax, "Expected a comma", token.start, token.end))
+                }
+            }
+        }
+        if body.last().is_some_and(|t| t.kind == TokenKind::Comma) || !(count..=count + 1).contains(&values.len()) {
+            return Err(ParseError::new(
+                ParseErrorKind::WrongComponentCount,
+                format!("Expected {} components and an optional alpha", count),
+                function.start,
+                end,
+            ));
+        }
+        let alpha = if values.len() > count { values.pop() } else { None };
+        (values, alpha)
+    } else {
+        // Syntaxe moderne : a b c [/ alpha]
+        // Modern syntax: a b c [/ alpha]
+        let slash = body.iter().position(|t| t.kind == TokenKind::Slash);
+        let (main, rest) = match slash {
+            Some(index) => (&body[..index], Some(&body[index + 1..])),
+            None => (body, None),
+        };
+        let components = main.iter().map(to_arg).collect::<Result<Vec<_>, _>>()?;
+        if components.len() != count {
+            return Err(ParseError::new(
+                ParseErrorKind::WrongComponentCount,
+                format!("Expected {} components, found {}", count, components.len()),
+                function.start,
+                end,
+            ));
+        }
+        let alpha = match rest {
+            Some([token]) => Some(to_arg(token)?),
+            Some(_) => {
+                let slash_token = &body[slash.unwrap()];
+                return Err(ParseError::new(ParseErrorKind::WrongComponentCount, "Expected a single alpha value after \"/\"", slash_token.start, end));
+            }
+            None => None,
+        };
+        (components, alpha)
+    };
+
+    Ok(Args { components, alpha, legacy: has_comma })
+}
+
+/// Erreur de type de composante / Component type error
+fn invalid(arg: &Arg, expected: &str) -> ParseError {
+    ParseError::new(ParseErrorKind::InvalidComponent, format!("Expected {}", expected), arg.start, arg.end)
+}
+
+/// Résout une composante nombre ou pourcentage
+/// Resolves a number or percentage component
+///
+/// # Arguments
+/// * `percent_scale` - Valeur correspondant à 100 % / Value matching 100%
+fn number_or_percentage(arg: &Arg, percent_scale: f64) -> Result<f64, ParseError> {
+    match arg.value {
+        Component::Number(v) => Ok(v),
+        Component::Percentage(v) => Ok(v / 100.0 * percent_scale),
+        Component::None => Ok(0.0),
+        Component::Angle(_) => Err(invalid(arg, "a number or a percentage")),
+    }
+}
+
+/// Résout une teinte (nombre ou angle) en degrés / Resolves a hue (number or angle) in degrees
+fn hue(arg: &Arg) -> Result<f64, ParseError> {
+    match arg.value {
+        Component::Number(v) | Component::Angle(v) => Ok(v),
+        Component::None => Ok(0.0),
+        Component::Percentage(_) => Err(invalid(arg, "a hue (number or angle)")),
+    }
+}
+
+/// Résout l'opacité (1.0 par défaut) / Resolves alpha (1.0 by default)
+fn alpha(arg: &Option<Arg>) -> Result<f64, ParseError> {
+    match arg {
+        Some(arg) => Ok(number_or_percentage(arg, 1.0)?.clamp(0.0, 1.0)),
+        None => Ok(1.0),
+    }
+}
+
+/// Vérifie que la syntaxe historique n'utilise ni `none` ni types mélangés
+/// Checks that the legacy syntax uses neither `none` nor mixed types
+fn check_legacy(args: &Args, percentages: Option<bool>) -> Result<(), ParseError> {
+    if !args.legacy {
+        return Ok(());
+    }
+    for arg in args.components.iter().chain(args.alpha.iter()) {
+        if matches!(arg.value, Component::None) {
+            return Err(invalid(arg, "a value (\"none\" is not allowed with commas)"));
+        }
+    }
+    // rgb() : composantes toutes en nombres ou toutes en pourcentages
+    // rgb(): components all numbers or all percentages
+    if percentages.is_none() {
+        let first_is_percent = matches!(args.components[0].value, Component::Percentage(_));
+        for arg in &args.components[1..] {
+            if matches!(arg.value, Component::Percentage(_)) != first_is_percent {
+                return Err(ParseError::new(ParseErrorKind::MixedSyntax, "Cannot mix numbers and percentages with commas", arg.start, arg.end));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Analyse rgb() et rgba() / Parses rgb() and rgba()
+fn parse_rgb(function: &Token, body: &[Token]) -> Result<ParsedColor, ParseError> {
+    let args = split_args(function, body, 3, true)?;
+    check_legacy(&args, None)?;
+    let mut srgb = [0.0; 3];
+    for (i, arg) in args.components.iter().enumerate() {
+        srgb[i] = (number_or_percentage(arg, 255.0)? / 255.0).clamp(0.0, 1.0);
+    }
+    Ok(ParsedColor { color: PreciseColor::from_srgb(srgb), alpha: alpha(&args.alpha)? })
+}
+
+/// Analyse hsl() et hsla() / Parses hsl() and hsla()
+fn parse_hsl(function: &Token, body: &[Token]) -> Result<ParsedColor, ParseError> {
+    let args = split_args(function, body, 3, true)?;
+    check_legacy(&args, Some(true))?;
+    let h = hue(&args.components[0])?;
+    let mut sl = [0.0; 2];
+    for (i, arg) in args.components[1..].iter().enumerate() {
+        // Avec virgules, saturation et luminosité doivent être des pourcentages
+        // With commas, saturation and lightness must be percentages
+        if args.legacy && !matches!(arg.value, Component::Percentage(_)) {
+            return Err(invalid(arg, "a percentage"));
+        }
+        sl[i] = number_or_percentage(arg, 100.0)?.clamp(0.0, 100.0);
+    }
+    Ok(ParsedColor { color: PreciseColor::from_srgb(conversion::hsl_to_srgb([h, sl[0], sl[1]])), alpha: alpha(&args.alpha)? })
+}
+
+/// Analyse hwb() / Parses hwb()
+fn parse_hwb(function: &Token, body: &[Token]) -> Result<ParsedColor, ParseError> {
+    let args = split_args(function, body, 3, false)?;
+    let h = hue(&args.components[0])?;
+    let w = number_or_percentage(&args.components[1], 100.0)?.clamp(0.0, 100.0);
+    let b = number_or_percentage(&args.components[2], 100.0)?.clamp(0.0, 100.0);
+    Ok(ParsedColor { color: PreciseColor::from_srgb(conversion::hwb_to_srgb([h, w, b])), alpha: alpha(&args.alpha)? })
+}
+
+/// Analyse lab(), lch(), oklab() et oklch() / Parses lab(), lch(), oklab() and oklch()
+fn parse_lab_like(name: &str, function: &Token, body: &[Token]) -> Result<ParsedColor, ParseError> {
+    let args = split_args(function, body, 3, false)?;
+    let [l_arg, c1, c2] = [&args.components[0], &args.components[1], &args.components[2]];
+
+    // Échelles des pourcentages définies par CSS Color 4
+    // Percentage scales defined by CSS Color 4
+    let (l_scale, ab_scale, chroma_scale) = if name.starts_with("ok") { (1.0, 0.4, 0.4) } else { (100.0, 125.0, 150.0) };
+    let lightness = number_or_percentage(l_arg, l_scale)?.clamp(0.0, l_scale);
+
+    let xyz = match name {
+        "lab" => conversion::lab_to_xyz([lightness, number_or_percentage(c1, ab_scale)?, number_or_percentage(c2, ab_scale)?]),
+        "oklab" => conversion::oklab_to_xyz([lightness, number_or_percentage(c1, ab_scale)?, number_or_percentage(c2, ab_scale)?]),
+        _ => {
+            let chroma = number_or_percentage(c1, chroma_scale)?.max(0.0);
+            let lab = conversion::lch_to_lab([lightness, chroma, hue(c2)?]);
+            if name == "lch" { conversion::lab_to_xyz(lab) } else { conversion::oklab_to_xyz(lab) }
+        }
+    };
+
+    Ok(ParsedColor { color: PreciseColor::from_srgb(conversion::xyz_to_srgb(xyz)), alpha: alpha(&args.alpha)? })
+}
+
+/// Analyse color(<espace> c1 c2 c3 [/ alpha]) / Parses color(<space> c1 c2 c3 [/ alpha])
+fn parse_color_function(function: &Token, body: &[Token]) -> Result<ParsedColor, ParseError> {
+    let (space_token, rest) = match body.split_first() {
+        Some(split) => split,
+        None => return Err(ParseError::new(ParseErrorKind::WrongComponentCount, "Expected a colour space", function.start, function.end)),
+    };
+    let space = match &space_token.kind {
+        TokenKind::Ident(name) => name.as_str(),
+        _ => return Err(ParseError::new(ParseErrorKind::UnknownColorSpace, "Expected a colour space", space_token.start, space_token.end)),
+    };
+
+    let args = split_args(function, rest, 3, false)?;
+    let mut values = [0.0; 3];
+    for (i, arg) in args.components.iter().enumerate() {
+        values[i] = number_or_percentage(arg, 1.0)?;
+    }
+
+    // Les espaces RGB conservent leurs composantes exactes
+    // RGB spaces keep their exact components
+    let color = match space {
+        "xyz" | "xyz-d65" => PreciseColor::from_srgb(conversion::xyz_to_srgb(values)),
+        "xyz-d50" => PreciseColor::from_srgb(conversion::xyz_to_srgb(conversion::xyz_d50_to_d65(values))),
+        _ => match RgbSpace::from_css_name(space) {
+            Some(rgb_space) => PreciseColor { space: rgb_space, components: values },
+            None => {
+                return Err(ParseError::new(
+                    ParseErrorKind::UnknownColorSpace,
+                    format!("Unknown colour space \"{}\"", space),
+                    space_token.start,
+                    space_token.end,
+                ))
+            }
+        },
+    };
+
+    Ok(ParsedColor { color, alpha: alpha(&args.alpha)? })
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rgb8(input: &str) -> ((u8, u8, u8), f64) {
+        let parsed = parse(input).unwrap();
+        (parsed.color.to_rgb8(), parsed.alpha)
+    }
+
+    fn error(input: &str) -> (ParseErrorKind, usize, usize) {
+        let e = parse(input).unwrap_err();
+        (e.kind, e.start, e.end)
+    }
+
+    #[test]
+    fn test_hex_and_names() {
+        assert_eq!(rgb8("#0a3"), ((0, 170, 51), 1.0));
+        assert_eq!(rgb8("#FF000080").0, (255, 0, 0));
+        assert!((rgb8("#f008").1 - 136.0 / 255.0).abs() < 1e-9);
+        assert_eq!(rgb8("  RebeccaPurple "), ((102, 51, 153), 1.0));
+        assert_eq!(rgb8("transparent").1, 0.0);
+    }
+
+    #[test]
+    fn test_functions() {
+        assert_eq!(rgb8("rgb(12 34 56 / 50%)"), ((12, 34, 56), 0.5));
+        assert_eq!(rgb8("rgba(255, 0, 0, 0.25)"), ((255, 0, 0), 0.25));
+        assert_eq!(rgb8("hsl(120deg 100% 25%)"), ((0, 128, 0), 1.0));
+        assert_eq!(rgb8("hsl(0.5turn, 100%, 50%)").0, (0, 255, 255));
+        assert_eq!(rgb8("hwb(0 0% 0%)").0, (255, 0, 0));
+        assert_eq!(rgb8("lab(54.29 80.8 69.89)").0, (255, 0, 0));
+        assert_eq!(rgb8("oklch(62.8% 0.2577 29.23)").0, (255, 0, 0));
+        assert_eq!(rgb8("color(srgb 1 0.6 0)").0, (255, 153, 0));
+        assert_eq!(rgb8("color(display-p3 1 1 1)").0, (255, 255, 255));
+        assert_eq!(parse("color(display-p3 0 1 0)").unwrap().color.space, RgbSpace::DisplayP3);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(error(""), (ParseErrorKind::Empty, 0, 0));
+        assert_eq!(error("#12g"), (ParseErrorKind::InvalidHex, 3, 4));
+        assert_eq!(error("#12345"), (ParseErrorKind::InvalidHex, 0, 6));
+        assert_eq!(error("reddish"), (ParseErrorKind::UnknownColorName, 0, 7));
+        assert_eq!(error("rgb(1, 2 3)"), (ParseErrorKind::MixedSyntax, 9, 10));
+        assert_eq!(error("rgb(1, 50%, 3)"), (ParseErrorKind::MixedSyntax, 7, 10));
+        assert_eq!(error("hwb(0, 0%, 0%)"), (ParseErrorKind::MixedSyntax, 5, 6));
+        assert_eq!(error("rgb(1 2)"), (ParseErrorKind::WrongComponentCount, 0, 7));
+        assert_eq!(error("rgb(1 2 3"), (ParseErrorKind::MissingCloseParenthesis, 0, 9));
+        assert_eq!(error("color(foo 1 2 3)"), (ParseErrorKind::UnknownColorSpace, 6, 9));
+        assert_eq!(error("hsl(10% 50% 50%)"), (ParseErrorKind::InvalidComponent, 4, 7));
+        assert_eq!(error("red blue"), (ParseErrorKind::UnexpectedToken, 4, 8));
+        assert_eq!(error("rgb(1 2 3) ;"), (ParseErrorKind::UnexpectedCharacter, 11, 12));
+    }
+}
@@ -0,0 +1,310 @@
+// =============================================================================
+// cvd.rs - Simulation des déficiences de la vision des couleurs
+// cvd.rs - Colour vision deficiency simulation
+// =============================================================================
+//
+// Simule la protanopie, la deutéranopie, la tritanopie (et les trichromaties
+// anormales correspondantes) avec le modèle de Machado, Oliveira et Fernandes
+// (2009), ainsi que l'achromatopsie, puis calcule le contraste WCAG 2 et APCA
+// de chaque simulation.
+// Simulates protanopia, deuteranopia, tritanopia (and the matching anomalous
+// trichromacies) with the Machado, Oliveira and Fernandes (2009) model, as well
+// as achromatopsia, then computes the WCAG 2 and APCA contrast of each simulation.
+// Reference: https://www.inf.ufrgs.br/~oliveira/pubs_files/CVD_Simulation/CVD_Simulation.html
+
+use serde::{Deserialize, Serialize};
+use crate::apca;
+use crate::color;
+use crate::config;
+use crate::conversion;
+use crate::picker::common::format_hex_color;
+use crate::store::AppState;
+
+// =============================================================================
+// MATRICES DE MACHADO (sévérité 0.0 à 1.0 par pas de 0.1, RGB linéaire)
+// MACHADO MATRICES (severity 0.0 to 1.0 in 0.1 steps, linear RGB)
+// =============================================================================
+
+/// Matrice 3x3 (lignes) / 3x3 matrix (rows)
+type Matrix = [[f64; 3]; 3];
+
+/// Matrice identité (vision typique) / Identity matrix (typical vision)
+const IDENTITY: Matrix = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+/// Protanomalie puis protanopie / Protanomaly then protanopia
+const PROTAN: [Matrix; 11] = [
+    IDENTITY,
+    [[0.856_167, 0.182_038, -0.038_205], [0.029_342, 0.955_115, 0.015_544], [-0.002_880, -0.001_563, 1.004_443]],
+    [[0.734_766, 0.334_872, -0.069_637], [0.051_840, 0.919_198, 0.028_963], [-0.004_928, -0.004_209, 1.009_137]],
+    [[0.630_323, 0.465_641, -0.095_964], [0.069_181, 0.890_046, 0.040_773], [-0.006_308, -0.007_724, 1.014_032]],
+    [[0.539_009, 0.579_343, -0.118_352], [0.082_546, 0.866_121, 0.051_332], [-0.007_136, -0.011_959, 1.019_095]],
+    [[0.458_064, 0.679_578, -0.137_642], [0.092_785, 0.846_313, 0.060_902], [-0.007_494, -0.016_807, 1.024_301]],
+    [[0.385_450, 0.769_005, -0.154_455], [0.100_526, 0.829_802, 0.069_673], [-0.007_442, -0.022_190, 1.029_632]],
+    [[0.319_627, 0.849_633, -0.169_261], [0.106_241, 0.815_969, 0.077_790], [-0.007_025, -0.028_051, 1.035_076]],
+    [[0.259_411, 0.923_008, -0.182_420], [0.110_296, 0.804_340, 0.085_364], [-0.006_276, -0.034_346, 1.040_622]],
+    [[0.203_876, 0.990_338, -0.194_214], [0.112_975, 0.794_542, 0.092_483], [-0.005_222, -0.041_043, 1.046_265]],
+    [[0.152_286, 1.052_583, -0.204_868], [0.114_503, 0.786_281, 0.099_216], [-0.003_882, -0.048_116, 1.051_998]],
+];
+
+/// Deutéranomalie puis deutéranopie / Deuteranomaly then deuteranopia
+const DEUTAN: [Matrix; 11] = [
+    IDENTITY,
+    [[0.866_435, 0.177_704, -0.044_139], [0.049_567, 0.939_063, 0.011_370], [-0.003_453, 0.007_233, 0.996_220]],
+    [[0.760_729, 0.319_078, -0.079_807], [0.090_568, 0.889_315, 0.020_117], [-0.006_027, 0.013_325, 0.992_702]],
+    [[0.675_425, 0.433_850, -0.109_275], [0.125_303, 0.847_755, 0.026_942], [-0.007_950, 0.018_572, 0.989_378]],
+    [[0.605_511, 0.528_560, -0.134_071], [0.155_318, 0.812_366, 0.032_316], [-0.009_376, 0.023_176, 0.986_200]],
+    [[0.547_494, 0.607_765, -0.155_259], [0.181_692, 0.781_742, 0.036_566], [-0.010_410, 0.027_275, 0.983_136]],
+    [[0.498_864, 0.674_741, -0.173_604], [0.205_199, 0.754_872, 0.039_929], [-0.011_131, 0.030_969, 0.980_162]],
+    [[0.457_771, 0.731_899, -0.189_670], [0.226_409, 0.731_012, 0.042_579], [-0.011_595, 0.034_333, 0.977_261]],
+    [[0.422_823, 0.781_057, -0.203_881], [0.245_752, 0.709_602, 0.044_646], [-0.011_843, 0.037_423, 0.974_421]],
+    [[0.392_952, 0.823_610, -0.216_562], [0.263_559, 0.690_210, 0.046_232], [-0.011_910, 0.040_281, 0.971_630]],
+    [[0.367_322, 0.860_646, -0.227_968], [0.280_085, 0.672_501, 0.047_413], [-0.011_820, 0.042_940, 0.968_881]],
+];
+
+/// Tritanomalie puis tritanopie / Tritanomaly then tritanopia
+const TRITAN: [Matrix; 11] = [
+    IDENTITY,
+    [[0.926_670, 0.092_514, -0.019_184], [0.021_191, 0.964_503, 0.014_306], [0.008_437, 0.054_813, 0.936_750]],
+    [[0.895_720, 0.133_330, -0.029_050], [0.029_997, 0.945_400, 0.024_603], [0.013_027, 0.104_707, 0.882_266]],
+    [[0.905_871, 0.127_791, -0.033_662], [0.026_856, 0.941_251, 0.031_893], [0.013_410, 0.148_296, 0.838_294]],
+    [[0.948_035, 0.089_490, -0.037_526], [0.014_364, 0.946_792, 0.038_844], [0.010_853, 0.193_991, 0.795_156]],
+    [[1.017_277, 0.027_029, -0.044_306], [-0.006_113, 0.958_479, 0.047_634], [0.006_379, 0.248_708, 0.744_913]],
+    [[1.104_996, -0.046_633, -0.058_363], [-0.032_137, 0.971_635, 0.060_503], [0.001_336, 0.317_922, 0.680_742]],
+    [[1.193_214, -0.109_812, -0.083_402], [-0.058_496, 0.979_410, 0.079_086], [-0.002_346, 0.403_492, 0.598_854]],
+    [[1.257_728, -0.139_648, -0.118_081], [-0.078_003, 0.975_409, 0.102_594], [-0.003_316, 0.501_214, 0.502_102]],
+    [[1.278_864, -0.125_333, -0.153_531], [-0.084_748, 0.957_674, 0.127_074], [-0.000_989, 0.601_151, 0.399_838]],
+    [[1.255_528, -0.076_749, -0.178_779], [-0.078_411, 0.930_809, 0.147_602], [0.004_733, 0.691_367, 0.303_900]],
+];
+
+// =============================================================================
+// STRUCTURES
+// =============================================================================
+
+/// Type de déficience de la vision des couleurs
+/// Colour vision deficiency type
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Deficiency {
+    /// Cônes L (rouge) absents ou anormaux / Missing or anomalous L (red) cones
+    Protan,
+    /// Cônes M (vert) absents ou anormaux / Missing or anomalous M (green) cones
+    Deutan,
+    /// Cônes S (bleu) absents ou anormaux / Missing or anomalous S (blue) cones
+    Tritan,
+    /// Vision sans couleur (monochromatie des bâtonnets) / No colour vision (rod monochromacy)
+    Achromatopsia,
+}
+
+impl Deficiency {
+    /// Toutes les déficiences simulées / Every simulated deficiency
+    pub const ALL: [Deficiency; 4] = [Deficiency::Protan, Deficiency::Deutan, Deficiency::Tritan, Deficiency::Achromatopsia];
+
+    /// Simule la perception d'une couleur sRGB
+    /// Simulates how an sRGB color is perceived
+    ///
+    /// # Arguments
+    /// * `rgb` - Couleur sRGB (0.0 - 1.0) / sRGB color (0.0 - 1.0)
+    /// * `severity` - 0.0 (vision typique) à 1.0 (dichromatie) / 0.0 (typical vision) to 1.0 (dichromacy)
+    pub fn simulate(self, rgb: [f64; 3], severity: f64) -> [f64; 3] {
+        let severity = severity.clamp(0.0, 1.0);
+        let linear = rgb.map(conversion::srgb_to_linear);
+
+        let simulated = match self {
+            // Achromatopsie : seule la luminance est perçue
+            // Achromatopsia: only luminance is perceived
+            Deficiency::Achromatopsia => {
+                let y = conversion::srgb_to_luminance(rgb);
+                let gray = [y; 3];
+                [0, 1, 2].map(|i| linear[i] + (gray[i] - linear[i]) * severity)
+            }
+            _ => {
+                let matrices = match self {
+                    Deficiency::Protan => &PROTAN,
+                    Deficiency::Deutan => &DEUTAN,
+                    _ => &TRITAN,
+                };
+                // Interpolation entre les deux matrices publiées qui encadrent la sévérité
+                // Interpolation between the two published matrices around the severity
+                let position = severity * 10.0;
+                let low = (position.floor() as usize).min(9);
+                let t = position - low as f64;
+                let mut matrix = matrices[low];
+                for (row, high_row) in matrix.iter_mut().zip(&matrices[low + 1]) {
+                    for (value, high_value) in row.iter_mut().zip(high_row) {
+                        *value += (high_value - *value) * t;
+                    }
+                }
+                [0, 1, 2].map(|i| matrix[i][0] * linear[0] + matrix[i][1] * linear[1] + matrix[i][2] * linear[2])
+            }
+        };
+
+        simulated.map(|c| conversion::linear_to_srgb(c.clamp(0.0, 1.0)))
+    }
+}
+
+/// Résultat d'une simulation / Simulation result
+#[derive(Serialize, Clone, Debug)]
+pub struct Simulation {
+    /// Déficience simulée / Simulated deficiency
+    pub deficiency: Deficiency,
+
+    /// Sévérité (0.0 - 1.0) / Severity (0.0 - 1.0)
+    pub severity: f64,
+
+    /// Premier plan simulé / Simulated foreground
+    pub foreground_rgb: (u8, u8, u8),
+    pub foreground_hex: String,
+
+    /// Arrière-plan simulé / Simulated background
+    pub background_rgb: (u8, u8, u8),
+    pub background_hex: String,
+
+    /// Ratio de contraste WCAG 2, arrondi / WCAG 2 contrast ratio, rounded
+    pub contrast_ratio_rounded: f32,
+
+    /// Contraste APCA (Lc), arrondi / APCA contrast (Lc), rounded
+    pub apca_lc_rounded: f64,
+}
+
+/// Rapport de simulation pour les couleurs du store
+/// Simulation report for the store colors
+#[derive(Serialize, Clone, Debug)]
+pub struct CvdReport {
+    /// Ratio de contraste en vision typique / Contrast ratio with typical vision
+    pub contrast_ratio_rounded: f32,
+
+    /// Contraste APCA en vision typique / APCA contrast with typical vision
+    pub apca_lc_rounded: f64,
+
+    /// Une simulation par déficience / One simulation per deficiency
+    pub simulations: Vec<Simulation>,
+
+    /// Plus faible ratio de contraste parmi les simulations
+    /// Lowest contrast ratio among the simulations
+    pub min_contrast_ratio_rounded: f32,
+
+    /// Plus faible valeur absolue de Lc parmi les simulations
+    /// Lowest absolute Lc value among the simulations
+    pub min_apca_lc_rounded: f64,
+}
+
+// =============================================================================
+// FONCTIONS DE CALCUL
+// CALCULATION FUNCTIONS
+// =============================================================================
+
+/// Simule une paire de couleurs et calcule son contraste
+/// Simulates a color pair and computes its contrast
+///
+/// # Arguments
+/// * `foreground`, `background` - Couleurs sRGB composées / Composited sRGB colors
+/// * `deficiency` - Déficience simulée / Simulated deficiency
+/// * `severity` - Sévérité (0.0 - 1.0) / Severity (0.0 - 1.0)
+pub fn simulate_pair(foreground: [f64; 3], background: [f64; 3], deficiency: Deficiency, severity: f64) -> Simulation {
+    let fg = deficiency.simulate(foreground, severity);
+    let bg = deficiency.simulate(background, severity);
+    let (fr, fgg, fb) = conversion::srgb_to_rgb8(fg);
+    let (br, bgg, bb) = conversion::srgb_to_rgb8(bg);
+
+    let ratio = color::contrast_ratio(fg, bg);
+    let lc = apca::contrast(fg, bg);
+
+    Simulation {
+        deficiency,
+        severity: severity.clamp(0.0, 1.0),
+        foreground_rgb: (fr, fgg, fb),
+        foreground_hex: format_hex_color(fr, fgg, fb),
+        background_rgb: (br, bgg, bb),
+        background_hex: format_hex_color(br, bgg, bb),
+        contrast_ratio_rounded: color::round_ratio(ratio),
+        apca_lc_rounded: (lc * config::APCA_ROUNDING_FACTOR).round() / config::APCA_ROUNDING_FACTOR,
+    }
+}
+
+// =============================================================================
+// COMMANDES TAURI
+// TAURI COMMANDS
+// =============================================================================
+
+/// Simule les déficiences de la vision des couleurs sur les couleurs du store
+/// Simulates colour vision deficiencies on the store colors
+///
+/// # Arguments
+/// * `severity` - Sévérité des trichromaties anormales (1.0 par défaut = dichromatie)
+///   Severity of anomalous trichromacies (1.0 by default = dichromacy)
+#[tauri::command]
+pub fn simulate_cvd(state: tauri::State<AppState>, severity: Option<f64>) -> CvdReport {
+    let store = state.store.lock().unwrap();
+    let severity = severity.unwrap_or(1.0);
+
+    // L'achromatopsie est toujours simulée complètement
+    // Achromatopsia is always fully simulated
+    let simulations: Vec<Simulation> = Deficiency::ALL
+        .iter()
+        .map(|&deficiency| {
+            let severity = if deficiency == Deficiency::Achromatopsia { 1.0 } else { severity };
+            simulate_pair(store.foreground_composited_srgb, store.background_composited_srgb, deficiency, severity)
+        })
+        .collect();
+
+    CvdReport {
+        contrast_ratio_rounded: store.contrast_ratio_rounded,
+        apca_lc_rounded: store.apca_lc_rounded,
+        min_contrast_ratio_rounded: simulations.iter().map(|s| s.contrast_ratio_rounded).fold(f32::INFINITY, f32::min),
+        min_apca_lc_rounded: simulations.iter().map(|s| s.apca_lc_rounded.abs()).fold(f64::INFINITY, f64::min),
+        simulations,
+    }
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grays_unchanged() {
+        // Les gris ne changent pas, quelle que soit la déficience
+        // Grays do not change, whatever the deficiency
+        for deficiency in Deficiency::ALL {
+            let gray = deficiency.simulate([0.5, 0.5, 0.5], 1.0);
+            assert!(gray.iter().all(|c| (c - 0.5).abs() < 0.01), "{:?}: {:?}", deficiency, gray);
+        }
+    }
+
+    #[test]
+    fn test_protan_red_darker() {
+        // Le rouge paraît plus sombre en protanopie, inchangé à sévérité nulle
+        // Red looks darker with protanopia, unchanged at zero severity
+        let red = conversion::rgb8_to_srgb((255, 0, 0));
+        let white = conversion::rgb8_to_srgb((255, 255, 255));
+        let typical = simulate_pair(red, white, Deficiency::Protan, 0.0);
+        let protan = simulate_pair(red, white, Deficiency::Protan, 1.0);
+        assert_eq!(typical.foreground_hex, "#FF0000");
+        assert_eq!(typical.contrast_ratio_rounded, 4.0);
+        assert!(protan.contrast_ratio_rounded > typical.contrast_ratio_rounded);
+    }
+
+    #[test]
+    fn test_published_severities() {
+        // Bleu linéaire pur : troisième colonne de la matrice de sévérité 0.5, puis
+        // moyenne des matrices 0.6 et 0.7 à sévérité 0.65
+        // Pure linear blue: third column of the 0.5 severity matrix, then the
+        // average of the 0.6 and 0.7 matrices at 0.65 severity
+        let blue = [0.0, 0.0, 1.0];
+        let expected = |column: [f64; 3]| column.map(|c| conversion::linear_to_srgb(c.clamp(0.0, 1.0)));
+        let close = |a: [f64; 3], b: [f64; 3]| a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-9);
+        assert!(close(Deficiency::Tritan.simulate(blue, 0.5), expected([TRITAN[5][0][2], TRITAN[5][1][2], TRITAN[5][2][2]])));
+        let mid = [0, 1, 2].map(|i| (TRITAN[6][i][2] + TRITAN[7][i][2]) / 2.0);
+        assert!(close(Deficiency::Tritan.simulate(blue, 0.65), expected(mid)));
+    }
+
+    #[test]
+    fn test_achromatopsia_keeps_luminance() {
+        let blue = conversion::rgb8_to_srgb((0, 0, 255));
+        let simulated = Deficiency::Achromatopsia.simulate(blue, 1.0);
+        assert!((conversion::srgb_to_luminance(simulated) - conversion::srgb_to_luminance(blue)).abs() < 1e-9);
+    }
+}
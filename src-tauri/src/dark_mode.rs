@@ -0,0 +1,208 @@
+// =============================================================================
+// dark_mode.rs - Équivalent en thème sombre de la paire du store
+// dark_mode.rs - Dark theme counterpart of the store pair
+// =============================================================================
+//
+// Propose une paire sombre en inversant la clarté OKLCH des deux couleurs
+// (teinte et chroma conservées), puis éloigne le premier plan de l'arrière-plan
+// jusqu'à retrouver au moins le contraste de la paire claire. Une paire sombre
+// saisie par l'utilisateur peut aussi être vérifiée ; les deux thèmes sont
+// rapportés côte à côte.
+// Proposes a dark pair by inverting the OKLCH lightness of both colors (hue
+// and chroma kept), then moves the foreground away from the background until
+// the contrast of the light pair is reached again. A dark pair entered by the
+// user can also be verified; both themes are reported side by side.
+
+use serde::Serialize;
+use crate::apca;
+use crate::color;
+use crate::compliance::{self, CriterionResult};
+use crate::config;
+use crate::conversion;
+use crate::css_color::{self, ParseError};
+use crate::fixes;
+use crate::picker::common::format_hex_color;
+use crate::store::{AppState, ResultStore};
+
+// =============================================================================
+// STRUCTURES
+// =============================================================================
+
+/// Paire composée d'un thème / Composited pair of a theme
+#[derive(Serialize, Clone, Debug)]
+pub struct ThemePair {
+    pub foreground_hex: String,
+    pub background_hex: String,
+
+    /// Couleurs en OKLCH / Colors in OKLCH
+    pub foreground_oklch: [f64; 3],
+    pub background_oklch: [f64; 3],
+
+    pub contrast_ratio_rounded: f32,
+    pub apca_lc_rounded: f64,
+
+    /// Critères du profil actif / Active profile criteria
+    pub compliance: Vec<CriterionResult>,
+}
+
+/// Thèmes clair et sombre côte à côte / Light and dark themes side by side
+#[derive(Serialize, Clone, Debug)]
+pub struct DarkModeReport {
+    /// Paire du store / Store pair
+    pub light: ThemePair,
+
+    /// Paire sombre proposée ou saisie / Proposed or entered dark pair
+    pub dark: ThemePair,
+
+    /// Si la paire sombre a été dérivée (sinon saisie) / If the dark pair was derived (otherwise entered)
+    pub derived: bool,
+
+    /// Si le contraste sombre (non arrondi) atteint au moins celui du thème clair
+    /// If the (unrounded) dark contrast reaches at least the light theme one
+    pub keeps_contrast: bool,
+}
+
+// =============================================================================
+// FONCTIONS DE CALCUL
+// CALCULATION FUNCTIONS
+// =============================================================================
+
+/// Couleur 8 bits ramenée dans le gamut sRGB pour une couleur OKLCH
+/// 8-bit color gamut mapped into sRGB for an OKLCH color
+fn to_rgb8(oklch: [f64; 3]) -> [f64; 3] {
+    let rgb = conversion::srgb_to_rgb8(conversion::gamut_map_srgb(conversion::oklch_to_srgb(oklch)));
+    conversion::rgb8_to_srgb(rgb)
+}
+
+/// Inverse la clarté OKLCH d'une couleur / Inverts the OKLCH lightness of a color
+fn invert(srgb: [f64; 3]) -> [f64; 3] {
+    let [lightness, chroma, hue] = conversion::srgb_to_oklch(srgb);
+    to_rgb8([1.0 - lightness.clamp(0.0, 1.0), chroma, hue])
+}
+
+/// Résultats d'une paire composée / Results of a composited pair
+fn theme_pair(store: &ResultStore, foreground: [f64; 3], background: [f64; 3]) -> ThemePair {
+    let hex = |srgb: [f64; 3]| {
+        let (r, g, b) = conversion::srgb_to_rgb8(srgb);
+        format_hex_color(r, g, b)
+    };
+    let ratio = color::contrast_ratio(foreground, background);
+    let lc = apca::contrast(foreground, background);
+
+    ThemePair {
+        foreground_hex: hex(foreground),
+        background_hex: hex(background),
+        foreground_oklch: conversion::srgb_to_oklch(foreground),
+        background_oklch: conversion::srgb_to_oklch(background),
+        contrast_ratio_rounded: color::round_ratio(ratio),
+        apca_lc_rounded: (lc * config::APCA_ROUNDING_FACTOR).round() / config::APCA_ROUNDING_FACTOR,
+        compliance: compliance::evaluate(&store.profile.criteria, ratio, store.text_spec.as_ref().map(|t| t.large)),
+    }
+}
+
+/// Rapport pour une paire sombre composée / Report for a composited dark pair
+fn report(store: &ResultStore, foreground: [f64; 3], background: [f64; 3], derived: bool) -> DarkModeReport {
+    DarkModeReport {
+        light: theme_pair(store, store.foreground_composited_srgb, store.background_composited_srgb),
+        dark: theme_pair(store, foreground, background),
+        derived,
+        keeps_contrast: color::contrast_ratio(foreground, background) >= store.contrast_ratio_raw,
+    }
+}
+
+/// Dérive la paire sombre de la paire composée du store
+/// Derives the dark pair from the composited store pair
+///
+/// L'arrière-plan garde sa clarté inversée ; seul le premier plan est ajusté,
+/// vers le blanc ou le noir selon son côté. Si même l'extrême n'atteint pas le
+/// contraste d'origine, il est retenu et `keeps_contrast` vaut false.
+/// The background keeps its inverted lightness; only the foreground is adjusted,
+/// towards white or black depending on its side. If even the extreme does not
+/// reach the original contrast, it is kept and `keeps_contrast` is false.
+pub fn derive(store: &ResultStore) -> DarkModeReport {
+    let background = invert(store.background_composited_srgb);
+    let [lightness, chroma, hue] = conversion::srgb_to_oklch(store.foreground_composited_srgb);
+    let start = 1.0 - lightness.clamp(0.0, 1.0);
+    let extreme = if start >= conversion::srgb_to_oklch(background)[0] { 1.0 } else { 0.0 };
+
+    let candidate = |l: f64| to_rgb8([l, chroma, hue]);
+    let passes = |l: f64| color::contrast_ratio(candidate(l), background) >= store.contrast_ratio_raw;
+
+    let foreground = if passes(start) || !passes(extreme) {
+        candidate(if passes(start) { start } else { extreme })
+    } else {
+        // Depuis la clarté inversée / From the inverted lightness
+        candidate(fixes::search_lightness(start, extreme, passes))
+    };
+
+    report(store, foreground, background, true)
+}
+
+/// Vérifie une paire sombre saisie, composée sur la couleur de page inversée
+/// Verifies an entered dark pair, composited over the inverted page color
+pub fn verify(store: &ResultStore, foreground: css_color::ParsedColor, background: css_color::ParsedColor) -> DarkModeReport {
+    let page = invert(conversion::rgb8_to_srgb(store.page_rgb));
+    let background = color::composite(background.color.to_srgb(), background.alpha, page);
+    let foreground = color::composite(foreground.color.to_srgb(), foreground.alpha, background);
+    report(store, foreground, background, false)
+}
+
+// =============================================================================
+// COMMANDES TAURI
+// TAURI COMMANDS
+// =============================================================================
+
+/// Propose l'équivalent sombre de la paire du store
+/// Proposes the dark counterpart of the store pair
+#[tauri::command]
+pub fn derive_dark_mode(state: tauri::State<AppState>) -> DarkModeReport {
+    let store = state.store.lock().unwrap();
+    derive(&store)
+}
+
+/// Vérifie une paire sombre face à la paire du store
+/// Verifies a dark pair against the store pair
+///
+/// # Arguments
+/// * `foreground`, `background` - Saisies CSS Color 4 du thème sombre / Dark theme CSS Color 4 entries
+#[tauri::command]
+pub fn verify_dark_mode(state: tauri::State<AppState>, foreground: String, background: String) -> Result<DarkModeReport, ParseError> {
+    let foreground = css_color::parse(&foreground)?;
+    let background = css_color::parse(&background)?;
+    let store = state.store.lock().unwrap();
+    Ok(verify(&store, foreground, background))
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::store_with;
+
+    #[test]
+    fn test_derive_keeps_contrast() {
+        // Noir sur blanc devient blanc sur noir / Black on white becomes white on black
+        let report = derive(&store_with((0, 0, 0), (255, 255, 255)));
+        assert_eq!((report.dark.foreground_hex.as_str(), report.dark.background_hex.as_str()), ("#FFFFFF", "#000000"));
+        assert!(report.keeps_contrast);
+
+        // Le gris inversé est trop sombre sur noir : il est éclairci jusqu'à 4.5:1
+        // The inverted grey is too dark on black: it is lightened up to 4.5:1
+        let report = derive(&store_with((0x76, 0x76, 0x76), (255, 255, 255)));
+        assert!(report.keeps_contrast);
+        assert!(report.dark.compliance[0].pass);
+        assert!(report.dark.foreground_oklch[0] > report.dark.background_oklch[0]);
+    }
+
+    #[test]
+    fn test_verify_entered_pair() {
+        let store = store_with((0x33, 0x33, 0x33), (255, 255, 255));
+        let report = verify(&store, css_color::parse("#777").unwrap(), css_color::parse("#121212").unwrap());
+        assert!(!report.derived);
+        assert!(!report.keeps_contrast);
+        assert!(report.light.contrast_ratio_rounded > report.dark.contrast_ratio_rounded);
+    }
+}
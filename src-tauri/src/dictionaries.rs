@@ -0,0 +1,436 @@
+// =============================================================================
+// dictionaries.rs - Dictionnaires de noms de couleur
+// dictionaries.rs - Colour name dictionaries
+// =============================================================================
+//
+// Cherche le nom le plus proche d'une couleur (CIEDE2000) dans plusieurs
+// dictionnaires : les noms CSS et X11 intégrés, et des dictionnaires chargés
+// depuis des fichiers (noms de l'enquête xkcd, RAL Classic, fichiers JSON de
+// l'utilisateur ; ces listes ne sont pas fournies avec l'application). Un
+// dictionnaire peut être rattaché à une locale, pour que les utilisateurs
+// francophones obtiennent des noms en français.
+// Finds the nearest name for a colour (CIEDE2000) across several
+// dictionaries: the built-in CSS and X11 names, and dictionaries loaded from
+// files (xkcd survey names, RAL Classic, user JSON files; these lists do not
+// ship with the application). A dictionary can be tied to a locale, so French
+// users get French names.
+//
+// Formats acceptés / Accepted formats:
+// - X11 rgb.txt : `255 250 250		snow` (lignes `!` ignorées / `!` lines ignored)
+// - xkcd rgb.txt : `cloudy blue	#acc2d9`
+// - JSON : { "id": "ral-fr", "name": "RAL Classic", "locale": "fr",
+//            "colors": { "RAL 5015 Bleu ciel": "#2271B3" } }
+//   ou une liste / or a list: [{ "name": "RAL 5015 Sky blue", "hex": "#2271B3" }]
+// Reference: https://www.w3.org/TR/css-color-4/#named-colors
+
+use serde::{Deserialize, Serialize};
+use crate::color_names;
+use crate::conversion;
+use crate::css_color;
+use crate::picker::common::format_hex_color;
+use crate::store::AppState;
+
+/// Identifiant du dictionnaire CSS intégré / Built-in CSS dictionary id
+pub const CSS_DICTIONARY_ID: &str = "css";
+
+/// Identifiant du dictionnaire X11 intégré / Built-in X11 dictionary id
+pub const X11_DICTIONARY_ID: &str = "x11";
+
+/// rgb.txt de Xorg / Xorg rgb.txt
+const X11_RGB: &str = include_str!("../data/x11-rgb.txt");
+
+/// Distance CIEDE2000 sous laquelle une différence n'est pas perceptible
+/// CIEDE2000 distance below which a difference is not noticeable
+const JUST_NOTICEABLE_DIFFERENCE: f64 = 1.0;
+
+// =============================================================================
+// STRUCTURES
+// =============================================================================
+
+/// Couleur nommée d'un dictionnaire / Named colour of a dictionary
+#[derive(Clone, Debug, PartialEq)]
+pub struct DictionaryColor {
+    pub name: String,
+    pub rgb: (u8, u8, u8),
+
+    /// CIE Lab D50, calculé au chargement / CIE Lab D50, computed on load
+    pub lab: [f64; 3],
+}
+
+/// Dictionnaire de noms de couleur / Colour name dictionary
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColorDictionary {
+    pub id: String,
+    pub name: String,
+
+    /// Locale des noms (par ex. "fr"), None si neutre / Locale of the names (e.g. "fr"), None if neutral
+    pub locale: Option<String>,
+    pub built_in: bool,
+    pub colors: Vec<DictionaryColor>,
+}
+
+/// Description d'un dictionnaire pour l'interface / Dictionary description for the UI
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct DictionaryInfo {
+    pub id: String,
+    pub name: String,
+    pub locale: Option<String>,
+    pub built_in: bool,
+
+    /// Nombre de couleurs / Number of colours
+    pub size: usize,
+}
+
+/// Nom le plus proche dans un dictionnaire / Nearest name in a dictionary
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct NearestName {
+    /// Identifiant et nom du dictionnaire / Dictionary id and name
+    pub dictionary: String,
+    pub dictionary_name: String,
+
+    pub name: String,
+    pub hex: String,
+
+    /// Distance CIEDE2000 à la couleur cherchée / CIEDE2000 distance to the searched colour
+    pub delta_e_2000: f64,
+
+    /// Si la différence est perceptible (« environ sarcelle »)
+    /// If the difference is noticeable ("approximately teal")
+    pub approximate: bool,
+}
+
+/// Erreur de dictionnaire / Dictionary error
+#[derive(Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", content = "value", rename_all = "kebab-case")]
+pub enum DictionaryError {
+    /// Fichier illisible / Unreadable file
+    Io(String),
+    /// JSON invalide / Invalid JSON
+    Invalid(String),
+    /// Aucune couleur reconnue / No colour recognised
+    Empty,
+    /// Identifiant réservé par le dictionnaire intégré / Id reserved by the built-in dictionary
+    ReservedId(String),
+    /// Dictionnaire inconnu / Unknown dictionary
+    UnknownDictionary(String),
+}
+
+/// Dictionnaire JSON / JSON dictionary
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum JsonDictionary {
+    Full {
+        id: Option<String>,
+        name: Option<String>,
+        locale: Option<String>,
+        colors: JsonColors,
+    },
+    List(Vec<JsonColor>),
+}
+
+/// Couleurs JSON : table nom / valeur ou liste / JSON colours: name / value map or list
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum JsonColors {
+    Map(serde_json::Map<String, serde_json::Value>),
+    List(Vec<JsonColor>),
+}
+
+/// Couleur JSON / JSON colour
+#[derive(Deserialize)]
+struct JsonColor {
+    name: String,
+    #[serde(alias = "value")]
+    hex: String,
+}
+
+// =============================================================================
+// CHARGEMENT
+// LOADING
+// =============================================================================
+
+impl DictionaryColor {
+    fn new(name: &str, rgb: (u8, u8, u8)) -> Self {
+        let lab = conversion::xyz_to_lab(conversion::srgb_to_xyz(conversion::rgb8_to_srgb(rgb)));
+        Self { name: name.trim().to_string(), rgb, lab }
+    }
+
+    /// Couleur depuis une valeur CSS ; None si elle ne s'analyse pas
+    /// Colour from a CSS value; None if it does not parse
+    fn parse(name: &str, value: &str) -> Option<Self> {
+        let parsed = css_color::parse(value).ok()?;
+        Some(Self::new(name, parsed.color.to_rgb8()))
+    }
+}
+
+impl ColorDictionary {
+    /// Description pour l'interface / Description for the UI
+    pub fn info(&self) -> DictionaryInfo {
+        DictionaryInfo {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            locale: self.locale.clone(),
+            built_in: self.built_in,
+            size: self.colors.len(),
+        }
+    }
+}
+
+/// Dictionnaire CSS intégré / Built-in CSS dictionary
+pub fn css_dictionary() -> ColorDictionary {
+    ColorDictionary {
+        id: CSS_DICTIONARY_ID.to_string(),
+        name: "CSS".to_string(),
+        locale: None,
+        built_in: true,
+        colors: color_names::css_colors().map(|(name, rgb)| DictionaryColor::new(name, rgb)).collect(),
+    }
+}
+
+/// Dictionnaire X11 intégré ; les variantes `DarkGrey` des noms `dark grey` sont retirées
+/// Built-in X11 dictionary; the `DarkGrey` variants of the `dark grey` names are removed
+pub fn x11_dictionary() -> ColorDictionary {
+    let mut seen = std::collections::HashSet::new();
+    ColorDictionary {
+        id: X11_DICTIONARY_ID.to_string(),
+        name: "X11".to_string(),
+        locale: None,
+        built_in: true,
+        colors: X11_RGB
+            .lines()
+            .filter_map(parse_text_line)
+            .filter(|c| seen.insert(c.name.replace(' ', "").to_ascii_lowercase()))
+            .collect(),
+    }
+}
+
+/// Dictionnaires intégrés, chargés au démarrage dans l'état de l'application
+/// Built-in dictionaries, loaded into the application state at startup
+pub fn built_in_dictionaries() -> Vec<ColorDictionary> {
+    vec![css_dictionary(), x11_dictionary()]
+}
+
+/// Lit une ligne X11 (`r g b nom`) ou xkcd (`nom #rrggbb`)
+/// Reads an X11 (`r g b name`) or xkcd (`name #rrggbb`) line
+fn parse_text_line(line: &str) -> Option<DictionaryColor> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('!') {
+        return None;
+    }
+
+    // xkcd : le nom précède la valeur hexadécimale / xkcd: the name precedes the hex value
+    if let Some(hash) = line.rfind('#') {
+        let name = line[..hash].trim();
+        let hex = line[hash..].split_whitespace().next()?;
+        return (!name.is_empty()).then(|| DictionaryColor::parse(name, hex)).flatten();
+    }
+
+    // X11 : trois composantes puis le nom / X11: three components then the name
+    let mut parts = line.split_whitespace();
+    let r = parts.next()?.parse().ok()?;
+    let g = parts.next()?.parse().ok()?;
+    let b = parts.next()?.parse().ok()?;
+    let name = parts.collect::<Vec<_>>().join(" ");
+    (!name.is_empty()).then(|| DictionaryColor::new(&name, (r, g, b)))
+}
+
+/// Analyse un dictionnaire JSON ou texte / Parses a JSON or text dictionary
+///
+/// # Arguments
+/// * `source` - Contenu du fichier / File content
+/// * `default_id` - Identifiant si le fichier n'en donne pas (nom du fichier)
+///   Id if the file does not give one (file name)
+pub fn parse_dictionary(source: &str, default_id: &str) -> Result<ColorDictionary, DictionaryError> {
+    let trimmed = source.trim_start();
+    let (id, name, locale, colors) = if trimmed.starts_with('{') || trimmed.starts_with('[') {
+        let json: JsonDictionary = serde_json::from_str(source).map_err(|e| DictionaryError::Invalid(e.to_string()))?;
+        let list = |colors: Vec<JsonColor>| -> Vec<DictionaryColor> {
+            colors.iter().filter_map(|c| DictionaryColor::parse(&c.name, &c.hex)).collect()
+        };
+        match json {
+            JsonDictionary::Full { id, name, locale, colors } => {
+                let colors = match colors {
+                    JsonColors::Map(map) => map
+                        .iter()
+                        .filter_map(|(name, value)| DictionaryColor::parse(name, value.as_str()?))
+                        .collect(),
+                    JsonColors::List(colors) => list(colors),
+                };
+                (id, name, locale, colors)
+            }
+            JsonDictionary::List(colors) => (None, None, None, list(colors)),
+        }
+    } else {
+        (None, None, None, source.lines().filter_map(parse_text_line).collect())
+    };
+
+    let id = id.unwrap_or_else(|| default_id.to_string());
+    if [CSS_DICTIONARY_ID, X11_DICTIONARY_ID].contains(&id.as_str()) {
+        return Err(DictionaryError::ReservedId(id));
+    }
+    if colors.is_empty() {
+        return Err(DictionaryError::Empty);
+    }
+    Ok(ColorDictionary { name: name.unwrap_or_else(|| id.clone()), id, locale, built_in: false, colors })
+}
+
+// =============================================================================
+// RECHERCHE
+// LOOKUP
+// =============================================================================
+
+/// Nom le plus proche dans un dictionnaire / Nearest name in a dictionary
+pub fn nearest_in(dictionary: &ColorDictionary, rgb: (u8, u8, u8)) -> Option<NearestName> {
+    let lab = conversion::xyz_to_lab(conversion::srgb_to_xyz(conversion::rgb8_to_srgb(rgb)));
+    let mut best: Option<(f64, &DictionaryColor)> = None;
+    for color in &dictionary.colors {
+        let distance = if color.rgb == rgb { 0.0 } else { conversion::delta_e_2000_lab(lab, color.lab) };
+        if best.is_none_or(|(d, _)| distance < d) {
+            best = Some((distance, color));
+        }
+        if distance == 0.0 {
+            break;
+        }
+    }
+
+    best.map(|(distance, color)| NearestName {
+        dictionary: dictionary.id.clone(),
+        dictionary_name: dictionary.name.clone(),
+        name: color.name.clone(),
+        hex: format_hex_color(color.rgb.0, color.rgb.1, color.rgb.2),
+        delta_e_2000: distance,
+        approximate: distance >= JUST_NOTICEABLE_DIFFERENCE,
+    })
+}
+
+/// Noms les plus proches dans les dictionnaires adaptés à la locale
+/// Nearest names in the dictionaries suited to the locale
+///
+/// Les dictionnaires de la locale viennent en premier, puis les dictionnaires
+/// neutres ; ceux d'une autre locale sont ignorés.
+/// Dictionaries in the locale come first, then neutral dictionaries; those in
+/// another locale are skipped.
+pub fn nearest_names(dictionaries: &[ColorDictionary], rgb: (u8, u8, u8), locale: &str) -> Vec<NearestName> {
+    let language = locale.split(['-', '_']).next().unwrap_or(locale);
+    let localized = dictionaries.iter().filter(|d| d.locale.as_deref().is_some_and(|l| l.split(['-', '_']).next() == Some(language)));
+    let neutral = dictionaries.iter().filter(|d| d.locale.is_none());
+    localized.chain(neutral).filter_map(|d| nearest_in(d, rgb)).collect()
+}
+
+// =============================================================================
+// COMMANDES TAURI
+// TAURI COMMANDS
+// =============================================================================
+
+/// Liste les dictionnaires intégrés puis les dictionnaires chargés
+/// Lists the built-in dictionaries, then the loaded ones
+#[tauri::command]
+pub fn list_color_dictionaries(state: tauri::State<AppState>) -> Vec<DictionaryInfo> {
+    state.dictionaries.lock().unwrap().iter().map(ColorDictionary::info).collect()
+}
+
+/// Charge un dictionnaire depuis un fichier ; un dictionnaire de même identifiant est remplacé
+/// Loads a dictionary from a file; a dictionary with the same id is replaced
+///
+/// # Arguments
+/// * `path` - Fichier X11, xkcd ou JSON / X11, xkcd or JSON file
+#[tauri::command]
+pub fn load_color_dictionary(state: tauri::State<AppState>, path: String) -> Result<DictionaryInfo, DictionaryError> {
+    let source = std::fs::read_to_string(&path).map_err(|e| DictionaryError::Io(format!("{}: {}", path, e)))?;
+    let default_id = std::path::Path::new(&path).file_stem().and_then(|s| s.to_str()).unwrap_or("custom");
+    let dictionary = parse_dictionary(&source, default_id)?;
+    let info = dictionary.info();
+
+    let mut dictionaries = state.dictionaries.lock().unwrap();
+    dictionaries.retain(|d| d.id != dictionary.id);
+    dictionaries.push(dictionary);
+    Ok(info)
+}
+
+/// Retire un dictionnaire chargé / Removes a loaded dictionary
+#[tauri::command]
+pub fn remove_color_dictionary(state: tauri::State<AppState>, id: String) -> Result<(), DictionaryError> {
+    let mut dictionaries = state.dictionaries.lock().unwrap();
+    if dictionaries.iter().any(|d| d.built_in && d.id == id) {
+        return Err(DictionaryError::ReservedId(id));
+    }
+    let count = dictionaries.len();
+    dictionaries.retain(|d| d.id != id);
+    if dictionaries.len() == count {
+        return Err(DictionaryError::UnknownDictionary(id));
+    }
+    Ok(())
+}
+
+/// Cherche le nom le plus proche dans chaque dictionnaire adapté
+/// Finds the nearest name in each suitable dictionary
+///
+/// # Arguments
+/// * `r`, `g`, `b` - Composantes RGB / RGB components
+/// * `locale` - Locale des noms, celle de l'application si absente
+///   Locale of the names, the application one if absent
+#[tauri::command]
+pub fn nearest_color_names(state: tauri::State<AppState>, r: u8, g: u8, b: u8, locale: Option<String>) -> Vec<NearestName> {
+    let locale = locale.unwrap_or_else(|| state.locale.lock().unwrap().clone());
+    nearest_names(&state.dictionaries.lock().unwrap(), (r, g, b), &locale)
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_formats() {
+        let x11 = parse_dictionary("! $Xorg: rgb.txt $\n255 250 250\t\tsnow\n  0   0 128\t\tnavy blue\n", "rgb").unwrap();
+        assert_eq!(x11.id, "rgb");
+        assert_eq!(x11.colors[1].name, "navy blue");
+        assert_eq!(x11.colors[1].rgb, (0, 0, 128));
+
+        let xkcd = parse_dictionary("License: http://creativecommons.org/publicdomain/zero/1.0/\ncloudy blue\t#acc2d9\t\n", "xkcd").unwrap();
+        assert_eq!(xkcd.colors.len(), 1);
+        assert_eq!(xkcd.colors[0].rgb, (0xac, 0xc2, 0xd9));
+
+        assert_eq!(parse_dictionary("no colours here", "x"), Err(DictionaryError::Empty));
+        assert!(matches!(parse_dictionary(r#"{ "id": "css", "colors": {} }"#, "x"), Err(DictionaryError::ReservedId(_))));
+    }
+
+    #[test]
+    fn test_built_in_lookup() {
+        let nearest = |dictionary: &ColorDictionary, rgb| nearest_in(dictionary, rgb).unwrap().name;
+        let css = css_dictionary();
+        assert_eq!(nearest(&css, (255, 165, 0)), "orange");
+        assert_eq!(nearest(&css, (254, 1, 1)), "red");
+        assert_eq!(nearest(&css, (0, 0, 130)), "navy");
+
+        // X11 sans les doublons en CamelCase / X11 without the CamelCase duplicates
+        let x11 = x11_dictionary();
+        assert!(x11.colors.iter().all(|c| c.name != "GhostWhite"));
+        assert_eq!(nearest(&x11, (248, 248, 255)), "ghost white");
+    }
+
+    #[test]
+    fn test_localized_lookup() {
+        let french = parse_dictionary(
+            r##"{ "id": "fr", "name": "Couleurs", "locale": "fr", "colors": { "bleu canard": "#048B9A", "bleu marine": "#03224C" } }"##,
+            "x",
+        )
+        .unwrap();
+        let dictionaries = [css_dictionary(), french];
+
+        // En français, le dictionnaire français vient en premier
+        // In French, the French dictionary comes first
+        let names = nearest_names(&dictionaries, (0x05, 0x8A, 0x99), "fr-FR");
+        assert_eq!(names.len(), 2);
+        assert_eq!(names[0].name, "bleu canard");
+        assert!(!names[0].approximate);
+        assert_eq!(names[1].name, "darkcyan");
+        assert!(names[1].approximate);
+
+        let names = nearest_names(&dictionaries, (0, 128, 128), "en");
+        assert_eq!((names.len(), names[0].name.as_str(), names[0].delta_e_2000), (1, "teal", 0.0));
+    }
+}
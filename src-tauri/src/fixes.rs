@@ -0,0 +1,318 @@
+// =============================================================================
+// fixes.rs - Suggestions de correction du contraste
+// fixes.rs - Contrast repair suggestions
+// =============================================================================
+//
+// Cherche le premier plan et l'arrière-plan les plus proches qui atteignent
+// une cible de contraste, en ne modifiant que la clarté OKLCH : la teinte et
+// la chroma sont conservées, sauf si le gamut sRGB oblige à réduire la chroma.
+// Finds the closest foreground and background reaching a contrast target by
+// changing only the OKLCH lightness: hue and chroma are kept, unless the sRGB
+// gamut forces a chroma reduction.
+
+use serde::{Deserialize, Serialize};
+use crate::apca;
+use crate::color;
+use crate::compliance::{self, ContentKind, Criterion};
+use crate::config;
+use crate::conversion;
+use crate::picker::common::format_hex_color;
+use crate::store::{AppState, ResultStore};
+
+/// Nombre d'itérations de la recherche dichotomique sur la clarté
+/// Number of iterations of the binary search on lightness
+const SEARCH_ITERATIONS: usize = 40;
+
+/// Pas de clarté utilisé pour compenser l'arrondi en RGB 8 bits
+/// Lightness step used to compensate for 8-bit RGB rounding
+const ROUNDING_NUDGE: f64 = 0.001;
+
+// =============================================================================
+// STRUCTURES
+// =============================================================================
+
+/// Cible de contraste à atteindre / Contrast target to reach
+///
+/// Sérialisée sous la forme `{ "type": "criterion", "value": "1.4.3" }`,
+/// `{ "type": "ratio", "value": 4.5 }` ou `{ "type": "apca-lc", "value": 75 }`.
+/// Serialized as `{ "type": "criterion", "value": "1.4.3" }`,
+/// `{ "type": "ratio", "value": 4.5 }` or `{ "type": "apca-lc", "value": 75 }`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", content = "value", rename_all = "kebab-case")]
+pub enum FixTarget {
+    /// Critère du profil actif : "1.4.3", "1.4.3-large", "1.4.6", "1.4.6-large", "1.4.11"…
+    /// Criterion of the active profile: "1.4.3", "1.4.3-large", "1.4.6", "1.4.6-large", "1.4.11"…
+    Criterion(String),
+    /// Ratio WCAG 2 minimal / Minimum WCAG 2 ratio
+    Ratio(f64),
+    /// Valeur absolue minimale de Lc APCA / Minimum absolute APCA Lc value
+    ApcaLc(f64),
+}
+
+/// Erreur de suggestion / Suggestion error
+#[derive(Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", content = "value", rename_all = "kebab-case")]
+pub enum FixError {
+    /// Critère inconnu / Unknown criterion
+    UnknownCriterion(String),
+}
+
+/// Couleur proposée pour une case / Color suggested for a slot
+#[derive(Serialize, Clone, Debug)]
+pub struct Suggestion {
+    /// Case à modifier ("foreground" ou "background"), à passer à `update_store`
+    /// Slot to change ("foreground" or "background"), to pass to `update_store`
+    pub key: String,
+
+    /// Couleur proposée (à passer à `update_store`) / Suggested color (to pass to `update_store`)
+    pub rgb: (u8, u8, u8),
+    pub hex: String,
+
+    /// Couleur proposée en OKLCH / Suggested color in OKLCH
+    pub oklch: [f64; 3],
+
+    /// Distance perceptuelle à la couleur actuelle (deltaEOK)
+    /// Perceptual distance to the current color (deltaEOK)
+    pub delta_e_ok: f64,
+
+    /// Contraste obtenu / Resulting contrast
+    pub contrast_ratio_rounded: f32,
+    pub apca_lc_rounded: f64,
+}
+
+/// Suggestions pour les deux cases / Suggestions for both slots
+#[derive(Serialize, Clone, Debug)]
+pub struct FixSuggestions {
+    /// Si la paire actuelle atteint déjà la cible / If the current pair already reaches the target
+    pub already_passes: bool,
+
+    /// Premier plan le plus proche qui passe (None si impossible)
+    /// Closest passing foreground (None if impossible)
+    pub foreground: Option<Suggestion>,
+
+    /// Arrière-plan le plus proche qui passe (None si impossible)
+    /// Closest passing background (None if impossible)
+    pub background: Option<Suggestion>,
+}
+
+// =============================================================================
+// FONCTIONS DE CALCUL
+// CALCULATION FUNCTIONS
+// =============================================================================
+
+impl FixTarget {
+    /// Résout un critère du profil actif en ratio / Resolves a criterion of the active profile into a ratio
+    pub fn resolve(&self, criteria: &[Criterion]) -> Result<FixTarget, FixError> {
+        match self {
+            FixTarget::Criterion(key) => compliance::find(criteria, key)
+                .map(|criterion| FixTarget::Ratio(criterion.threshold))
+                .ok_or_else(|| FixError::UnknownCriterion(key.clone())),
+            target => Ok(target.clone()),
+        }
+    }
+
+    /// Cible par défaut : premier critère de texte du profil actif qui s'applique
+    /// Default target: first text criterion of the active profile that applies
+    pub fn text_default(store: &ResultStore) -> Result<FixTarget, FixError> {
+        let content = match store.text_spec.as_ref().map(|t| t.large) {
+            Some(true) => ContentKind::Large,
+            _ => ContentKind::Regular,
+        };
+        store
+            .profile
+            .criteria
+            .iter()
+            .find(|c| c.content == content)
+            .map(|c| FixTarget::Ratio(c.threshold))
+            .ok_or(FixError::UnknownCriterion(store.profile.id.clone()))
+    }
+
+    /// Vérifie si une paire composée atteint la cible (valeurs non arrondies)
+    /// Checks whether a composited pair reaches the target (unrounded values)
+    pub fn passes(&self, foreground: [f64; 3], background: [f64; 3]) -> bool {
+        match self {
+            FixTarget::Ratio(ratio) => color::contrast_ratio(foreground, background) >= *ratio,
+            FixTarget::ApcaLc(lc) => apca::contrast(foreground, background).abs() >= *lc,
+            FixTarget::Criterion(_) => false,
+        }
+    }
+}
+
+/// Compose une paire comme `color::update_contrast` / Composites a pair like `color::update_contrast`
+fn composite_pair(store: &ResultStore, foreground: [f64; 3], background: [f64; 3]) -> ([f64; 3], [f64; 3]) {
+    let page = conversion::rgb8_to_srgb(store.page_rgb);
+    let background = color::composite(background, store.background_alpha, page);
+    let foreground = color::composite(foreground, store.foreground_alpha, background);
+    (foreground, background)
+}
+
+/// Cherche entre `start` (qui ne passe pas) et `extreme` (qui passe) la clarté
+/// la plus proche de `start` qui passe
+/// Searches between `start` (which does not pass) and `extreme` (which passes)
+/// for the lightness closest to `start` that passes
+///
+/// `passes` doit être monotone sur l'intervalle ; la clarté retournée est
+/// bornée à 0.0 - 1.0.
+/// `passes` must be monotonic over the interval; the returned lightness is
+/// bounded to 0.0 - 1.0.
+pub fn search_lightness(start: f64, extreme: f64, passes: impl Fn(f64) -> bool) -> f64 {
+    // `far` passe, `near` ne passe pas / `far` passes, `near` does not
+    let mut far = extreme;
+    let mut near = start;
+    for _ in 0..SEARCH_ITERATIONS {
+        let mid = (far + near) / 2.0;
+        if passes(mid) {
+            far = mid;
+        } else {
+            near = mid;
+        }
+    }
+
+    // Compense l'arrondi 8 bits en s'éloignant légèrement
+    // Compensate for 8-bit rounding by moving slightly further
+    let step = if extreme > start { ROUNDING_NUDGE } else { -ROUNDING_NUDGE };
+    let mut l = far;
+    while (0.0..=1.0).contains(&l) && !passes(l) {
+        l += step;
+    }
+    l.clamp(0.0, 1.0)
+}
+
+/// Cherche la couleur la plus proche qui passe pour une case
+/// Finds the closest passing color for a slot
+///
+/// La clarté OKLCH est parcourue vers le noir et vers le blanc ; sur chaque
+/// branche le contraste varie de façon monotone, d'où une recherche dichotomique.
+/// OKLCH lightness is walked towards black and towards white; on each branch
+/// contrast varies monotonically, hence a binary search.
+fn closest_passing(store: &ResultStore, key: &str, target: &FixTarget) -> Option<Suggestion> {
+    let foreground = store.foreground_precise.to_srgb_mapped();
+    let background = store.background_precise.to_srgb_mapped();
+    let original = if key == "foreground" { foreground } else { background };
+    let [lightness, chroma, hue] = conversion::srgb_to_oklch(original);
+
+    // Couleur candidate (8 bits) pour une clarté donnée
+    // Candidate color (8-bit) for a given lightness
+    let candidate = |l: f64| conversion::srgb_to_rgb8(conversion::gamut_map_srgb(conversion::oklch_to_srgb([l, chroma, hue])));
+    let passes = |rgb: (u8, u8, u8)| {
+        let srgb = conversion::rgb8_to_srgb(rgb);
+        let (fg, bg) = if key == "foreground" {
+            composite_pair(store, srgb, background)
+        } else {
+            composite_pair(store, foreground, srgb)
+        };
+        target.passes(fg, bg)
+    };
+
+    let mut best: Option<(f64, (u8, u8, u8))> = None;
+    for extreme in [0.0, 1.0] {
+        // Branche impossible si même le noir ou le blanc ne passe pas
+        // Branch impossible if even black or white does not pass
+        if !passes(candidate(extreme)) {
+            continue;
+        }
+
+        // Depuis la couleur d'origine / From the original color
+        let rgb = candidate(search_lightness(lightness.clamp(0.0, 1.0), extreme, |l| passes(candidate(l))));
+        if !passes(rgb) {
+            continue;
+        }
+
+        let distance = conversion::delta_e_ok(original, conversion::rgb8_to_srgb(rgb));
+        if best.is_none_or(|(d, _)| distance < d) {
+            best = Some((distance, rgb));
+        }
+    }
+
+    best.map(|(distance, rgb)| {
+        let srgb = conversion::rgb8_to_srgb(rgb);
+        let (fg, bg) = if key == "foreground" {
+            composite_pair(store, srgb, background)
+        } else {
+            composite_pair(store, foreground, srgb)
+        };
+        let ratio = color::contrast_ratio(fg, bg);
+        let lc = apca::contrast(fg, bg);
+        Suggestion {
+            key: key.to_string(),
+            rgb,
+            hex: format_hex_color(rgb.0, rgb.1, rgb.2),
+            oklch: conversion::srgb_to_oklch(srgb),
+            delta_e_ok: distance,
+            contrast_ratio_rounded: color::round_ratio(ratio),
+            apca_lc_rounded: (lc * config::APCA_ROUNDING_FACTOR).round() / config::APCA_ROUNDING_FACTOR,
+        }
+    })
+}
+
+/// Calcule les suggestions pour un store / Computes the suggestions for a store
+pub fn suggest(store: &ResultStore, target: &FixTarget) -> Result<FixSuggestions, FixError> {
+    let target = target.resolve(&store.profile.criteria)?;
+    Ok(FixSuggestions {
+        already_passes: target.passes(store.foreground_composited_srgb, store.background_composited_srgb),
+        foreground: closest_passing(store, "foreground", &target),
+        background: closest_passing(store, "background", &target),
+    })
+}
+
+// =============================================================================
+// COMMANDES TAURI
+// TAURI COMMANDS
+// =============================================================================
+
+/// Propose le premier plan et l'arrière-plan les plus proches qui atteignent la cible
+/// Suggests the closest foreground and background reaching the target
+///
+/// # Arguments
+/// * `target` - Critère WCAG, ratio ou Lc APCA / WCAG criterion, ratio or APCA Lc
+#[tauri::command]
+pub fn suggest_fixes(state: tauri::State<AppState>, target: FixTarget) -> Result<FixSuggestions, FixError> {
+    let store = state.store.lock().unwrap();
+    suggest(&store, &target)
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::store_with;
+
+    #[test]
+    fn test_gray_on_white() {
+        // #999 sur blanc échoue à 4.5:1 ; le gris proposé est plus sombre et passe
+        // #999 on white fails 4.5:1; the suggested gray is darker and passes
+        let store = store_with((153, 153, 153), (255, 255, 255));
+        let fixes = suggest(&store, &FixTarget::Criterion("1.4.3".to_string())).unwrap();
+        assert!(!fixes.already_passes);
+        let fg = fixes.foreground.unwrap();
+        assert_eq!(fg.rgb.0, fg.rgb.1);
+        assert!(fg.rgb.0 < 153);
+        assert!(color::contrast_ratio(conversion::rgb8_to_srgb(fg.rgb), [1.0; 3]) >= 4.5);
+        // Le plus proche : un niveau plus clair échoue
+        // Closest: one level lighter fails
+        let lighter = conversion::rgb8_to_srgb((fg.rgb.0 + 1, fg.rgb.0 + 1, fg.rgb.0 + 1));
+        assert!(color::contrast_ratio(lighter, [1.0; 3]) < 4.5);
+    }
+
+    #[test]
+    fn test_keeps_hue() {
+        // Bleu moyen sur blanc : le bleu proposé est plus sombre, de même teinte
+        // Medium blue on white: the suggested blue is darker, with the same hue
+        let store = store_with((80, 140, 220), (255, 255, 255));
+        let fixes = suggest(&store, &FixTarget::ApcaLc(75.0)).unwrap();
+        let original = conversion::srgb_to_oklch(conversion::rgb8_to_srgb((80, 140, 220)));
+        let fg = fixes.foreground.unwrap();
+        assert!(fg.oklch[0] < original[0]);
+        assert!((fg.oklch[2] - original[2]).abs() < 2.0);
+        assert!(fg.apca_lc_rounded >= 75.0);
+    }
+
+    #[test]
+    fn test_unknown_criterion() {
+        let store = ResultStore::default();
+        assert!(suggest(&store, &FixTarget::Criterion("9.9.9".to_string())).is_err());
+    }
+}
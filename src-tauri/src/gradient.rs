@@ -0,0 +1,882 @@
+// =============================================================================
+// gradient.rs - Contraste le long d'un dégradé CSS
+// gradient.rs - Contrast along a CSS gradient
+// =============================================================================
+//
+// Analyse les dégradés linear-gradient(), radial-gradient() et conic-gradient()
+// (et leurs variantes repeating-), avec l'espace d'interpolation (`in oklch
+// longer hue`…), les indications de transition et les arrêts transparents. Les
+// couleurs des arrêts passent par l'analyseur CSS Color 4 ; l'interpolation
+// se fait en alpha prémultiplié comme dans les navigateurs. Le dégradé est
+// échantillonné le long de sa ligne, chaque échantillon est composé sur la
+// page, et le contraste du premier plan est mesuré comme dans le store.
+// Parses linear-gradient(), radial-gradient() and conic-gradient() gradients
+// (and their repeating- variants), with the interpolation space (`in oklch
+// longer hue`…), transition hints and transparent stops. Stop colors go
+// through the CSS Color 4 parser; interpolation uses premultiplied alpha like
+// browsers. The gradient is sampled along its line, each sample is composited
+// over the page, and the foreground contrast is measured like in the store.
+// Reference: https://www.w3.org/TR/css-images-4/#gradients
+
+use serde::Serialize;
+use crate::color;
+use crate::compliance::{self, CriterionResult};
+use crate::conversion::{self, RgbSpace};
+use crate::css_color::{self, ParseError};
+use crate::picker::common::format_hex_color;
+use crate::store::{AppState, ResultStore};
+
+/// Nombre d'intervalles d'échantillonnage le long de la ligne du dégradé
+/// Number of sampling intervals along the gradient line
+const SAMPLES: usize = 1000;
+
+/// Écart avant un arrêt, pour échantillonner les deux côtés d'un arrêt net
+/// Offset before a stop, to sample both sides of a hard stop
+const HARD_STOP_OFFSET: f64 = 1e-9;
+
+/// Période minimale d'un dégradé répété (fraction de la ligne)
+/// Minimum period of a repeating gradient (fraction of the line)
+const MIN_PERIOD: f64 = 1e-3;
+
+// =============================================================================
+// STRUCTURES
+// =============================================================================
+
+/// Forme du dégradé / Gradient shape
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum GradientKind {
+    Linear,
+    Radial,
+    Conic,
+}
+
+/// Espace d'interpolation CSS Color 4 / CSS Color 4 interpolation space
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum InterpolationSpace {
+    Srgb,
+    SrgbLinear,
+    DisplayP3,
+    A98Rgb,
+    ProphotoRgb,
+    Rec2020,
+    Lab,
+    Oklab,
+    XyzD50,
+    XyzD65,
+    Hsl,
+    Hwb,
+    Lch,
+    Oklch,
+}
+
+/// Méthode d'interpolation de la teinte / Hue interpolation method
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum HueInterpolation {
+    Shorter,
+    Longer,
+    Increasing,
+    Decreasing,
+}
+
+/// Arrêt de couleur résolu / Resolved color stop
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct ColorStop {
+    #[serde(skip)]
+    pub srgb: [f64; 3],
+    pub hex: String,
+    pub alpha: f64,
+
+    /// Position sur la ligne du dégradé (0.0 - 1.0) / Position on the gradient line (0.0 - 1.0)
+    pub position: f64,
+}
+
+/// Dégradé analysé / Parsed gradient
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct Gradient {
+    pub kind: GradientKind,
+    pub repeating: bool,
+    pub space: InterpolationSpace,
+    pub hue: HueInterpolation,
+
+    /// Angle d'un dégradé linéaire ou de départ d'un dégradé conique (degrés)
+    /// Angle of a linear gradient or start angle of a conic gradient (degrees)
+    pub angle: Option<f64>,
+
+    pub stops: Vec<ColorStop>,
+
+    /// Indication de transition entre l'arrêt i et l'arrêt i + 1 / Transition hint between stop i and stop i + 1
+    #[serde(skip)]
+    pub hints: Vec<Option<f64>>,
+}
+
+/// Point du dégradé / Point of the gradient
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct GradientSample {
+    /// Position le long de la ligne du dégradé en pourcentage / Position along the gradient line in percent
+    pub position: f64,
+
+    /// Angle du point pour un dégradé conique (degrés) / Angle of the point for a conic gradient (degrees)
+    pub angle: Option<f64>,
+
+    /// Dégradé composé sur la page / Gradient composited over the page
+    pub hex: String,
+
+    pub contrast_ratio_rounded: f32,
+}
+
+/// Contraste du premier plan le long d'un dégradé / Foreground contrast along a gradient
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct GradientReport {
+    pub gradient: Gradient,
+    pub lowest: GradientSample,
+    pub highest: GradientSample,
+
+    /// Critères du profil actif évalués sur le minimum / Active profile criteria evaluated on the minimum
+    pub compliance: Vec<CriterionResult>,
+}
+
+/// Erreur d'analyse de dégradé / Gradient parse error
+#[derive(Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", content = "value", rename_all = "kebab-case")]
+pub enum GradientError {
+    /// Fonction de dégradé inconnue / Unknown gradient function
+    UnknownFunction(String),
+    /// Partie de la saisie invalide / Invalid part of the input
+    InvalidSyntax(String),
+    /// Couleur d'arrêt invalide (positions dans la saisie complète)
+    /// Invalid stop color (positions in the whole input)
+    InvalidColor(ParseError),
+    /// Espace d'interpolation inconnu / Unknown interpolation space
+    UnknownColorSpace(String),
+    /// Position en longueur, qui dépend de la taille de la boîte / Length position, which depends on the box size
+    UnsupportedUnit(String),
+    /// Moins de deux arrêts de couleur / Fewer than two color stops
+    TooFewStops,
+}
+
+// =============================================================================
+// ESPACES D'INTERPOLATION
+// INTERPOLATION SPACES
+// =============================================================================
+
+impl InterpolationSpace {
+    /// Retrouve un espace à partir de son nom CSS / Finds a space from its CSS name
+    fn from_css_name(name: &str) -> Option<Self> {
+        let space = match name {
+            "srgb" => InterpolationSpace::Srgb,
+            "srgb-linear" => InterpolationSpace::SrgbLinear,
+            "display-p3" => InterpolationSpace::DisplayP3,
+            "a98-rgb" => InterpolationSpace::A98Rgb,
+            "prophoto-rgb" => InterpolationSpace::ProphotoRgb,
+            "rec2020" => InterpolationSpace::Rec2020,
+            "lab" => InterpolationSpace::Lab,
+            "oklab" => InterpolationSpace::Oklab,
+            "xyz-d50" => InterpolationSpace::XyzD50,
+            "xyz" | "xyz-d65" => InterpolationSpace::XyzD65,
+            "hsl" => InterpolationSpace::Hsl,
+            "hwb" => InterpolationSpace::Hwb,
+            "lch" => InterpolationSpace::Lch,
+            "oklch" => InterpolationSpace::Oklch,
+            _ => return None,
+        };
+        Some(space)
+    }
+
+    /// Espace RGB prédéfini correspondant / Matching predefined RGB space
+    fn rgb_space(self) -> Option<RgbSpace> {
+        match self {
+            InterpolationSpace::SrgbLinear => Some(RgbSpace::SrgbLinear),
+            InterpolationSpace::DisplayP3 => Some(RgbSpace::DisplayP3),
+            InterpolationSpace::A98Rgb => Some(RgbSpace::A98Rgb),
+            InterpolationSpace::ProphotoRgb => Some(RgbSpace::ProphotoRgb),
+            InterpolationSpace::Rec2020 => Some(RgbSpace::Rec2020),
+            _ => None,
+        }
+    }
+
+    /// Indice de la teinte pour les espaces polaires / Hue index for polar spaces
+    fn hue_index(self) -> Option<usize> {
+        match self {
+            InterpolationSpace::Hsl | InterpolationSpace::Hwb => Some(0),
+            InterpolationSpace::Lch | InterpolationSpace::Oklch => Some(2),
+            _ => None,
+        }
+    }
+
+    /// Convertit depuis sRGB étendu / Converts from extended sRGB
+    fn srgb_to_components(self, srgb: [f64; 3]) -> [f64; 3] {
+        if let Some(space) = self.rgb_space() {
+            return space.xyz_to_components(conversion::srgb_to_xyz(srgb));
+        }
+        let xyz = conversion::srgb_to_xyz(srgb);
+        match self {
+            InterpolationSpace::Lab => conversion::xyz_to_lab(xyz),
+            InterpolationSpace::Oklab => conversion::xyz_to_oklab(xyz),
+            InterpolationSpace::XyzD50 => conversion::xyz_d65_to_d50(xyz),
+            InterpolationSpace::XyzD65 => xyz,
+            InterpolationSpace::Hsl => conversion::srgb_to_hsl(srgb),
+            InterpolationSpace::Hwb => conversion::srgb_to_hwb(srgb),
+            InterpolationSpace::Lch => conversion::lab_to_lch(conversion::xyz_to_lab(xyz)),
+            InterpolationSpace::Oklch => conversion::srgb_to_oklch(srgb),
+            _ => srgb,
+        }
+    }
+
+    /// Convertit vers sRGB étendu / Converts to extended sRGB
+    fn components_to_srgb(self, components: [f64; 3]) -> [f64; 3] {
+        if let Some(space) = self.rgb_space() {
+            return conversion::xyz_to_srgb(space.to_xyz(components));
+        }
+        match self {
+            InterpolationSpace::Lab => conversion::xyz_to_srgb(conversion::lab_to_xyz(components)),
+            InterpolationSpace::Oklab => conversion::xyz_to_srgb(conversion::oklab_to_xyz(components)),
+            InterpolationSpace::XyzD50 => conversion::xyz_to_srgb(conversion::xyz_d50_to_d65(components)),
+            InterpolationSpace::XyzD65 => conversion::xyz_to_srgb(components),
+            InterpolationSpace::Hsl => conversion::hsl_to_srgb(components),
+            InterpolationSpace::Hwb => conversion::hwb_to_srgb(components),
+            InterpolationSpace::Lch => conversion::xyz_to_srgb(conversion::lab_to_xyz(conversion::lch_to_lab(components))),
+            InterpolationSpace::Oklch => conversion::oklch_to_srgb(components),
+            _ => components,
+        }
+    }
+
+    /// Teinte sans effet (gris) : elle prend la teinte de l'autre arrêt
+    /// Powerless hue (grey): it takes the hue of the other stop
+    fn powerless_hue(self, components: [f64; 3]) -> bool {
+        match self {
+            InterpolationSpace::Hsl => components[1].abs() < 1e-3,
+            InterpolationSpace::Hwb => components[1] + components[2] >= 100.0 - 1e-3,
+            InterpolationSpace::Lch => components[1] < 1e-2,
+            InterpolationSpace::Oklch => components[1] < 1e-4,
+            _ => false,
+        }
+    }
+}
+
+impl HueInterpolation {
+    /// Ajuste deux teintes (degrés) selon la méthode / Adjusts two hues (degrees) for the method
+    fn fix(self, mut a: f64, mut b: f64) -> (f64, f64) {
+        let delta = b - a;
+        match self {
+            HueInterpolation::Shorter if delta > 180.0 => a += 360.0,
+            HueInterpolation::Shorter if delta < -180.0 => b += 360.0,
+            HueInterpolation::Longer if delta > 0.0 && delta < 180.0 => a += 360.0,
+            HueInterpolation::Longer if delta > -180.0 && delta <= 0.0 => b += 360.0,
+            HueInterpolation::Increasing if b < a => b += 360.0,
+            HueInterpolation::Decreasing if a < b => a += 360.0,
+            _ => {}
+        }
+        (a, b)
+    }
+}
+
+// =============================================================================
+// ANALYSE SYNTAXIQUE
+// PARSER
+// =============================================================================
+
+/// Partie de la saisie et sa position (en caractères) / Part of the input and its position (in characters)
+#[derive(Clone, Copy, Debug)]
+struct Part<'a> {
+    text: &'a str,
+    start: usize,
+}
+
+/// Découpe une partie au niveau supérieur (hors parenthèses) ; les parties sont
+/// rognées et peuvent être vides
+/// Splits a part at the top level (outside parentheses); the parts are trimmed
+/// and may be empty
+fn split(part: Part, separator: impl Fn(char) -> bool) -> Result<Vec<Part>, GradientError> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut from = (0, part.start);
+    let mut push = |from: (usize, usize), to: usize| {
+        let text = &part.text[from.0..to];
+        let trimmed = text.trim_start();
+        let skipped = text.chars().count() - trimmed.chars().count();
+        parts.push(Part { text: trimmed.trim_end(), start: from.1 + skipped });
+    };
+
+    for (index, (byte, c)) in part.text.char_indices().enumerate() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.checked_sub(1).ok_or_else(|| GradientError::InvalidSyntax(part.text.to_string()))?,
+            c if depth == 0 && separator(c) => {
+                push(from, byte);
+                from = (byte + c.len_utf8(), part.start + index + 1);
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return Err(GradientError::InvalidSyntax(part.text.to_string()));
+    }
+    push(from, part.text.len());
+    Ok(parts)
+}
+
+/// Sépare un nombre de son unité (`25%`, `0.5turn`, `0`) / Splits a number from its unit (`25%`, `0.5turn`, `0`)
+fn dimension(text: &str) -> Option<(f64, String)> {
+    let split = text
+        .char_indices()
+        .find(|(i, c)| !(c.is_ascii_digit() || *c == '.' || (*i == 0 && (*c == '+' || *c == '-'))))
+        .map_or(text.len(), |(i, _)| i);
+    let value = text[..split].parse::<f64>().ok()?;
+    Some((value, text[split..].to_ascii_lowercase()))
+}
+
+/// Angle en degrés / Angle in degrees
+fn angle(text: &str) -> Option<f64> {
+    let (value, unit) = dimension(text)?;
+    match unit.as_str() {
+        "deg" => Some(value),
+        "grad" => Some(value * 0.9),
+        "rad" => Some(value.to_degrees()),
+        "turn" => Some(value * 360.0),
+        "" if value == 0.0 => Some(0.0),
+        _ => None,
+    }
+}
+
+/// Position d'un arrêt ou d'une indication (0.0 - 1.0 sur la ligne)
+/// Position of a stop or a hint (0.0 - 1.0 on the line)
+fn position(kind: GradientKind, word: Part) -> Result<f64, GradientError> {
+    let (value, unit) = dimension(word.text).ok_or_else(|| GradientError::InvalidSyntax(word.text.to_string()))?;
+    match unit.as_str() {
+        "%" => Ok(value / 100.0),
+        "" if value == 0.0 => Ok(0.0),
+        _ if kind == GradientKind::Conic => {
+            angle(word.text).map(|degrees| degrees / 360.0).ok_or_else(|| GradientError::UnsupportedUnit(word.text.to_string()))
+        }
+        "" => Err(GradientError::InvalidSyntax(word.text.to_string())),
+        _ => Err(GradientError::UnsupportedUnit(word.text.to_string())),
+    }
+}
+
+/// Si le premier mot d'un élément ouvre le préambule (direction, forme, espace…)
+/// If the first word of an item opens the preamble (direction, shape, space…)
+fn is_preamble(word: &str) -> bool {
+    let word = word.to_ascii_lowercase();
+    matches!(
+        word.as_str(),
+        "in" | "to" | "from" | "at" | "circle" | "ellipse" | "closest-side" | "closest-corner" | "farthest-side" | "farthest-corner"
+    ) || dimension(&word).is_some()
+}
+
+/// Résultat du préambule : espace, méthode de teinte et angle
+/// Preamble result: space, hue method and angle
+type Preamble = (Option<InterpolationSpace>, HueInterpolation, Option<f64>);
+
+/// Analyse le préambule ; la géométrie radiale et le centre n'influent pas sur les couleurs
+/// Parses the preamble; radial geometry and the center do not affect the colors
+fn parse_preamble(kind: GradientKind, words: &[Part]) -> Result<Preamble, GradientError> {
+    let invalid = |word: &Part| GradientError::InvalidSyntax(word.text.to_string());
+    let mut space = None;
+    let mut hue = HueInterpolation::Shorter;
+    let mut direction = None;
+    let mut i = 0;
+
+    while i < words.len() {
+        let word = words[i].text.to_ascii_lowercase();
+        match word.as_str() {
+            "in" if space.is_none() => {
+                let name = words.get(i + 1).ok_or_else(|| invalid(&words[i]))?;
+                let parsed = InterpolationSpace::from_css_name(&name.text.to_ascii_lowercase())
+                    .ok_or_else(|| GradientError::UnknownColorSpace(name.text.to_string()))?;
+                space = Some(parsed);
+                i += 2;
+
+                // `<méthode> hue`, réservé aux espaces polaires / `<method> hue`, polar spaces only
+                if words.get(i + 1).is_some_and(|w| w.text.eq_ignore_ascii_case("hue")) {
+                    if parsed.hue_index().is_none() {
+                        return Err(invalid(&words[i]));
+                    }
+                    hue = match words[i].text.to_ascii_lowercase().as_str() {
+                        "shorter" => HueInterpolation::Shorter,
+                        "longer" => HueInterpolation::Longer,
+                        "increasing" => HueInterpolation::Increasing,
+                        "decreasing" => HueInterpolation::Decreasing,
+                        _ => return Err(invalid(&words[i])),
+                    };
+                    i += 2;
+                }
+            }
+            "to" if kind == GradientKind::Linear && direction.is_none() => {
+                // Côté ou coin, angles d'une boîte carrée / Side or corner, angles of a square box
+                let mut vertical = None;
+                let mut horizontal = None;
+                i += 1;
+                while let Some(side) = words.get(i) {
+                    match side.text.to_ascii_lowercase().as_str() {
+                        "top" if vertical.is_none() => vertical = Some(0.0),
+                        "bottom" if vertical.is_none() => vertical = Some(180.0),
+                        "left" if horizontal.is_none() => horizontal = Some(270.0),
+                        "right" if horizontal.is_none() => horizontal = Some(90.0),
+                        _ => break,
+                    }
+                    i += 1;
+                }
+                direction = Some(match (vertical, horizontal) {
+                    (Some(v), None) => v,
+                    (None, Some(h)) => h,
+                    (Some(v), Some(h)) => match (v == 0.0, h == 90.0) {
+                        (true, true) => 45.0,
+                        (false, true) => 135.0,
+                        (false, false) => 225.0,
+                        (true, false) => 315.0,
+                    },
+                    (None, None) => return Err(invalid(&words[i - 1])),
+                });
+            }
+            "from" if kind == GradientKind::Conic && direction.is_none() => {
+                let value = words.get(i + 1).ok_or_else(|| invalid(&words[i]))?;
+                direction = Some(angle(value.text).ok_or_else(|| invalid(value))?);
+                i += 2;
+            }
+            "at" if kind != GradientKind::Linear => {
+                // Centre : mots-clés ou longueurs / Center: keywords or lengths
+                i += 1;
+                let start = i;
+                while words.get(i).is_some_and(|w| {
+                    matches!(w.text.to_ascii_lowercase().as_str(), "left" | "center" | "right" | "top" | "bottom")
+                        || dimension(w.text).is_some()
+                }) {
+                    i += 1;
+                }
+                if i == start {
+                    return Err(invalid(&words[start - 1]));
+                }
+            }
+            "circle" | "ellipse" | "closest-side" | "closest-corner" | "farthest-side" | "farthest-corner"
+                if kind == GradientKind::Radial =>
+            {
+                i += 1;
+            }
+            _ if kind == GradientKind::Radial && dimension(&word).is_some() => i += 1,
+            _ if kind == GradientKind::Linear && direction.is_none() && angle(&word).is_some() => {
+                direction = angle(&word);
+                i += 1;
+            }
+            _ => return Err(invalid(&words[i])),
+        }
+    }
+
+    // Vers le bas par défaut, départ en haut pour le conique / Downwards by default, starting at the top for conic
+    let angle = match kind {
+        GradientKind::Radial => None,
+        _ => Some(direction.unwrap_or(if kind == GradientKind::Linear { 180.0 } else { 0.0 })),
+    };
+    Ok((space, hue, angle))
+}
+
+/// Si la couleur utilise une syntaxe héritée (hexadécimal, nom, rgb(), hsl())
+/// If the color uses a legacy syntax (hex, name, rgb(), hsl())
+fn is_legacy(text: &str) -> bool {
+    let name = text.split('(').next().unwrap_or_default().to_ascii_lowercase();
+    text.starts_with('#') || !text.contains('(') || matches!(name.as_str(), "rgb" | "rgba" | "hsl" | "hsla")
+}
+
+/// Analyse un dégradé CSS / Parses a CSS gradient
+///
+/// # Arguments
+/// * `input` - Par ex. `linear-gradient(90deg in oklch, #0a3 0%, #fe0 100%)`
+///   E.g. `linear-gradient(90deg in oklch, #0a3 0%, #fe0 100%)`
+pub fn parse(input: &str) -> Result<Gradient, GradientError> {
+    let trimmed = input.trim();
+    let offset = input.chars().count() - input.trim_start().chars().count();
+    let open = trimmed.find('(').ok_or_else(|| GradientError::UnknownFunction(trimmed.to_string()))?;
+
+    let name = trimmed[..open].trim().to_ascii_lowercase();
+    let (repeating, name) = match name.strip_prefix("repeating-") {
+        Some(name) => (true, name),
+        None => (false, name.as_str()),
+    };
+    let kind = match name {
+        "linear-gradient" => GradientKind::Linear,
+        "radial-gradient" => GradientKind::Radial,
+        "conic-gradient" => GradientKind::Conic,
+        _ => return Err(GradientError::UnknownFunction(trimmed[..open].to_string())),
+    };
+    let body = trimmed[open + 1..]
+        .strip_suffix(')')
+        .ok_or_else(|| GradientError::InvalidSyntax(trimmed.to_string()))?;
+
+    let items = split(Part { text: body, start: offset + trimmed[..=open].chars().count() }, |c| c == ',')?;
+    if items.iter().any(|item| item.text.is_empty()) {
+        return Err(GradientError::InvalidSyntax(body.to_string()));
+    }
+    // Plusieurs espaces ou retours à la ligne ne séparent les mots qu'une fois
+    // Several spaces or line breaks only separate words once
+    let words: Vec<Vec<Part>> = items
+        .iter()
+        .map(|item| Ok(split(*item, char::is_whitespace)?.into_iter().filter(|word| !word.text.is_empty()).collect()))
+        .collect::<Result<_, _>>()?;
+
+    let (space, hue, angle, words) = if is_preamble(words[0][0].text) {
+        let (space, hue, angle) = parse_preamble(kind, &words[0])?;
+        (space, hue, angle, &words[1..])
+    } else {
+        let (_, hue, angle) = parse_preamble(kind, &[])?;
+        (None, hue, angle, &words[..])
+    };
+
+    // Arrêts (avec position optionnelle) et indications entre deux arrêts
+    // Stops (with optional position) and hints between two stops
+    let mut stops: Vec<(css_color::ParsedColor, Option<f64>)> = Vec::new();
+    let mut hints: Vec<Option<f64>> = Vec::new();
+    let mut legacy = true;
+    let mut color_items = 0;
+    for (index, item) in words.iter().enumerate() {
+        if item.len() == 1 && dimension(item[0].text).is_some() {
+            let is_between = !stops.is_empty() && index + 1 < words.len() && hints.len() == stops.len() - 1;
+            if !is_between {
+                return Err(GradientError::InvalidSyntax(item[0].text.to_string()));
+            }
+            hints.push(Some(position(kind, item[0])?));
+            continue;
+        }
+        if item.len() > 3 {
+            return Err(GradientError::InvalidSyntax(item[3].text.to_string()));
+        }
+
+        let color = css_color::parse(item[0].text).map_err(|error| {
+            GradientError::InvalidColor(ParseError { start: error.start + item[0].start, end: error.end + item[0].start, ..error })
+        })?;
+        legacy &= is_legacy(item[0].text);
+        color_items += 1;
+
+        // Un arrêt à deux positions vaut deux arrêts / A stop with two positions counts as two stops
+        let positions = item[1..].iter().map(|word| position(kind, *word).map(Some)).collect::<Result<Vec<_>, _>>()?;
+        for position in if positions.is_empty() { vec![None] } else { positions } {
+            if hints.len() < stops.len() {
+                hints.push(None);
+            }
+            stops.push((color, position));
+        }
+    }
+    if color_items < 2 {
+        return Err(GradientError::TooFewStops);
+    }
+
+    // Correction des positions (CSS Images 3, § 3.5.3) / Position fix-up (CSS Images 3, § 3.5.3)
+    let mut positions: Vec<Option<f64>> = stops.iter().map(|(_, position)| *position).collect();
+    let last = positions.len() - 1;
+    positions[0] = positions[0].or(Some(0.0));
+    positions[last] = positions[last].or(Some(1.0));
+    let mut max = f64::NEG_INFINITY;
+    for position in positions.iter_mut().flatten() {
+        max = max.max(*position);
+        *position = max;
+    }
+    let mut i = 1;
+    while i < last {
+        if positions[i].is_none() {
+            let before = positions[i - 1].unwrap_or_default();
+            let next = (i..=last).find(|&j| positions[j].is_some()).unwrap_or(last);
+            let after = positions[next].unwrap_or(1.0);
+            let count = (next - i + 1) as f64;
+            for (k, position) in positions[i..next].iter_mut().enumerate() {
+                *position = Some(before + (after - before) * (k + 1) as f64 / count);
+            }
+            i = next;
+        }
+        i += 1;
+    }
+
+    let stops: Vec<ColorStop> = stops
+        .iter()
+        .zip(&positions)
+        .map(|((color, _), position)| {
+            let srgb = color.color.to_srgb();
+            let (r, g, b) = conversion::srgb_to_rgb8(conversion::gamut_map_srgb(srgb));
+            ColorStop { srgb, hex: format_hex_color(r, g, b), alpha: color.alpha, position: position.unwrap_or_default() }
+        })
+        .collect();
+
+    // Une indication reste entre ses deux arrêts / A hint stays between its two stops
+    let hints = hints
+        .iter()
+        .enumerate()
+        .map(|(i, hint)| hint.map(|h| h.clamp(stops[i].position, stops[i + 1].position)))
+        .collect();
+
+    Ok(Gradient {
+        kind,
+        repeating,
+        // Oklab par défaut, sRGB si toutes les couleurs sont en syntaxe héritée
+        // Oklab by default, sRGB if every color uses a legacy syntax
+        space: space.unwrap_or(if legacy { InterpolationSpace::Srgb } else { InterpolationSpace::Oklab }),
+        hue,
+        angle,
+        stops,
+        hints,
+    })
+}
+
+// =============================================================================
+// FONCTIONS DE CALCUL
+// CALCULATION FUNCTIONS
+// =============================================================================
+
+impl Gradient {
+    /// Mélange deux arrêts en alpha prémultiplié / Mixes two stops with premultiplied alpha
+    fn mix(&self, a: &ColorStop, b: &ColorStop, weight: f64) -> ([f64; 3], f64) {
+        let mut from = self.space.srgb_to_components(a.srgb);
+        let mut to = self.space.srgb_to_components(b.srgb);
+        let hue = self.space.hue_index();
+        if let Some(h) = hue {
+            match (self.space.powerless_hue(from), self.space.powerless_hue(to)) {
+                (true, false) => from[h] = to[h],
+                (false, true) => to[h] = from[h],
+                _ => {}
+            }
+            (from[h], to[h]) = self.hue.fix(from[h], to[h]);
+        }
+
+        let lerp = |x: f64, y: f64| x + (y - x) * weight;
+        let alpha = lerp(a.alpha, b.alpha);
+        let components = [0, 1, 2].map(|i| {
+            if Some(i) == hue {
+                lerp(from[i], to[i])
+            } else if alpha > 0.0 {
+                lerp(from[i] * a.alpha, to[i] * b.alpha) / alpha
+            } else {
+                0.0
+            }
+        });
+        (self.space.components_to_srgb(components), alpha)
+    }
+
+    /// Couleur et opacité à une position de la ligne (0.0 - 1.0)
+    /// Color and opacity at a position of the line (0.0 - 1.0)
+    pub fn color_at(&self, position: f64) -> ([f64; 3], f64) {
+        let first = self.stops[0].position;
+        let last = self.stops[self.stops.len() - 1].position;
+        let position = if self.repeating && last - first >= MIN_PERIOD {
+            first + (position - first).rem_euclid(last - first)
+        } else {
+            position
+        };
+
+        let Some(i) = self.stops.iter().rposition(|stop| stop.position <= position) else {
+            return (self.stops[0].srgb, self.stops[0].alpha);
+        };
+        if i == self.stops.len() - 1 {
+            return (self.stops[i].srgb, self.stops[i].alpha);
+        }
+
+        let (a, b) = (&self.stops[i], &self.stops[i + 1]);
+        let mut weight = (position - a.position) / (b.position - a.position);
+        // Indication de transition : le milieu du mélange est déplacé
+        // Transition hint: the middle of the blend is moved
+        if let Some(hint) = self.hints[i] {
+            let middle = (hint - a.position) / (b.position - a.position);
+            weight = if middle <= 0.0 {
+                1.0
+            } else if middle >= 1.0 {
+                0.0
+            } else {
+                weight.powf(0.5f64.ln() / middle.ln())
+            };
+        }
+        self.mix(a, b, weight)
+    }
+
+    /// Positions échantillonnées : grille régulière, plus chaque arrêt et juste avant
+    /// Sampled positions: regular grid, plus each stop and just before it
+    fn sample_positions(&self) -> Vec<f64> {
+        let mut positions: Vec<f64> = (0..=SAMPLES).map(|k| k as f64 / SAMPLES as f64).collect();
+        let first = self.stops[0].position;
+        let period = self.stops[self.stops.len() - 1].position - first;
+        for stop in &self.stops {
+            let occurrences: Vec<f64> = if self.repeating && period >= MIN_PERIOD {
+                let k = (-stop.position / period).ceil() as i64;
+                (k..).map(|k| stop.position + k as f64 * period).take_while(|p| *p <= 1.0).take(SAMPLES).collect()
+            } else {
+                vec![stop.position]
+            };
+            for position in occurrences.into_iter().filter(|p| (0.0..=1.0).contains(p)) {
+                positions.push(position);
+                positions.push((position - HARD_STOP_OFFSET).max(0.0));
+            }
+        }
+        positions
+    }
+}
+
+/// Mesure le contraste du premier plan du store le long d'un dégradé
+/// Measures the store foreground contrast along a gradient
+///
+/// Chaque échantillon est composé sur la couleur de page, puis le premier plan
+/// est composé sur l'échantillon, comme `color::update_contrast`. Le contraste
+/// est mesuré sur les couleurs ramenées dans le gamut sRGB, celles que l'écran
+/// affiche et que `hex` rapporte.
+/// Each sample is composited over the page color, then the foreground is
+/// composited over the sample, like `color::update_contrast`. Contrast is
+/// measured on the colors gamut mapped into sRGB, the ones the screen shows
+/// and `hex` reports.
+pub fn analyze(store: &ResultStore, gradient: Gradient) -> GradientReport {
+    let page = conversion::rgb8_to_srgb(store.page_rgb);
+    let foreground = store.foreground_precise.to_srgb_mapped();
+
+    let samples: Vec<(f64, [f64; 3], f64)> = gradient
+        .sample_positions()
+        .into_iter()
+        .map(|position| {
+            let (srgb, alpha) = gradient.color_at(position);
+            let background = color::composite(conversion::gamut_map_srgb(srgb), alpha, page);
+            let ratio = color::contrast_ratio(color::composite(foreground, store.foreground_alpha, background), background);
+            (position, background, ratio)
+        })
+        .collect();
+
+    let sample = |(position, background, ratio): (f64, [f64; 3], f64)| {
+        let (r, g, b) = conversion::srgb_to_rgb8(background);
+        GradientSample {
+            position: (position * 10000.0).round() / 100.0,
+            angle: match gradient.kind {
+                GradientKind::Conic => gradient.angle.map(|start| ((start + position * 360.0).rem_euclid(360.0) * 10.0).round() / 10.0),
+                _ => None,
+            },
+            hex: format_hex_color(r, g, b),
+            contrast_ratio_rounded: color::round_ratio(ratio),
+        }
+    };
+    let lowest = *samples.iter().min_by(|a, b| a.2.total_cmp(&b.2)).expect("at least one sample");
+    let highest = *samples.iter().max_by(|a, b| a.2.total_cmp(&b.2)).expect("at least one sample");
+
+    GradientReport {
+        lowest: sample(lowest),
+        highest: sample(highest),
+        compliance: compliance::evaluate(&store.profile.criteria, lowest.2, store.text_spec.as_ref().map(|t| t.large)),
+        gradient,
+    }
+}
+
+// =============================================================================
+// COMMANDES TAURI
+// TAURI COMMANDS
+// =============================================================================
+
+/// Mesure le contraste du premier plan le long d'un dégradé CSS
+/// Measures the foreground contrast along a CSS gradient
+///
+/// # Arguments
+/// * `gradient` - Dégradé CSS, par ex. `linear-gradient(90deg, #0a3 0%, #fe0 100%)`
+///   CSS gradient, e.g. `linear-gradient(90deg, #0a3 0%, #fe0 100%)`
+#[tauri::command]
+pub fn gradient_contrast(state: tauri::State<AppState>, gradient: String) -> Result<GradientReport, GradientError> {
+    let gradient = parse(&gradient)?;
+    let store = state.store.lock().unwrap();
+    Ok(analyze(&store, gradient))
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conversion::PreciseColor;
+
+    fn hex_at(gradient: &Gradient, position: f64) -> String {
+        let (srgb, _) = gradient.color_at(position);
+        let (r, g, b) = conversion::srgb_to_rgb8(srgb);
+        format_hex_color(r, g, b)
+    }
+
+    #[test]
+    fn test_parse_gradients() {
+        let gradient = parse("linear-gradient(90deg, #0a3 0%, #fe0 100%)").unwrap();
+        assert_eq!((gradient.kind, gradient.space, gradient.angle), (GradientKind::Linear, InterpolationSpace::Srgb, Some(90.0)));
+        assert_eq!((hex_at(&gradient, 0.0), hex_at(&gradient, 1.0)), ("#00AA33".to_string(), "#FFEE00".to_string()));
+        assert_eq!(hex_at(&gradient, 0.5), "#80CC1A");
+
+        // Positions manquantes réparties, arrêt à deux positions, position en retrait
+        // Missing positions spread out, two-position stop, position moved back
+        let gradient = parse("repeating-linear-gradient(to top right in oklch longer hue, red, lime 20% 40%, blue 10%, white)").unwrap();
+        let positions: Vec<f64> = gradient.stops.iter().map(|s| s.position).collect();
+        assert_eq!(positions, vec![0.0, 0.2, 0.4, 0.4, 1.0]);
+        assert_eq!((gradient.space, gradient.hue, gradient.angle), (InterpolationSpace::Oklch, HueInterpolation::Longer, Some(45.0)));
+        assert!(gradient.repeating);
+
+        let gradient = parse("conic-gradient(from 0.25turn at 30% 40%, oklch(70% 0.1 200), 25%, black 90deg)").unwrap();
+        assert_eq!((gradient.kind, gradient.space, gradient.angle), (GradientKind::Conic, InterpolationSpace::Oklab, Some(90.0)));
+        assert_eq!(gradient.hints, vec![Some(0.25)]);
+
+        assert!(parse("radial-gradient(circle closest-side at center, transparent, #000 50%)").is_ok());
+        assert_eq!(parse("linear-gradient(red)"), Err(GradientError::TooFewStops));
+        assert_eq!(parse("linear-gradient(red 10px, blue)"), Err(GradientError::UnsupportedUnit("10px".to_string())));
+        assert_eq!(parse("linear-gradient(in cmyk, red, blue)"), Err(GradientError::UnknownColorSpace("cmyk".to_string())));
+        assert_eq!(parse("linear-gradient(in lab longer hue, red, blue)"), Err(GradientError::InvalidSyntax("longer".to_string())));
+        assert!(matches!(parse("fancy-gradient(red, blue)"), Err(GradientError::UnknownFunction(_))));
+        match parse("linear-gradient(red, #12)") {
+            Err(GradientError::InvalidColor(error)) => assert_eq!((error.start, error.end), (21, 24)),
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_extra_whitespace() {
+        // Espaces multiples et arrêts alignés sur plusieurs lignes (DevTools, Prettier)
+        // Multiple spaces and column-aligned stops over several lines (DevTools, Prettier)
+        let single = parse("linear-gradient(to right, #0a3 0%, red 10%, #fe0 100%)").unwrap();
+        let spaced = parse("linear-gradient(\n  to  right,\n  #0a3   0%,\n  red  10%,\n  #fe0 100%\n)").unwrap();
+        assert_eq!(spaced, single);
+        assert!(parse("linear-gradient(red  10%, blue)").is_ok());
+
+        // Les positions d'erreur restent celles de la saisie / Error positions stay those of the input
+        match parse("linear-gradient(red,\n    #12   50%)") {
+            Err(GradientError::InvalidColor(error)) => assert_eq!((error.start, error.end), (25, 28)),
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_interpolation() {
+        // Le transparent prémultiplié ne grise pas le blanc / Premultiplied transparent does not grey out white
+        let gradient = parse("linear-gradient(white, transparent)").unwrap();
+        let (srgb, alpha) = gradient.color_at(0.5);
+        assert_eq!((conversion::srgb_to_rgb8(srgb), alpha), ((255, 255, 255), 0.5));
+
+        // Arrêt net et indication de transition / Hard stop and transition hint
+        let gradient = parse("linear-gradient(black 50%, white 50%)").unwrap();
+        assert_eq!((hex_at(&gradient, 0.4999), hex_at(&gradient, 0.5)), ("#000000".to_string(), "#FFFFFF".to_string()));
+        let gradient = parse("linear-gradient(black, 10%, white)").unwrap();
+        assert_eq!(hex_at(&gradient, 0.1), "#808080");
+
+        // Le gris garde la teinte de l'autre arrêt en OKLCH / Grey takes the hue of the other stop in OKLCH
+        let gradient = parse("linear-gradient(in oklch, white, blue)").unwrap();
+        let hue = conversion::srgb_to_oklch(gradient.color_at(0.5).0)[2];
+        assert!((hue - conversion::srgb_to_oklch([0.0, 0.0, 1.0])[2]).abs() < 0.5);
+    }
+
+    #[test]
+    fn test_lowest_contrast() {
+        let mut store = ResultStore::default();
+        color::set_color(&mut store, "foreground", PreciseColor::from_rgb8((0xFF, 0xFF, 0xFF)), Some(1.0));
+        color::update_contrast(&mut store);
+
+        // Le jaune en fin de dégradé est le pire fond pour un texte blanc
+        // The yellow at the end of the gradient is the worst background for white text
+        let report = analyze(&store, parse("linear-gradient(90deg, #0a3 0%, #fe0 100%)").unwrap());
+        assert_eq!((report.lowest.position, report.lowest.hex.as_str()), (100.0, "#FFEE00"));
+        assert_eq!((report.highest.position, report.highest.hex.as_str()), (0.0, "#00AA33"));
+        assert!(!report.compliance.iter().any(|c| c.pass));
+
+        // Un arrêt transparent laisse voir la page blanche ; angle conique rapporté
+        // A transparent stop shows the white page; conic angle reported
+        let report = analyze(&store, parse("conic-gradient(from 90deg, black, transparent 50%, black)").unwrap());
+        assert_eq!((report.lowest.position, report.lowest.angle), (50.0, Some(270.0)));
+        assert_eq!(report.lowest.contrast_ratio_rounded, 1.0);
+
+        // Bleu Rec. 2020 hors gamut : ratio mesuré sur le bleu affiché (8.1, pas 9.6)
+        // Out-of-gamut Rec. 2020 blue: ratio measured on the displayed blue (8.1, not 9.6)
+        let report = analyze(&store, parse("linear-gradient(color(rec2020 0 0 1), color(rec2020 0 0 1))").unwrap());
+        assert_eq!((report.lowest.hex.as_str(), report.lowest.contrast_ratio_rounded), ("#005193", 8.1));
+    }
+}
@@ -50,3 +50,70 @@ pub fn menu_t(locale: &str, key: &str) -> &'static str {
         _ => "?",
     }
 }
+
+/// Retourne le vocabulaire des noms de couleur descriptifs pour une locale donnée
+/// Returns the descriptive colour name vocabulary for a given locale
+///
+/// `pattern` place la clarté `{l}`, la chroma `{c}` et la teinte `{h}`.
+/// `pattern` places lightness `{l}`, chroma `{c}` and hue `{h}`.
+pub fn name_t(locale: &str, key: &str) -> &'static str {
+    match (locale, key) {
+        // === English ===
+        ("en", "pattern") => "{l} {c} {h}",
+        ("en", "very_dark") => "very dark",
+        ("en", "dark") => "dark",
+        ("en", "light") => "light",
+        ("en", "very_light") => "very light",
+        ("en", "desaturated") => "desaturated",
+        ("en", "vivid") => "vivid",
+        ("en", "warm") => "warm",
+        ("en", "cool") => "cool",
+        ("en", "red") => "red",
+        ("en", "orange") => "orange",
+        ("en", "brown") => "brown",
+        ("en", "yellow") => "yellow",
+        ("en", "olive") => "olive",
+        ("en", "lime") => "lime",
+        ("en", "green") => "green",
+        ("en", "cyan") => "cyan",
+        ("en", "blue") => "blue",
+        ("en", "violet") => "violet",
+        ("en", "magenta") => "magenta",
+        ("en", "pink") => "pink",
+        ("en", "grey") => "grey",
+        ("en", "black") => "black",
+        ("en", "white") => "white",
+
+        // === Français ===
+        // Les adjectifs suivent le nom et restent au masculin (« le bleu »)
+        // Adjectives follow the noun and stay masculine ("le bleu")
+        ("fr", "pattern") => "{h} {c} {l}",
+        ("fr", "very_dark") => "tr\u{00e8}s fonc\u{00e9}",
+        ("fr", "dark") => "fonc\u{00e9}",
+        ("fr", "light") => "clair",
+        ("fr", "very_light") => "tr\u{00e8}s clair",
+        ("fr", "desaturated") => "d\u{00e9}satur\u{00e9}",
+        ("fr", "vivid") => "vif",
+        ("fr", "warm") => "chaud",
+        ("fr", "cool") => "froid",
+        ("fr", "red") => "rouge",
+        ("fr", "orange") => "orange",
+        ("fr", "brown") => "brun",
+        ("fr", "yellow") => "jaune",
+        ("fr", "olive") => "olive",
+        ("fr", "lime") => "vert citron",
+        ("fr", "green") => "vert",
+        ("fr", "cyan") => "cyan",
+        ("fr", "blue") => "bleu",
+        ("fr", "violet") => "violet",
+        ("fr", "magenta") => "magenta",
+        ("fr", "pink") => "rose",
+        ("fr", "grey") => "gris",
+        ("fr", "black") => "noir",
+        ("fr", "white") => "blanc",
+
+        // Fallback vers l'anglais / Fallback to English
+        ("en", _) => "?",
+        (_, key) => name_t("en", key),
+    }
+}
@@ -15,6 +15,16 @@ use serde::{Deserialize, Serialize};
 // Import Mutex for thread-safe synchronization
 use std::sync::Mutex;
 
+// Conversions et état de l'application pour l'épreuvage écran
+// Conversions and application state for soft proofing
+use crate::conversion::{self, RgbSpace};
+use crate::store::AppState;
+
+// Import de la couleur exacte pour les conversions sans écrêtage (macOS)
+// Import the exact color for conversions without clipping (macOS)
+#[cfg(target_os = "macos")]
+use crate::conversion::PreciseColor;
+
 // =============================================================================
 // STRUCTURES
 // =============================================================================
@@ -196,36 +206,52 @@ pub fn get_selected_nscolorspace() -> Option<objc2::rc::Retained<objc2_app_kit::
 /// Convertit une couleur RGB depuis l'espace colorimétrique source vers sRGB
 /// Converts an RGB color from source color space to sRGB
 ///
+/// Les espaces larges connus (Display P3, Rec. 2020) sont conservés tels quels ;
+/// les autres sont convertis en sRGB étendu, sans écrêtage.
+/// Known wide spaces (Display P3, Rec. 2020) are kept as is;
+/// others are converted to extended sRGB, without clipping.
+///
 /// # Arguments
 /// * `r`, `g`, `b` - Composantes RGB en u8 (0-255)
 /// * `source_colorspace` - Espace colorimétrique source (ou None pour Auto)
 ///
 /// # Returns
-/// * `(u8, u8, u8)` - Composantes RGB converties en sRGB
+/// * `PreciseColor` - Couleur exacte, étiquetée avec son espace
 #[cfg(target_os = "macos")]
-pub fn convert_color_to_srgb(r: u8, g: u8, b: u8, source_colorspace: Option<&objc2_app_kit::NSColorSpace>) -> (u8, u8, u8) {
+pub fn convert_color_to_srgb(r: u8, g: u8, b: u8, source_colorspace: Option<&objc2_app_kit::NSColorSpace>) -> PreciseColor {
     // Import des types nécessaires
     // Import required types
     use objc2_app_kit::{NSColor, NSColorSpace};
     use std::ptr::NonNull;
 
+    // Couleur capturée, interprétée comme sRGB par défaut
+    // Captured color, interpreted as sRGB by default
+    let unchanged = PreciseColor::from_rgb8((r, g, b));
+
     // Si pas d'espace colorimétrique source, retourne les couleurs inchangées
     // If no source color space, return colors unchanged
     let source_cs = match source_colorspace {
         Some(cs) => cs,
-        None => return (r, g, b),
+        None => return unchanged,
     };
 
+    // Convertit les valeurs u8 en CGFloat (0.0 - 1.0)
+    // Convert u8 values to CGFloat (0.0 - 1.0)
+    let r_f: f64 = r as f64 / 255.0;
+    let g_f: f64 = g as f64 / 255.0;
+    let b_f: f64 = b as f64 / 255.0;
+    let a_f: f64 = 1.0;
+
+    // Espaces larges connus, identifiés par leur profil ICC : conserve les composantes exactes
+    // Known wide spaces, identified by their ICC profile: keep the exact components
+    let wide_space = source_cs.ICCProfileData().and_then(|data| identify_rgb_space(&data.to_vec()));
+    if let Some(space) = wide_space {
+        return PreciseColor { space, components: [r_f, g_f, b_f] };
+    }
+
     // Bloc unsafe pour les appels Objective-C
     // Unsafe block for Objective-C calls
     unsafe {
-        // Convertit les valeurs u8 en CGFloat (0.0 - 1.0)
-        // Convert u8 values to CGFloat (0.0 - 1.0)
-        let r_f: f64 = r as f64 / 255.0;
-        let g_f: f64 = g as f64 / 255.0;
-        let b_f: f64 = b as f64 / 255.0;
-        let a_f: f64 = 1.0;
-
         // Crée un tableau de composantes [R, G, B, A]
         // Create components array [R, G, B, A]
         let components: [f64; 4] = [r_f, g_f, b_f, a_f];
@@ -238,7 +264,7 @@ pub fn convert_color_to_srgb(r: u8, g: u8, b: u8, source_colorspace: Option<&obj
         // Check that pointer is valid
         let components_ptr = match components_ptr {
             Some(ptr) => ptr,
-            None => return (r, g, b), // Retourne les couleurs inchangées si échec
+            None => return unchanged, // Retourne les couleurs inchangées si échec
         };
 
         // Crée une couleur dans l'espace colorimétrique source
@@ -249,30 +275,24 @@ pub fn convert_color_to_srgb(r: u8, g: u8, b: u8, source_colorspace: Option<&obj
             4, // 4 composantes (RGBA) / 4 components (RGBA)
         );
 
-        // Récupère l'espace colorimétrique sRGB de destination
-        // Get the destination sRGB color space
-        let srgb_cs = NSColorSpace::sRGBColorSpace();
+        // Récupère l'espace sRGB étendu de destination (valeurs hors 0-1 autorisées)
+        // Get the destination extended sRGB color space (values outside 0-1 allowed)
+        let srgb_cs = NSColorSpace::extendedSRGBColorSpace();
 
         // Convertit la couleur vers sRGB
         // Convert the color to sRGB
         let srgb_color = match source_color.colorUsingColorSpace(&srgb_cs) {
             Some(c) => c,
-            None => return (r, g, b), // Retourne les couleurs inchangées si la conversion échoue
+            None => return unchanged, // Retourne les couleurs inchangées si la conversion échoue
         };
 
-        // Extrait les composantes de la couleur convertie
-        // Extract components from the converted color
-        let srgb_r = srgb_color.redComponent();
-        let srgb_g = srgb_color.greenComponent();
-        let srgb_b = srgb_color.blueComponent();
-
-        // Convertit les valeurs CGFloat (0.0 - 1.0) en u8 (0 - 255)
-        // Convert CGFloat values (0.0 - 1.0) to u8 (0 - 255)
-        let r_out = (srgb_r * 255.0).round().clamp(0.0, 255.0) as u8;
-        let g_out = (srgb_g * 255.0).round().clamp(0.0, 255.0) as u8;
-        let b_out = (srgb_b * 255.0).round().clamp(0.0, 255.0) as u8;
-
-        (r_out, g_out, b_out)
+        // Extrait les composantes de la couleur convertie, sans écrêtage
+        // Extract components from the converted color, without clipping
+        PreciseColor::from_srgb([
+            srgb_color.redComponent(),
+            srgb_color.greenComponent(),
+            srgb_color.blueComponent(),
+        ])
     }
 }
 
@@ -338,6 +358,568 @@ fn get_system_color_spaces() -> Vec<ICCProfile> {
     }]
 }
 
+// =============================================================================
+// PROFILS DE SORTIE CMYK (ÉPREUVAGE ÉCRAN)
+// CMYK OUTPUT PROFILES (SOFT PROOFING)
+// =============================================================================
+//
+// Lecture des profils d'impression (FOGRA39, GRACoL…) chargés depuis le disque :
+// les tables A2B (CMYK vers PCS) et B2A (PCS vers CMYK) de type lut8, lut16,
+// lutAtoB et lutBtoA sont évaluées en intention colorimétrique relative.
+// Reading of print profiles (FOGRA39, GRACoL…) loaded from disk: the A2B
+// (CMYK to PCS) and B2A (PCS to CMYK) tables of type lut8, lut16, lutAtoB and
+// lutBtoA are evaluated with the relative colorimetric intent.
+//
+// Reference: ICC.1:2010 (profile version 4.3.0.0), sections 10.8 - 10.12
+
+/// Point blanc D50 de l'espace de connexion (PCS) / Profile connection space (PCS) D50 white point
+const ICC_D50: [f64; 3] = [0.9642, 1.0, 0.8249];
+
+/// Erreur de lecture d'un profil de sortie / Output profile reading error
+#[derive(Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", content = "value", rename_all = "kebab-case")]
+pub enum IccError {
+    /// Fichier illisible / Unreadable file
+    Io(String),
+    /// Profil mal formé ou tronqué / Malformed or truncated profile
+    Invalid(String),
+    /// Profil valide mais non pris en charge (espace non CMYK, type de table inconnu)
+    /// Valid but unsupported profile (non-CMYK space, unknown table type)
+    Unsupported(String),
+}
+
+/// Encodage des valeurs PCS dans une table / Encoding of the PCS values in a table
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum PcsEncoding {
+    /// Lab 16 bits hérité de la version 2 (lut16) / Version 2 legacy 16-bit Lab (lut16)
+    LabLegacy,
+    /// Lab de la version 4 (lut8, lutAtoB, lutBtoA) / Version 4 Lab (lut8, lutAtoB, lutBtoA)
+    Lab,
+    /// XYZ D50 (u1Fixed15)
+    Xyz,
+}
+
+/// Courbe de tonalité d'une étape de table / Tone curve of a table stage
+#[derive(Clone, Debug)]
+enum Curve {
+    /// Table échantillonnée (0.0 - 1.0), vide pour l'identité
+    /// Sampled table (0.0 - 1.0), empty for identity
+    Table(Vec<f64>),
+    /// Fonction paramétrique ICC (types 0 à 4) / ICC parametric function (types 0 to 4)
+    Parametric(u16, [f64; 7]),
+}
+
+/// Table de correspondance multidimensionnelle / Multidimensional lookup table
+#[derive(Clone, Debug)]
+struct Clut {
+    /// Nombre de points de grille par entrée / Number of grid points per input
+    grid: Vec<usize>,
+    outputs: usize,
+    /// Valeurs normalisées, la première entrée variant le plus lentement
+    /// Normalized values, the first input varying the slowest
+    data: Vec<f64>,
+}
+
+/// Étape d'une table ICC / ICC table stage
+#[derive(Clone, Debug)]
+enum Stage {
+    Curves(Vec<Curve>),
+    Matrix([[f64; 3]; 3], [f64; 3]),
+    Clut(Clut),
+}
+
+/// Transformation d'une table A2B ou B2A / Transform of an A2B or B2A table
+#[derive(Clone, Debug)]
+struct Lut {
+    stages: Vec<Stage>,
+    encoding: PcsEncoding,
+}
+
+/// Profil de sortie CMYK chargé / Loaded CMYK output profile
+#[derive(Clone, Debug)]
+pub struct OutputProfile {
+    /// Description du profil, sinon nom du fichier / Profile description, otherwise file name
+    pub name: String,
+
+    /// PCS vers CMYK / PCS to CMYK
+    to_device: Lut,
+
+    /// CMYK vers PCS / CMYK to PCS
+    to_pcs: Lut,
+
+    /// Blanc du support (papier) en XYZ D50 / Media (paper) white in XYZ D50
+    media_white: [f64; 3],
+}
+
+impl Curve {
+    fn eval(&self, x: f64) -> f64 {
+        let x = x.clamp(0.0, 1.0);
+        let y = match self {
+            Curve::Table(table) if table.len() < 2 => x,
+            Curve::Table(table) => {
+                let position = x * (table.len() - 1) as f64;
+                let low = (position.floor() as usize).min(table.len() - 2);
+                let t = position - low as f64;
+                table[low] * (1.0 - t) + table[low + 1] * t
+            }
+            Curve::Parametric(kind, p) => {
+                let [g, a, b, c, d, e, f] = *p;
+                match kind {
+                    0 => x.powf(g),
+                    1 if x >= -b / a => (a * x + b).powf(g),
+                    1 => 0.0,
+                    2 if x >= -b / a => (a * x + b).powf(g) + c,
+                    2 => c,
+                    3 if x >= d => (a * x + b).powf(g),
+                    3 => c * x,
+                    _ if x >= d => (a * x + b).powf(g) + e,
+                    _ => c * x + f,
+                }
+            }
+        };
+        y.clamp(0.0, 1.0)
+    }
+}
+
+impl Clut {
+    /// Interpolation multilinéaire / Multilinear interpolation
+    fn eval(&self, input: &[f64]) -> Vec<f64> {
+        let dims = self.grid.len();
+
+        // Indice inférieur et poids de chaque entrée / Lower index and weight of each input
+        let cells: Vec<(usize, f64)> = self
+            .grid
+            .iter()
+            .zip(input)
+            .map(|(&points, &x)| {
+                if points < 2 {
+                    return (0, 0.0);
+                }
+                let position = x.clamp(0.0, 1.0) * (points - 1) as f64;
+                let low = (position.floor() as usize).min(points - 2);
+                (low, position - low as f64)
+            })
+            .collect();
+
+        let mut output = vec![0.0; self.outputs];
+        for corner in 0..(1usize << dims) {
+            let mut index = 0;
+            let mut weight = 1.0;
+            for (i, (&(low, t), &points)) in cells.iter().zip(&self.grid).enumerate() {
+                let high = (corner >> (dims - 1 - i)) & 1 == 1;
+                index = index * points + (low + usize::from(high && points > 1));
+                weight *= if high { t } else { 1.0 - t };
+            }
+            if weight == 0.0 {
+                continue;
+            }
+            let values = &self.data[index * self.outputs..(index + 1) * self.outputs];
+            for (out, value) in output.iter_mut().zip(values) {
+                *out += weight * value;
+            }
+        }
+        output
+    }
+}
+
+impl Lut {
+    fn eval(&self, input: &[f64]) -> Vec<f64> {
+        self.stages.iter().fold(input.to_vec(), |values, stage| match stage {
+            Stage::Curves(curves) => values.iter().zip(curves).map(|(&v, curve)| curve.eval(v)).collect(),
+            Stage::Matrix(m, offset) => (0..3)
+                .map(|row| m[row][0] * values[0] + m[row][1] * values[1] + m[row][2] * values[2] + offset[row])
+                .collect(),
+            Stage::Clut(clut) => clut.eval(&values),
+        })
+    }
+
+    /// Encode une couleur XYZ D50 en valeurs PCS normalisées / Encodes an XYZ D50 color as normalized PCS values
+    fn encode(&self, xyz_d50: [f64; 3]) -> Vec<f64> {
+        match self.encoding {
+            PcsEncoding::Xyz => xyz_d50.iter().map(|v| v * 32768.0 / 65535.0).collect(),
+            encoding => {
+                let [l, a, b] = conversion::xyz_to_lab(conversion::xyz_d50_to_d65(xyz_d50));
+                if encoding == PcsEncoding::LabLegacy {
+                    vec![l / 100.0 * 65280.0 / 65535.0, (a + 128.0) * 256.0 / 65535.0, (b + 128.0) * 256.0 / 65535.0]
+                } else {
+                    vec![l / 100.0, (a + 128.0) / 255.0, (b + 128.0) / 255.0]
+                }
+            }
+        }
+    }
+
+    /// Décode des valeurs PCS normalisées en XYZ D50 / Decodes normalized PCS values to XYZ D50
+    fn decode(&self, pcs: &[f64]) -> [f64; 3] {
+        let lab = match self.encoding {
+            PcsEncoding::Xyz => return [0, 1, 2].map(|i| pcs[i] * 65535.0 / 32768.0),
+            PcsEncoding::LabLegacy => [
+                pcs[0] * 65535.0 / 65280.0 * 100.0,
+                pcs[1] * 65535.0 / 256.0 - 128.0,
+                pcs[2] * 65535.0 / 256.0 - 128.0,
+            ],
+            PcsEncoding::Lab => [pcs[0] * 100.0, pcs[1] * 255.0 - 128.0, pcs[2] * 255.0 - 128.0],
+        };
+        conversion::xyz_d65_to_d50(conversion::lab_to_xyz(lab))
+    }
+}
+
+impl OutputProfile {
+    /// Sépare une couleur sRGB étendue en CMYK (0.0 - 1.0) / Separates an extended sRGB color into CMYK (0.0 - 1.0)
+    pub fn to_cmyk(&self, srgb: [f64; 3]) -> [f64; 4] {
+        let xyz = conversion::xyz_d65_to_d50(conversion::srgb_to_xyz(srgb));
+        let device = self.to_device.eval(&self.to_device.encode(xyz));
+        [0, 1, 2, 3].map(|i| device[i].clamp(0.0, 1.0))
+    }
+
+    /// Couleur sRGB étendue d'une encre CMYK imprimée / Extended sRGB color of a printed CMYK ink
+    ///
+    /// Avec `simulate_paper`, le blanc de l'écran devient celui du papier
+    /// (intention colorimétrique absolue).
+    /// With `simulate_paper`, the screen white becomes the paper white
+    /// (absolute colorimetric intent).
+    pub fn cmyk_to_srgb(&self, cmyk: [f64; 4], simulate_paper: bool) -> [f64; 3] {
+        let mut xyz = self.to_pcs.decode(&self.to_pcs.eval(&cmyk));
+        if simulate_paper {
+            xyz = [0, 1, 2].map(|i| xyz[i] * self.media_white[i] / ICC_D50[i]);
+        }
+        conversion::xyz_to_srgb(conversion::xyz_d50_to_d65(xyz))
+    }
+}
+
+/// Lecture bornée d'un entier gros-boutiste / Bounded big-endian integer read
+fn read_be(data: &[u8], at: usize, len: usize) -> Result<u32, IccError> {
+    data.get(at..at + len)
+        .map(|bytes| bytes.iter().fold(0u32, |acc, &b| (acc << 8) | u32::from(b)))
+        .ok_or_else(|| IccError::Invalid(format!("truncated at byte {}", at)))
+}
+
+/// Nombre s15Fixed16 / s15Fixed16 number
+fn read_s15_fixed16(data: &[u8], at: usize) -> Result<f64, IccError> {
+    Ok(read_be(data, at, 4)? as i32 as f64 / 65536.0)
+}
+
+/// Signature de quatre caractères / Four-character signature
+fn read_signature(data: &[u8], at: usize) -> Result<[u8; 4], IccError> {
+    data.get(at..at + 4)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| IccError::Invalid(format!("truncated at byte {}", at)))
+}
+
+/// Valeurs normalisées de `count` entiers de `width` octets / Normalized values of `count` integers of `width` bytes
+fn read_normalized(data: &[u8], at: usize, count: usize, width: usize) -> Result<Vec<f64>, IccError> {
+    let max = if width == 1 { 255.0 } else { 65535.0 };
+    (0..count).map(|i| Ok(read_be(data, at + i * width, width)? as f64 / max)).collect()
+}
+
+/// Lit une courbe `curv` ou `para` et sa taille alignée sur 4 octets
+/// Reads a `curv` or `para` curve and its size aligned on 4 bytes
+fn read_curve(data: &[u8], at: usize) -> Result<(Curve, usize), IccError> {
+    let (curve, size) = match &read_signature(data, at)? {
+        b"curv" => {
+            let count = read_be(data, at + 8, 4)? as usize;
+            let curve = match count {
+                0 => Curve::Table(Vec::new()),
+                1 => Curve::Parametric(0, [read_be(data, at + 12, 2)? as f64 / 256.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]),
+                _ => Curve::Table(read_normalized(data, at + 12, count, 2)?),
+            };
+            (curve, 12 + 2 * count)
+        }
+        b"para" => {
+            let kind = read_be(data, at + 8, 2)? as u16;
+            let count = match kind {
+                0 => 1,
+                1 => 3,
+                2 => 4,
+                3 => 5,
+                4 => 7,
+                _ => return Err(IccError::Unsupported(format!("parametric curve type {}", kind))),
+            };
+            let mut params = [0.0; 7];
+            for (i, param) in params.iter_mut().enumerate().take(count) {
+                *param = read_s15_fixed16(data, at + 12 + 4 * i)?;
+            }
+            (Curve::Parametric(kind, params), 12 + 4 * count)
+        }
+        other => return Err(IccError::Unsupported(format!("curve type {}", String::from_utf8_lossy(other)))),
+    };
+    Ok((curve, size.div_ceil(4) * 4))
+}
+
+/// Lit `count` courbes consécutives / Reads `count` consecutive curves
+fn read_curves(data: &[u8], mut at: usize, count: usize) -> Result<Vec<Curve>, IccError> {
+    let mut curves = Vec::with_capacity(count);
+    for _ in 0..count {
+        let (curve, size) = read_curve(data, at)?;
+        curves.push(curve);
+        at += size;
+    }
+    Ok(curves)
+}
+
+/// Nombre de valeurs d'une CLUT, vérifié contre la taille de la balise
+/// Value count of a CLUT, checked against the tag size
+///
+/// Chaque dimension doit compter au moins deux points, sans quoi la CLUT ne
+/// peut pas être interpolée.
+/// Each dimension must have at least two points, otherwise the CLUT cannot
+/// be interpolated.
+fn clut_size(tag: &[u8], at: usize, grid: &[usize], outputs: usize, width: usize) -> Result<usize, IccError> {
+    if let Some(points) = grid.iter().find(|&&points| points < 2) {
+        return Err(IccError::Invalid(format!("CLUT grid of {} points", points)));
+    }
+    let size = grid.iter()
+        .try_fold(outputs, |size, &points| size.checked_mul(points))
+        .ok_or_else(|| IccError::Invalid("CLUT size overflow".to_string()))?;
+    match size.checked_mul(width).and_then(|bytes| bytes.checked_add(at)) {
+        Some(end) if end <= tag.len() => Ok(size),
+        _ => Err(IccError::Invalid(format!("CLUT of {} values past the end of the tag", size))),
+    }
+}
+
+/// Lit une table lut8 (`mft1`) ou lut16 (`mft2`) / Reads a lut8 (`mft1`) or lut16 (`mft2`) table
+fn read_mft(tag: &[u8], width: usize, pcs_input: bool, encoding: PcsEncoding) -> Result<Lut, IccError> {
+    let inputs = read_be(tag, 8, 1)? as usize;
+    let outputs = read_be(tag, 9, 1)? as usize;
+    let points = read_be(tag, 10, 1)? as usize;
+    let (input_entries, output_entries, mut at) = if width == 1 {
+        (256, 256, 48)
+    } else {
+        (read_be(tag, 48, 2)? as usize, read_be(tag, 50, 2)? as usize, 52)
+    };
+
+    let mut stages = Vec::new();
+
+    // La matrice ne s'applique qu'à une entrée XYZ / The matrix only applies to an XYZ input
+    if pcs_input && encoding == PcsEncoding::Xyz {
+        let mut m = [[0.0; 3]; 3];
+        for (i, value) in m.iter_mut().flatten().enumerate() {
+            *value = read_s15_fixed16(tag, 12 + 4 * i)?;
+        }
+        stages.push(Stage::Matrix(m, [0.0; 3]));
+    }
+
+    let tables = |count: usize, entries: usize, at: &mut usize| -> Result<Vec<Curve>, IccError> {
+        let curves = (0..count)
+            .map(|i| Ok(Curve::Table(read_normalized(tag, *at + i * entries * width, entries, width)?)))
+            .collect::<Result<Vec<_>, IccError>>()?;
+        *at += count * entries * width;
+        Ok(curves)
+    };
+
+    stages.push(Stage::Curves(tables(inputs, input_entries, &mut at)?));
+    let size = clut_size(tag, at, &vec![points; inputs], outputs, width)?;
+    let data = read_normalized(tag, at, size, width)?;
+    at += size * width;
+    stages.push(Stage::Clut(Clut { grid: vec![points; inputs], outputs, data }));
+    stages.push(Stage::Curves(tables(outputs, output_entries, &mut at)?));
+
+    Ok(Lut { stages, encoding })
+}
+
+/// Lit une table lutAtoB (`mAB `) ou lutBtoA (`mBA `) / Reads a lutAtoB (`mAB `) or lutBtoA (`mBA `) table
+fn read_mab(tag: &[u8], a_to_b: bool, encoding: PcsEncoding) -> Result<Lut, IccError> {
+    let inputs = read_be(tag, 8, 1)? as usize;
+    let outputs = read_be(tag, 9, 1)? as usize;
+    let offset = |at: usize| -> Result<Option<usize>, IccError> {
+        Ok(Some(read_be(tag, at, 4)? as usize).filter(|&o| o != 0))
+    };
+    let (b, matrix, m, clut, a) = (offset(12)?, offset(16)?, offset(20)?, offset(24)?, offset(28)?);
+
+    // Courbes B et M du côté PCS, courbes A du côté appareil
+    // B and M curves on the PCS side, A curves on the device side
+    let pcs_channels = if a_to_b { outputs } else { inputs };
+    let device_channels = if a_to_b { inputs } else { outputs };
+
+    let matrix_stage = |at: usize| -> Result<Stage, IccError> {
+        let mut m = [[0.0; 3]; 3];
+        for (i, value) in m.iter_mut().flatten().enumerate() {
+            *value = read_s15_fixed16(tag, at + 4 * i)?;
+        }
+        let mut offset = [0.0; 3];
+        for (i, value) in offset.iter_mut().enumerate() {
+            *value = read_s15_fixed16(tag, at + 36 + 4 * i)?;
+        }
+        Ok(Stage::Matrix(m, offset))
+    };
+    let clut_stage = |at: usize| -> Result<Stage, IccError> {
+        let grid = (0..inputs).map(|i| read_be(tag, at + i, 1).map(|v| v as usize)).collect::<Result<Vec<_>, _>>()?;
+        let width = read_be(tag, at + 16, 1)? as usize;
+        if width != 1 && width != 2 {
+            return Err(IccError::Invalid(format!("CLUT precision {}", width)));
+        }
+        let size = clut_size(tag, at + 20, &grid, outputs, width)?;
+        Ok(Stage::Clut(Clut { grid, outputs, data: read_normalized(tag, at + 20, size, width)? }))
+    };
+
+    let mut stages = Vec::new();
+    let push_curves = |at: Option<usize>, count: usize, stages: &mut Vec<Stage>| -> Result<(), IccError> {
+        if let Some(at) = at {
+            stages.push(Stage::Curves(read_curves(tag, at, count)?));
+        }
+        Ok(())
+    };
+
+    if a_to_b {
+        push_curves(a, device_channels, &mut stages)?;
+        if let Some(at) = clut {
+            stages.push(clut_stage(at)?);
+        }
+        push_curves(m, pcs_channels, &mut stages)?;
+        if let Some(at) = matrix {
+            stages.push(matrix_stage(at)?);
+        }
+        push_curves(b, pcs_channels, &mut stages)?;
+    } else {
+        push_curves(b, pcs_channels, &mut stages)?;
+        if let Some(at) = matrix {
+            stages.push(matrix_stage(at)?);
+        }
+        push_curves(m, pcs_channels, &mut stages)?;
+        if let Some(at) = clut {
+            stages.push(clut_stage(at)?);
+        }
+        push_curves(a, device_channels, &mut stages)?;
+    }
+
+    Ok(Lut { stages, encoding })
+}
+
+/// Lit une table A2B (`a_to_b`) ou B2A / Reads an A2B (`a_to_b`) or B2A table
+fn read_lut(tag: &[u8], a_to_b: bool, pcs_is_lab: bool) -> Result<Lut, IccError> {
+    let lab_or = |lab: PcsEncoding| if pcs_is_lab { lab } else { PcsEncoding::Xyz };
+
+    // CMYK côté appareil, trois canaux côté PCS, vérifiés avant de dimensionner les tables
+    // CMYK on the device side, three channels on the PCS side, checked before sizing the tables
+    let (inputs, outputs) = (read_be(tag, 8, 1)?, read_be(tag, 9, 1)?);
+    if (a_to_b && (inputs, outputs) != (4, 3)) || (!a_to_b && (inputs, outputs) != (3, 4)) {
+        return Err(IccError::Invalid(format!("{} inputs, {} outputs", inputs, outputs)));
+    }
+
+    match &read_signature(tag, 0)? {
+        b"mft1" => read_mft(tag, 1, !a_to_b, lab_or(PcsEncoding::Lab)),
+        b"mft2" => read_mft(tag, 2, !a_to_b, lab_or(PcsEncoding::LabLegacy)),
+        b"mAB " | b"mBA " => read_mab(tag, a_to_b, lab_or(PcsEncoding::Lab)),
+        other => Err(IccError::Unsupported(format!("table type {}", String::from_utf8_lossy(other)))),
+    }
+}
+
+/// Description d'un profil (`desc` ou `mluc`) / Profile description (`desc` or `mluc`)
+fn read_description(tag: &[u8]) -> Option<String> {
+    let text = match &read_signature(tag, 0).ok()? {
+        b"desc" => {
+            let count = read_be(tag, 8, 4).ok()? as usize;
+            String::from_utf8_lossy(tag.get(12..12 + count)?).to_string()
+        }
+        b"mluc" => {
+            let length = read_be(tag, 20, 4).ok()? as usize;
+            let offset = read_be(tag, 24, 4).ok()? as usize;
+            let units: Vec<u16> = tag.get(offset..offset + length)?.chunks_exact(2).map(|c| u16::from_be_bytes([c[0], c[1]])).collect();
+            String::from_utf16_lossy(&units)
+        }
+        _ => return None,
+    };
+    let text = text.trim_end_matches('\0').trim().to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+/// Balise d'un profil : signature et contenu / Profile tag: signature and content
+type Tag<'a> = ([u8; 4], &'a [u8]);
+
+/// Lit la table des balises d'un profil / Reads the tag table of a profile
+fn read_tags(data: &[u8]) -> Result<Vec<Tag<'_>>, IccError> {
+    let count = read_be(data, 128, 4)? as usize;
+    let mut tags = Vec::with_capacity(count.min(data.len() / 12));
+    for i in 0..count {
+        let at = 132 + 12 * i;
+        let (offset, size) = (read_be(data, at + 4, 4)? as usize, read_be(data, at + 8, 4)? as usize);
+        let tag = offset
+            .checked_add(size)
+            .and_then(|end| data.get(offset..end))
+            .ok_or_else(|| IccError::Invalid(format!("tag {} out of bounds", i)))?;
+        tags.push((read_signature(data, at)?, tag));
+    }
+    Ok(tags)
+}
+
+/// Identifie un profil RGB d'affichage Display P3 ou Rec. 2020 d'après son contenu
+/// Identifies a Display P3 or Rec. 2020 RGB display profile from its content
+///
+/// Les primaires (`rXYZ`, `gXYZ`, `bXYZ`, adaptées à D50) et la courbe de tonalité
+/// (`rTRC`) sont comparées à celles de chaque espace, quel que soit le nom du profil :
+/// DCI-P3, dont le blanc et le gamma diffèrent, n'est pas reconnu comme Display P3.
+/// The primaries (`rXYZ`, `gXYZ`, `bXYZ`, adapted to D50) and the tone curve
+/// (`rTRC`) are compared with those of each space, whatever the profile name:
+/// DCI-P3, whose white and gamma differ, is not recognized as Display P3.
+///
+/// # Arguments
+/// * `data` - Contenu du profil ICC / ICC profile content
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+pub fn identify_rgb_space(data: &[u8]) -> Option<RgbSpace> {
+    if read_signature(data, 36).ok()? != *b"acsp" || read_signature(data, 16).ok()? != *b"RGB " {
+        return None;
+    }
+    let tags = read_tags(data).ok()?;
+    let find = |signature: &[u8; 4]| tags.iter().find(|(s, _)| s == signature).map(|(_, tag)| *tag);
+    let xyz = |signature: &[u8; 4]| -> Option<[f64; 3]> {
+        let tag = find(signature)?;
+        Some([read_s15_fixed16(tag, 8).ok()?, read_s15_fixed16(tag, 12).ok()?, read_s15_fixed16(tag, 16).ok()?])
+    };
+    let colorants = [xyz(b"rXYZ")?, xyz(b"gXYZ")?, xyz(b"bXYZ")?];
+    let (curve, _) = read_curve(find(b"rTRC")?, 0).ok()?;
+
+    [RgbSpace::DisplayP3, RgbSpace::Rec2020].into_iter().find(|space| {
+        let primaries_match = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]
+            .into_iter()
+            .zip(colorants)
+            .all(|(unit, colorant)| {
+                let expected = conversion::xyz_d65_to_d50(space.to_xyz(unit));
+                expected.iter().zip(colorant).all(|(e, c)| (e - c).abs() < 0.005)
+            });
+        // Luminance relative d'un gris moyen / Relative luminance of a mid grey
+        let tone_matches = [0.25, 0.5, 0.75]
+            .into_iter()
+            .all(|x| (curve.eval(x) - space.to_xyz([x, x, x])[1]).abs() < 0.01);
+        primaries_match && tone_matches
+    })
+}
+
+/// Analyse un profil de sortie CMYK / Parses a CMYK output profile
+///
+/// # Arguments
+/// * `data` - Contenu du fichier ICC / ICC file content
+/// * `default_name` - Nom utilisé sans description / Name used without a description
+pub fn parse_output_profile(data: &[u8], default_name: &str) -> Result<OutputProfile, IccError> {
+    if read_signature(data, 36)? != *b"acsp" {
+        return Err(IccError::Invalid("missing acsp signature".to_string()));
+    }
+    let space = read_signature(data, 16)?;
+    if space != *b"CMYK" {
+        return Err(IccError::Unsupported(format!("colour space {}", String::from_utf8_lossy(&space).trim())));
+    }
+    let pcs_is_lab = read_signature(data, 20)? == *b"Lab ";
+
+    let tags = read_tags(data)?;
+    let find = |signatures: &[&[u8; 4]]| {
+        signatures.iter().find_map(|sig| tags.iter().find(|(s, _)| s == *sig).map(|(_, tag)| *tag))
+    };
+
+    // Colorimétrique relative, sinon perceptuelle / Relative colorimetric, otherwise perceptual
+    let a2b = find(&[b"A2B1", b"A2B0"]).ok_or_else(|| IccError::Invalid("missing A2B table".to_string()))?;
+    let b2a = find(&[b"B2A1", b"B2A0"]).ok_or_else(|| IccError::Invalid("missing B2A table".to_string()))?;
+
+    let media_white = match find(&[b"wtpt"]) {
+        Some(tag) => [read_s15_fixed16(tag, 8)?, read_s15_fixed16(tag, 12)?, read_s15_fixed16(tag, 16)?],
+        None => ICC_D50,
+    };
+
+    Ok(OutputProfile {
+        name: find(&[b"desc"]).and_then(read_description).unwrap_or_else(|| default_name.to_string()),
+        to_device: read_lut(b2a, false, pcs_is_lab)?,
+        to_pcs: read_lut(a2b, true, pcs_is_lab)?,
+        media_white,
+    })
+}
+
 // =============================================================================
 // COMMANDES TAURI
 // TAURI COMMANDS
@@ -421,6 +1003,30 @@ pub fn get_selected_icc_profile() -> Option<String> {
     SELECTED_PROFILE.lock().ok().and_then(|s| s.clone())
 }
 
+/// Charge un profil de sortie CMYK (FOGRA39, GRACoL…) pour l'épreuvage écran
+/// Loads a CMYK output profile (FOGRA39, GRACoL…) for soft proofing
+///
+/// # Arguments
+/// * `path` - Chemin du fichier .icc / .icm / Path of the .icc / .icm file
+///
+/// # Returns
+/// Le nom du profil chargé / The name of the loaded profile
+#[tauri::command]
+pub fn load_output_profile(state: tauri::State<AppState>, path: String) -> Result<String, IccError> {
+    let data = std::fs::read(&path).map_err(|e| IccError::Io(format!("{}: {}", path, e)))?;
+    let default_name = std::path::Path::new(&path).file_stem().and_then(|s| s.to_str()).unwrap_or("CMYK");
+    let profile = parse_output_profile(&data, default_name)?;
+    let name = profile.name.clone();
+    *state.output_profile.lock().unwrap() = Some(profile);
+    Ok(name)
+}
+
+/// Retire le profil de sortie / Removes the output profile
+#[tauri::command]
+pub fn clear_output_profile(state: tauri::State<AppState>) {
+    *state.output_profile.lock().unwrap() = None;
+}
+
 // =============================================================================
 // FONCTIONS UTILITAIRES
 // UTILITY FUNCTIONS
@@ -446,3 +1052,92 @@ pub fn get_current_profile_name() -> String {
         "Auto".to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Nombre s15Fixed16 gros-boutiste / Big-endian s15Fixed16 number
+    fn fixed(value: f64) -> [u8; 4] {
+        ((value * 65536.0).round() as i32).to_be_bytes()
+    }
+
+    /// Profil RGB minimal : primaires de `space` et courbe paramétrique `trc`
+    /// Minimal RGB profile: primaries of `space` and parametric curve `trc`
+    fn rgb_profile(space: RgbSpace, trc: &[f64]) -> Vec<u8> {
+        let mut tags: Vec<([u8; 4], Vec<u8>)> = Vec::new();
+        for (signature, unit) in [(b"rXYZ", [1.0, 0.0, 0.0]), (b"gXYZ", [0.0, 1.0, 0.0]), (b"bXYZ", [0.0, 0.0, 1.0])] {
+            let mut tag = b"XYZ \0\0\0\0".to_vec();
+            conversion::xyz_d65_to_d50(space.to_xyz(unit)).iter().for_each(|v| tag.extend(fixed(*v)));
+            tags.push((*signature, tag));
+        }
+        let mut curve = b"para\0\0\0\0".to_vec();
+        curve.extend(if trc.len() == 1 { [0u8, 0, 0, 0] } else { [0u8, 3, 0, 0] });
+        trc.iter().for_each(|v| curve.extend(fixed(*v)));
+        tags.push((*b"rTRC", curve));
+
+        let mut data = vec![0u8; 128];
+        data[16..20].copy_from_slice(b"RGB ");
+        data[36..40].copy_from_slice(b"acsp");
+        data.extend((tags.len() as u32).to_be_bytes());
+        let mut offset = 132 + 12 * tags.len();
+        let mut body: Vec<u8> = Vec::new();
+        for (signature, tag) in &tags {
+            data.extend(signature);
+            data.extend((offset as u32).to_be_bytes());
+            data.extend((tag.len() as u32).to_be_bytes());
+            offset += tag.len();
+            body.extend(tag);
+        }
+        data.extend(body);
+        data
+    }
+
+    /// Courbe sRGB (paramétrique de type 3) / sRGB curve (type 3 parametric)
+    const SRGB_TRC: [f64; 5] = [2.4, 1.0 / 1.055, 0.055 / 1.055, 1.0 / 12.92, 0.04045];
+
+    #[test]
+    fn test_identify_rgb_space() {
+        assert_eq!(identify_rgb_space(&rgb_profile(RgbSpace::DisplayP3, &SRGB_TRC)), Some(RgbSpace::DisplayP3));
+        // Primaires P3 avec le gamma 2,6 du cinéma : pas Display P3
+        // P3 primaries with the cinema 2.6 gamma: not Display P3
+        assert_eq!(identify_rgb_space(&rgb_profile(RgbSpace::DisplayP3, &[2.6])), None);
+        assert_eq!(identify_rgb_space(&rgb_profile(RgbSpace::Srgb, &SRGB_TRC)), None);
+    }
+
+    #[test]
+    fn test_oversized_clut() {
+        // lut8 CMYK vers PCS annonçant une grille de 255 points par canal
+        // CMYK to PCS lut8 announcing a 255-point grid per channel
+        let mut mft = b"mft1\0\0\0\0".to_vec();
+        mft.extend([4, 3, 255, 0]);
+        mft.resize(48 + 4 * 256, 0);
+        assert!(matches!(read_lut(&mft, true, true), Err(IccError::Invalid(_))));
+        // Une grille sans point ne donne pas une CLUT vide / A grid without points does not give an empty CLUT
+        mft[10] = 0;
+        mft.resize(48 + 7 * 256, 0);
+        assert!(matches!(read_lut(&mft, true, true), Err(IccError::Invalid(_))));
+
+        // lutAtoB dont la grille dépasse de loin la balise / lutAtoB whose grid far exceeds the tag
+        let mut mab = b"mAB \0\0\0\0".to_vec();
+        mab.extend([4, 3, 0, 0]);
+        mab.extend([0u8; 12]);
+        mab.extend(32u32.to_be_bytes());
+        mab.extend([0u8; 4]);
+        mab.extend([255u8; 16]);
+        mab.extend([1, 0, 0, 0]);
+        assert!(matches!(read_lut(&mab, true, true), Err(IccError::Invalid(_))));
+        // Un seul point par dimension / A single point per dimension
+        mab[32..36].copy_from_slice(&[1; 4]);
+        mab.resize(52 + 3, 0);
+        assert!(matches!(read_lut(&mab, true, true), Err(IccError::Invalid(_))));
+        mab.truncate(52);
+        // Une grille 2×2×2×2 tronquée est refusée sans panique
+        // A truncated 2×2×2×2 grid is rejected without panicking
+        mab[32..36].copy_from_slice(&[2; 4]);
+        assert!(matches!(read_lut(&mab, true, true), Err(IccError::Invalid(_))));
+        // Une entrée RGB n'est pas une table CMYK / An RGB input is not a CMYK table
+        mab[8] = 3;
+        assert!(matches!(read_lut(&mab, true, true), Err(IccError::Invalid(_))));
+    }
+}
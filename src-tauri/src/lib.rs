@@ -30,14 +30,86 @@ mod store;
 /// Color manipulation functions
 mod color;
 
+/// Conversions entre modèles de couleur (HSL, Lab, OKLCH, CMJN…)
+/// Color model conversions (HSL, Lab, OKLCH, CMYK…)
+mod conversion;
+
+/// Contraste APCA (brouillon WCAG 3)
+/// APCA contrast (WCAG 3 draft)
+mod apca;
+
+/// Tailles de police minimales APCA
+/// APCA minimum font sizes
+mod apca_fonts;
+
+/// Évaluation de la conformité WCAG
+/// WCAG compliance evaluation
+mod compliance;
+
+/// Profils de normes d'accessibilité (WCAG, EN 301 549, Section 508, BITV…)
+/// Accessibility standards profiles (WCAG, EN 301 549, Section 508, BITV…)
+mod profiles;
+
+/// Taille et graisse du texte testé (grand texte ou texte normal)
+/// Size and weight of the tested text (large or normal text)
+mod text_spec;
+
 /// Noms de couleurs CSS (W3C CSS Color Module Level 4)
 /// CSS named colors (W3C CSS Color Module Level 4)
 mod color_names;
 
+/// Analyseur de couleurs CSS Color Level 4 (saisie libre)
+/// CSS Color Level 4 color parser (free text entry)
+mod css_color;
+
+/// Dictionnaires de noms de couleur (CSS, X11, xkcd, RAL, JSON)
+/// Colour name dictionaries (CSS, X11, xkcd, RAL, JSON)
+mod dictionaries;
+
+/// Simulation des déficiences de la vision des couleurs
+/// Colour vision deficiency simulation
+mod cvd;
+
+/// Suggestions de correction du contraste
+/// Contrast repair suggestions
+mod fixes;
+
+/// Matrice de contraste des états d'un composant
+/// Component state contrast matrix
+mod states;
+
+/// Un premier plan sur plusieurs arrière-plans
+/// One foreground over several backgrounds
+mod surfaces;
+
+/// Contraste du texte sur une zone d'image
+/// Contrast of text over an image region
+mod region;
+
+/// Opacité minimale d'un voile sous le texte posé sur une image
+/// Minimum opacity of an overlay under text over an image
+mod scrim;
+
+/// Contraste le long d'un dégradé CSS
+/// Contrast along a CSS gradient
+mod gradient;
+
+/// Équivalent en thème sombre de la paire
+/// Dark theme counterpart of the pair
+mod dark_mode;
+
+/// Palette de jetons du design system
+/// Design system token palette
+mod tokens;
+
 /// Gestion des profils ICC
 /// ICC profile management
 mod icc;
 
+/// Épreuvage écran CMYK de la paire
+/// CMYK soft proofing of the pair
+mod proof;
+
 /// Internationalisation des menus
 /// Menu internationalization
 mod i18n;
@@ -361,6 +433,12 @@ pub fn run() {
             store: Mutex::new(store::ResultStore::default()),
             locale: Mutex::new("en".to_string()),
             templates: Mutex::new(Vec::new()),
+            profiles: Mutex::new(Vec::new()),
+            component_states: Mutex::new(Vec::new()),
+            tokens: Mutex::new(Vec::new()),
+            dictionaries: Mutex::new(dictionaries::built_in_dictionaries()),
+            output_profile: Mutex::new(None),
+            region_image: Mutex::new(None),
         })
         // Configure le menu de l'application
         // Configure the application menu
@@ -527,9 +605,43 @@ pub fn run() {
             store::update_store,
             store::clear_store,
             store::get_color_name,
+            dictionaries::list_color_dictionaries,
+            dictionaries::load_color_dictionary,
+            dictionaries::remove_color_dictionary,
+            dictionaries::nearest_color_names,
+            store::set_color_from_string,
+            store::set_text_spec,
+            store::clear_adjacent_text,
+            store::clear_reference_color,
+            store::get_color_difference,
+            apca_fonts::get_apca_font_sizes,
+            cvd::simulate_cvd,
+            fixes::suggest_fixes,
+            profiles::list_standards_profiles,
+            profiles::load_standards_profile,
+            profiles::select_standards_profile,
+            states::set_component_state,
+            states::remove_component_state,
+            states::get_state_matrix,
+            states::export_state_matrix,
+            surfaces::remove_surface,
+            surfaces::clear_surfaces,
+            region::load_region_image,
+            region::analyze_region,
+            region::detect_colors,
+            scrim::scrim_opacity,
+            gradient::gradient_contrast,
+            dark_mode::derive_dark_mode,
+            dark_mode::verify_dark_mode,
+            tokens::load_token_palette,
+            tokens::get_token_palette,
+            tokens::suggest_tokens,
             icc::list_icc_profiles,
             icc::select_icc_profile,
             icc::get_selected_icc_profile,
+            icc::load_output_profile,
+            icc::clear_output_profile,
+            proof::soft_proof,
             set_locale,
             set_copy_templates,
         ])
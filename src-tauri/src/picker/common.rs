@@ -7,6 +7,7 @@
 //! This module contains types and functions used by both macOS and Windows.
 
 use serde::Serialize;
+use crate::conversion::PreciseColor;
 
 // =============================================================================
 // STRUCTURES DE RÉSULTAT
@@ -20,13 +21,13 @@ use serde::Serialize;
 /// Contains selected colors for foreground and background.
 #[derive(Serialize, Clone, Debug, Default)]
 pub struct ColorPickerResult {
-    /// Couleur de premier plan (foreground) - RGB
-    /// Foreground color - RGB
-    pub foreground: Option<(u8, u8, u8)>,
+    /// Couleur de premier plan (foreground), étiquetée avec son espace
+    /// Foreground color, tagged with its space
+    pub foreground: Option<PreciseColor>,
     
-    /// Couleur d'arrière-plan (background) - RGB
-    /// Background color - RGB
-    pub background: Option<(u8, u8, u8)>,
+    /// Couleur d'arrière-plan (background), étiquetée avec son espace
+    /// Background color, tagged with its space
+    pub background: Option<PreciseColor>,
     
     /// Indique si le mode continue était activé
     /// Indicates if continue mode was enabled
@@ -79,6 +79,10 @@ use std::sync::Mutex; // Mutex pour synchronisation thread-safe
 // Importe toutes les constantes du module config
 use crate::config::*;
 
+// Couleur exacte étiquetée avec son espace (sRGB, Display P3…)
+// Exact color tagged with its space (sRGB, Display P3…)
+use crate::conversion::PreciseColor;
+
 // -----------------------------------------------------------------------------
 // Code commun entre plateformes
 // Common code shared between platforms
@@ -364,13 +368,13 @@ define_class!(
                         // Stocke dans FG_COLOR
                         // Store in FG_COLOR
                         if let Ok(mut fg_color) = FG_COLOR.lock() {
-                            *fg_color = Some((info.r, info.g, info.b));
+                            *fg_color = Some(info.precise);
                         }
                     } else {
                         // Stocke dans BG_COLOR
                         // Store in BG_COLOR
                         if let Ok(mut bg_color) = BG_COLOR.lock() {
-                            *bg_color = Some((info.r, info.g, info.b));
+                            *bg_color = Some(info.precise);
                         }
                     }
                 }
@@ -437,7 +441,10 @@ define_class!(
 
                 // Capture la zone et extrait la couleur du pixel central
                 // Capture the area and extract the center pixel color
-                if let Some((_image, r, g, b)) = capture_and_get_center_color(screen_location.x, screen_location.y, capture_size, captured_pixels) {
+                if let Some((_image, precise)) = capture_and_get_center_color(screen_location.x, screen_location.y, capture_size, captured_pixels) {
+                    // Valeurs 8 bits pour l'affichage / 8-bit values for display
+                    let (r, g, b) = precise.to_rgb8();
+
                     // Format the color in hexadecimal (#RRGGBB)
                     // Utilise format_hex_color du module common
                     // Uses format_hex_color from common module
@@ -454,6 +461,7 @@ define_class!(
                             r,                       // Red component [0-255]
                             g,                       // Green component [0-255]
                             b,                       // Blue component [0-255]
+                            precise,                 // Exact color
                             hex_color: hex_color.clone(), // Hex code "#RRGGBB"
                             scale_factor,            // Retina scale factor
                         });
@@ -575,11 +583,11 @@ define_class!(
                         // Store the color in the appropriate variable based on fg_mode
                         if is_fg_mode {
                             if let Ok(mut fg_color) = FG_COLOR.lock() {
-                                *fg_color = Some((info.r, info.g, info.b));
+                                *fg_color = Some(info.precise);
                             }
                         } else {
                             if let Ok(mut bg_color) = BG_COLOR.lock() {
-                                *bg_color = Some((info.r, info.g, info.b));
+                                *bg_color = Some(info.precise);
                             }
                         }
                     }
@@ -724,7 +732,10 @@ define_class!(
 
                             // Capture la zone et extrait la couleur du pixel central
                             // Capture the area and extract the center pixel color
-                            if let Some((_image, r, g, b)) = capture_and_get_center_color(new_x, new_y, capture_size, captured_pixels) {
+                            if let Some((_image, precise)) = capture_and_get_center_color(new_x, new_y, capture_size, captured_pixels) {
+                                // Valeurs 8 bits pour l'affichage / 8-bit values for display
+                                let (r, g, b) = precise.to_rgb8();
+
                                 // Utilise format_hex_color du module common
                                 // Uses format_hex_color from common module
                                 let hex_color = format_hex_color(r, g, b);
@@ -745,6 +756,7 @@ define_class!(
                                             r,
                                             g,
                                             b,
+                                            precise,
                                             hex_color,
                                             scale_factor,
                                         });
@@ -844,11 +856,11 @@ const CAPTURED_PIXELS_STEP: f64 = 2.0;
 
 /// Stocke la couleur de premier plan sélectionnée (foreground)
 /// Stores the selected foreground color
-static FG_COLOR: Mutex<Option<(u8, u8, u8)>> = Mutex::new(None);
+static FG_COLOR: Mutex<Option<PreciseColor>> = Mutex::new(None);
 
 /// Stocke la couleur d'arrière-plan sélectionnée (background)
 /// Stores the selected background color
-static BG_COLOR: Mutex<Option<(u8, u8, u8)>> = Mutex::new(None);
+static BG_COLOR: Mutex<Option<PreciseColor>> = Mutex::new(None);
 
 /// Mode d'affichage: true = arc du haut (foreground), false = arc du bas (background)
 /// Display mode: true = top arc (foreground), false = bottom arc (background)
@@ -873,6 +885,7 @@ struct MouseColorInfo {
     r: u8,           // Composante rouge (0-255)
     g: u8,           // Composante verte (0-255)
     b: u8,           // Composante bleue (0-255)
+    precise: PreciseColor, // Couleur exacte, non écrêtée / Exact, unclipped color
     hex_color: String, // Code couleur hexadécimal (#RRGGBB)
     scale_factor: f64, // Facteur d'échelle de l'écran (2.0 pour Retina)
 }
@@ -1002,9 +1015,9 @@ fn capture_zoom_area(x: f64, y: f64, size: f64) -> Option<CGImage> {
 /// * `target_pixels` - Nombre de pixels cibles pour le calcul du centre
 ///
 /// # Retourne
-/// * `Some((r, g, b))` - Les composantes RGB en u8 [0-255] converties en sRGB
+/// * `Some(PreciseColor)` - La couleur exacte, étiquetée avec son espace
 /// * `None` - Si l'extraction a échoué
-fn get_center_pixel_from_image(image: &CGImage, target_pixels: f64) -> Option<(u8, u8, u8)> {
+fn get_center_pixel_from_image(image: &CGImage, target_pixels: f64) -> Option<PreciseColor> {
     // Récupère les dimensions de l'image
     // Get image dimensions
     let img_width = image.width() as f64;
@@ -1076,9 +1089,7 @@ fn get_center_pixel_from_image(image: &CGImage, target_pixels: f64) -> Option<(u
         
         // Applique la conversion ICC si un profil est sélectionné
         // Apply ICC conversion if a profile is selected
-        let (r_out, g_out, b_out) = apply_icc_conversion(r, g, b);
-        
-        Some((r_out, g_out, b_out))
+        Some(apply_icc_conversion(r, g, b))
     } else {
         None
     }
@@ -1091,8 +1102,8 @@ fn get_center_pixel_from_image(image: &CGImage, target_pixels: f64) -> Option<(u
 /// * `r`, `g`, `b` - Composantes RGB brutes capturées / Raw captured RGB components
 ///
 /// # Returns
-/// * `(u8, u8, u8)` - Composantes RGB converties en sRGB / RGB components converted to sRGB
-fn apply_icc_conversion(r: u8, g: u8, b: u8) -> (u8, u8, u8) {
+/// * `PreciseColor` - Couleur exacte, sans écrêtage / Exact color, without clipping
+fn apply_icc_conversion(r: u8, g: u8, b: u8) -> PreciseColor {
     // Import du module icc pour la conversion
     // Import icc module for conversion
     use crate::icc;
@@ -1115,16 +1126,16 @@ fn apply_icc_conversion(r: u8, g: u8, b: u8) -> (u8, u8, u8) {
 /// * `target_pixels` - Nombre de pixels cibles pour le crop (utilisé pour trouver le centre)
 ///
 /// # Retourne
-/// * `Some((CGImage, r, g, b))` - L'image et les composantes RGB du pixel central
+/// * `Some((CGImage, PreciseColor))` - L'image et la couleur exacte du pixel central
 /// * `None` - Si la capture a échoué
-fn capture_and_get_center_color(x: f64, y: f64, size: f64, target_pixels: f64) -> Option<(CGImage, u8, u8, u8)> {
+fn capture_and_get_center_color(x: f64, y: f64, size: f64, target_pixels: f64) -> Option<(CGImage, PreciseColor)> {
     // Capture la zone
     let image = capture_zoom_area(x, y, size)?;
     
     // Extrait la couleur du pixel central (en tenant compte du crop)
-    let (r, g, b) = get_center_pixel_from_image(&image, target_pixels)?;
+    let color = get_center_pixel_from_image(&image, target_pixels)?;
     
-    Some((image, r, g, b))
+    Some((image, color))
 }
 
 // =============================================================================
@@ -1377,7 +1388,10 @@ pub fn run(fg: bool) -> ColorPickerResult {
                 
                 // Capture la zone et extrait la couleur du pixel central
                 // Capture the area and extract the center pixel color
-                if let Some((_image, r, g, b)) = capture_and_get_center_color(cocoa_x, cocoa_y, capture_size, captured_pixels) {
+                if let Some((_image, precise)) = capture_and_get_center_color(cocoa_x, cocoa_y, capture_size, captured_pixels) {
+                    // Valeurs 8 bits pour l'affichage / 8-bit values for display
+                    let (r, g, b) = precise.to_rgb8();
+
                     // Utilise format_hex_color du module common
                     // Uses format_hex_color from common module
                     let hex_color = format_hex_color(r, g, b);
@@ -1393,6 +1407,7 @@ pub fn run(fg: bool) -> ColorPickerResult {
                             r,
                             g,
                             b,
+                            precise,
                             hex_color,
                             scale_factor,
                         });
@@ -1449,13 +1464,13 @@ pub fn run(fg: bool) -> ColorPickerResult {
     // Récupère les couleurs sélectionnées
     // Get the selected colors
     let fg_color = if let Ok(color) = FG_COLOR.lock() {
-        color.clone() // Clone the Option<(u8, u8, u8)>
+        *color // Copy the Option<PreciseColor>
     } else {
         None // Return None if lock fails
     };
 
     let bg_color = if let Ok(color) = BG_COLOR.lock() {
-        color.clone() // Clone the Option<(u8, u8, u8)>
+        *color // Copy the Option<PreciseColor>
     } else {
         None // Return None if lock fails
     };
@@ -1756,12 +1771,12 @@ fn draw_view(view: &NSView) {
                     // Récupère les couleurs déjà capturées
                     // Get already captured colors
                     let captured_fg = if let Ok(color) = FG_COLOR.lock() {
-                        *color
+                        color.map(PreciseColor::to_rgb8)
                     } else {
                         None
                     };
                     let captured_bg = if let Ok(color) = BG_COLOR.lock() {
-                        *color
+                        color.map(PreciseColor::to_rgb8)
                     } else {
                         None
                     };
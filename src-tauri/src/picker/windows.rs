@@ -27,6 +27,7 @@ use super::common::{
     should_use_dark_text,      // Détermine si texte noir ou blanc / Determines black or white text
     format_labeled_hex_color,  // Formate "Label - #RRGGBB" / Formats "Label - #RRGGBB"
 };
+use crate::conversion::PreciseColor; // Couleur étiquetée avec son espace / Color tagged with its space
 
 // -----------------------------------------------------------------------------
 // IMPORTS - Windows API
@@ -1540,8 +1541,8 @@ pub fn run(fg: bool) -> ColorPickerResult {
     
     if let Ok(state) = STATE.lock() {
         ColorPickerResult {
-            foreground: state.fg_color,
-            background: state.bg_color,
+            foreground: state.fg_color.map(PreciseColor::from_rgb8),
+            background: state.bg_color.map(PreciseColor::from_rgb8),
             continue_mode: state.continue_mode,
         }
     } else {
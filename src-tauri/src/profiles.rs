@@ -0,0 +1,261 @@
+// =============================================================================
+// profiles.rs - Profils de normes d'accessibilité
+// profiles.rs - Accessibility standards profiles
+// =============================================================================
+//
+// Un profil liste les critères de contraste, leurs seuils et leurs libellés.
+// Les profils intégrés couvrent WCAG 2.2, EN 301 549, la Section 508 et la
+// BITV 2.0 ; les profils personnalisés (par ex. une politique interne à 5:1)
+// sont chargés depuis un fichier JSON. Le profil actif pilote les résultats
+// de conformité du store.
+// A profile lists the contrast criteria, their thresholds and their labels.
+// Built-in profiles cover WCAG 2.2, EN 301 549, Section 508 and BITV 2.0;
+// custom profiles (e.g. a 5:1 internal policy) are loaded from a JSON file.
+// The active profile drives the compliance results of the store.
+//
+// Format JSON / JSON format:
+// {
+//   "id": "acme",
+//   "name": "ACME policy",
+//   "criteria": [
+//     { "id": "1.4.3", "level": "AA", "content": "regular", "threshold": 5.0, "label": "ACME text contrast" }
+//   ]
+// }
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+use crate::color;
+use crate::compliance::{self, ContentKind, Criterion, Level};
+use crate::store::AppState;
+
+/// Identifiant du profil par défaut / Default profile id
+pub const DEFAULT_PROFILE_ID: &str = "wcag22";
+
+// =============================================================================
+// STRUCTURES
+// =============================================================================
+
+/// Profil de norme d'accessibilité / Accessibility standards profile
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Profile {
+    /// Identifiant unique / Unique id
+    pub id: String,
+
+    /// Nom affiché / Displayed name
+    pub name: String,
+
+    /// Si le profil est intégré à l'application / If the profile ships with the application
+    #[serde(default)]
+    pub built_in: bool,
+
+    /// Critères évalués / Evaluated criteria
+    pub criteria: Vec<Criterion>,
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        wcag22()
+    }
+}
+
+/// Erreur de profil / Profile error
+#[derive(Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", content = "value", rename_all = "kebab-case")]
+pub enum ProfileError {
+    /// Fichier illisible / Unreadable file
+    Io(String),
+    /// JSON invalide ou profil incohérent / Invalid JSON or inconsistent profile
+    Invalid(String),
+    /// Identifiant réservé par un profil intégré / Id reserved by a built-in profile
+    ReservedId(String),
+    /// Profil inconnu / Unknown profile
+    UnknownProfile(String),
+}
+
+// =============================================================================
+// PROFILS INTÉGRÉS
+// BUILT-IN PROFILES
+// =============================================================================
+
+/// WCAG 2.2, tous niveaux / WCAG 2.2, every level
+fn wcag22() -> Profile {
+    Profile {
+        id: DEFAULT_PROFILE_ID.to_string(),
+        name: "WCAG 2.2".to_string(),
+        built_in: true,
+        criteria: compliance::wcag_criteria(),
+    }
+}
+
+/// Profils intégrés / Built-in profiles
+///
+/// EN 301 549 et la BITV 2.0 reprennent WCAG 2.1 niveau AA (clauses 9.1.4.x) ;
+/// la Section 508 révisée reprend WCAG 2.0 niveau AA, sans 1.4.11.
+/// EN 301 549 and BITV 2.0 incorporate WCAG 2.1 level AA (clauses 9.1.4.x);
+/// the revised Section 508 incorporates WCAG 2.0 level AA, without 1.4.11.
+pub fn built_in_profiles() -> Vec<Profile> {
+    use compliance::criterion;
+    let profile = |id: &str, name: &str, criteria| Profile { id: id.to_string(), name: name.to_string(), built_in: true, criteria };
+
+    vec![
+        wcag22(),
+        profile("en-301-549", "EN 301 549 V3.2.1", vec![
+            criterion("1.4.3", Level::Aa, ContentKind::Regular, 4.5, "9.1.4.3 Contrast (minimum)"),
+            criterion("1.4.3", Level::Aa, ContentKind::Large, 3.0, "9.1.4.3 Contrast (minimum), large text"),
+            criterion("1.4.11", Level::Aa, ContentKind::NonText, 3.0, "9.1.4.11 Non-text contrast"),
+        ]),
+        profile("section-508", "Section 508", vec![
+            criterion("1.4.3", Level::Aa, ContentKind::Regular, 4.5, "E205.4 / WCAG 2.0 1.4.3 Contrast (Minimum)"),
+            criterion("1.4.3", Level::Aa, ContentKind::Large, 3.0, "E205.4 / WCAG 2.0 1.4.3 Contrast (Minimum), large text"),
+        ]),
+        profile("bitv-2", "BITV 2.0", vec![
+            criterion("1.4.3", Level::Aa, ContentKind::Regular, 4.5, "9.1.4.3 Kontraste von Texten ausreichend"),
+            criterion("1.4.3", Level::Aa, ContentKind::Large, 3.0, "9.1.4.3 Kontraste von Texten ausreichend, großer Text"),
+            criterion("1.4.11", Level::Aa, ContentKind::NonText, 3.0, "9.1.4.11 Kontraste von Grafiken und Grenzen von Bedienelementen ausreichend"),
+        ]),
+    ]
+}
+
+// =============================================================================
+// CHARGEMENT
+// LOADING
+// =============================================================================
+
+/// Analyse et valide un profil JSON / Parses and validates a JSON profile
+pub fn parse_profile(json: &str) -> Result<Profile, ProfileError> {
+    let mut profile: Profile = serde_json::from_str(json).map_err(|e| ProfileError::Invalid(e.to_string()))?;
+    profile.built_in = false;
+
+    if profile.id.trim().is_empty() {
+        return Err(ProfileError::Invalid("missing profile id".to_string()));
+    }
+    if built_in_profiles().iter().any(|p| p.id == profile.id) {
+        return Err(ProfileError::ReservedId(profile.id));
+    }
+    if profile.criteria.is_empty() {
+        return Err(ProfileError::Invalid("a profile needs at least one criterion".to_string()));
+    }
+
+    // Un ratio WCAG 2 est toujours compris entre 1 et 21
+    // A WCAG 2 ratio is always between 1 and 21
+    for criterion in &mut profile.criteria {
+        if !(1.0..=21.0).contains(&criterion.threshold) {
+            return Err(ProfileError::Invalid(format!("threshold of {} must be between 1 and 21", criterion.key())));
+        }
+        if criterion.label.is_empty() {
+            criterion.label = criterion.key();
+        }
+    }
+
+    Ok(profile)
+}
+
+// =============================================================================
+// COMMANDES TAURI
+// TAURI COMMANDS
+// =============================================================================
+
+/// Liste les profils intégrés puis les profils chargés
+/// Lists the built-in profiles, then the loaded ones
+#[tauri::command]
+pub fn list_standards_profiles(state: tauri::State<AppState>) -> Vec<Profile> {
+    let mut profiles = built_in_profiles();
+    profiles.extend(state.profiles.lock().unwrap().iter().cloned());
+    profiles
+}
+
+/// Charge un profil personnalisé depuis un fichier JSON
+/// Loads a custom profile from a JSON file
+///
+/// Un profil déjà chargé avec le même identifiant est remplacé.
+/// A profile already loaded with the same id is replaced.
+///
+/// # Arguments
+/// * `path` - Chemin du fichier JSON / Path of the JSON file
+#[tauri::command]
+pub fn load_standards_profile(state: tauri::State<AppState>, path: String) -> Result<Profile, ProfileError> {
+    let json = std::fs::read_to_string(&path).map_err(|e| ProfileError::Io(format!("{}: {}", path, e)))?;
+    let profile = parse_profile(&json)?;
+
+    let mut profiles = state.profiles.lock().unwrap();
+    profiles.retain(|p| p.id != profile.id);
+    profiles.push(profile.clone());
+    Ok(profile)
+}
+
+/// Active un profil et réévalue la conformité du store
+/// Activates a profile and re-evaluates the store compliance
+///
+/// # Arguments
+/// * `id` - Identifiant du profil / Profile id
+#[tauri::command]
+pub fn select_standards_profile(app: AppHandle, state: tauri::State<AppState>, id: String) -> Result<(), ProfileError> {
+    let profile = built_in_profiles()
+        .into_iter()
+        .chain(state.profiles.lock().unwrap().iter().cloned())
+        .find(|p| p.id == id)
+        .ok_or(ProfileError::UnknownProfile(id))?;
+
+    let mut store = state.store.lock().unwrap();
+    store.profile = profile;
+
+    // Recalcule les valeurs de contraste et la conformité
+    // Recalculate contrast values and compliance
+    color::update_contrast(&mut store);
+
+    // Émet l'événement
+    // Emit the event
+    let _ = app.emit("store-updated", store.clone());
+    Ok(())
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strict_policy() {
+        // Une politique à 5:1 fait échouer un ratio de 4.8 conforme à WCAG
+        // A 5:1 policy fails a 4.8 ratio that meets WCAG
+        let profile = parse_profile(
+            r#"{ "id": "acme", "name": "ACME", "criteria": [
+                { "id": "1.4.3", "level": "AA", "content": "regular", "threshold": 5.0 }
+            ] }"#,
+        )
+        .unwrap();
+        assert!(!profile.built_in);
+        assert_eq!(profile.criteria[0].label, "1.4.3");
+        assert!(!compliance::evaluate(&profile.criteria, 4.8, None)[0].pass);
+        assert!(compliance::evaluate(&wcag22().criteria, 4.8, None)[0].pass);
+    }
+
+    #[test]
+    fn test_store_sends_profile_id() {
+        // Le store émis ne contient que l'identifiant du profil actif
+        // The emitted store only contains the active profile id
+        let mut store = crate::store::ResultStore { profile: built_in_profiles().pop().unwrap(), ..Default::default() };
+        color::update_contrast(&mut store);
+        let json = serde_json::to_value(&store).unwrap();
+        assert_eq!(json["profile_id"], store.profile.id.as_str());
+        assert!(json.get("profile").is_none());
+    }
+
+    #[test]
+    fn test_invalid_profiles() {
+        assert!(matches!(parse_profile("{"), Err(ProfileError::Invalid(_))));
+        assert!(matches!(
+            parse_profile(r#"{ "id": "wcag22", "name": "x", "criteria": [] }"#),
+            Err(ProfileError::ReservedId(_))
+        ));
+        assert!(matches!(
+            parse_profile(r#"{ "id": "x", "name": "x", "criteria": [
+                { "id": "1.4.3", "level": "AA", "content": "regular", "threshold": 30 }
+            ] }"#),
+            Err(ProfileError::Invalid(_))
+        ));
+    }
+}
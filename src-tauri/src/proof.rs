@@ -0,0 +1,242 @@
+// =============================================================================
+// proof.rs - Épreuvage écran CMYK de la paire
+// proof.rs - CMYK soft proofing of the pair
+// =============================================================================
+//
+// Une paire lisible à l'écran peut perdre son contraste une fois imprimée :
+// le noir d'encre n'est pas celui de l'écran, et le papier n'est pas blanc.
+// La paire composée du store est séparée en CMYK avec le profil de sortie
+// chargé (FOGRA39, GRACoL…), puis ramenée en sRGB par le même profil ; le
+// contraste est recalculé sur les couleurs épreuvées.
+// A pair readable on screen can lose its contrast once printed: ink black is
+// not screen black, and paper is not white. The composited store pair is
+// separated into CMYK with the loaded output profile (FOGRA39, GRACoL…), then
+// brought back to sRGB through the same profile; contrast is recalculated on
+// the proofed colors.
+
+use serde::Serialize;
+use crate::apca;
+use crate::color;
+use crate::compliance::{self, CriterionResult};
+use crate::config;
+use crate::conversion;
+use crate::icc::OutputProfile;
+use crate::picker::common::format_hex_color;
+use crate::store::{AppState, ResultStore};
+
+// =============================================================================
+// STRUCTURES
+// =============================================================================
+
+/// Couleur avant et après impression / Color before and after printing
+#[derive(Serialize, Clone, Debug)]
+pub struct ProofedColor {
+    /// Couleur composée à l'écran / Composited on-screen color
+    pub original_hex: String,
+
+    /// Couleur imprimée simulée / Simulated printed color
+    pub proofed_hex: String,
+
+    /// Séparation CMYK en pourcentages / CMYK separation in percent
+    pub cmyk: [f64; 4],
+
+    /// Distance CIEDE2000 entre l'écran et l'épreuve / CIEDE2000 distance between screen and proof
+    pub delta_e_2000: f64,
+}
+
+/// Contraste de la paire après épreuvage / Contrast of the pair after proofing
+#[derive(Serialize, Clone, Debug)]
+pub struct SoftProofReport {
+    /// Nom du profil de sortie / Output profile name
+    pub profile: String,
+
+    /// Si le blanc du papier est simulé / If the paper white is simulated
+    pub simulate_paper: bool,
+
+    pub foreground: ProofedColor,
+    pub background: ProofedColor,
+
+    /// Contraste à l'écran puis après épreuvage / On-screen contrast then after proofing
+    pub contrast_ratio_rounded: f32,
+    pub proofed_contrast_ratio_rounded: f32,
+    pub proofed_apca_lc_rounded: f64,
+
+    /// Critères du profil actif sur la paire épreuvée / Active profile criteria on the proofed pair
+    pub compliance: Vec<CriterionResult>,
+
+    /// Si chaque critère réussi à l'écran réussit encore à l'impression
+    /// If every criterion passed on screen still passes in print
+    pub keeps_compliance: bool,
+}
+
+// =============================================================================
+// FONCTIONS DE CALCUL
+// CALCULATION FUNCTIONS
+// =============================================================================
+
+/// Sépare une couleur puis la ramène en sRGB / Separates a color then brings it back to sRGB
+fn proof_color(profile: &OutputProfile, srgb: [f64; 3], simulate_paper: bool) -> (ProofedColor, [f64; 3]) {
+    let hex = |srgb: [f64; 3]| {
+        let (r, g, b) = conversion::srgb_to_rgb8(conversion::gamut_map_srgb(srgb));
+        format_hex_color(r, g, b)
+    };
+    let cmyk = profile.to_cmyk(srgb);
+    let proofed = profile.cmyk_to_srgb(cmyk, simulate_paper);
+    let lab = |srgb| conversion::xyz_to_lab(conversion::srgb_to_xyz(srgb));
+
+    let color = ProofedColor {
+        original_hex: hex(srgb),
+        proofed_hex: hex(proofed),
+        cmyk: cmyk.map(|v| (v * 1000.0).round() / 10.0),
+        delta_e_2000: conversion::delta_e_2000_lab(lab(srgb), lab(proofed)),
+    };
+    (color, proofed)
+}
+
+/// Épreuve la paire composée du store / Proofs the composited store pair
+pub fn soft_proof_pair(store: &ResultStore, profile: &OutputProfile, simulate_paper: bool) -> SoftProofReport {
+    let (foreground, fg) = proof_color(profile, store.foreground_composited_srgb, simulate_paper);
+    let (background, bg) = proof_color(profile, store.background_composited_srgb, simulate_paper);
+
+    let ratio = color::contrast_ratio(fg, bg);
+    let lc = apca::contrast(fg, bg);
+    let compliance = compliance::evaluate(&store.profile.criteria, ratio, store.text_spec.as_ref().map(|t| t.large));
+    let keeps_compliance = store
+        .compliance
+        .iter()
+        .filter(|c| c.pass)
+        .all(|c| compliance.iter().any(|p| p.id == c.id && p.content == c.content && p.pass));
+
+    SoftProofReport {
+        profile: profile.name.clone(),
+        simulate_paper,
+        foreground,
+        background,
+        contrast_ratio_rounded: store.contrast_ratio_rounded,
+        proofed_contrast_ratio_rounded: color::round_ratio(ratio),
+        proofed_apca_lc_rounded: (lc * config::APCA_ROUNDING_FACTOR).round() / config::APCA_ROUNDING_FACTOR,
+        compliance,
+        keeps_compliance,
+    }
+}
+
+// =============================================================================
+// COMMANDES TAURI
+// TAURI COMMANDS
+// =============================================================================
+
+/// Épreuve la paire du store avec le profil de sortie chargé, None sans profil
+/// Proofs the store pair with the loaded output profile, None without a profile
+///
+/// # Arguments
+/// * `simulate_paper` - Simule le blanc du papier (false par défaut) / Simulates the paper white (false by default)
+#[tauri::command]
+pub fn soft_proof(state: tauri::State<AppState>, simulate_paper: Option<bool>) -> Option<SoftProofReport> {
+    let profile = state.output_profile.lock().unwrap();
+    let store = state.store.lock().unwrap();
+    profile.as_ref().map(|profile| soft_proof_pair(&store, profile, simulate_paper.unwrap_or(false)))
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::store_with;
+    use crate::icc::parse_output_profile;
+
+    /// Table lut16 à grille 2 et courbes identité / lut16 table with a grid of 2 and identity curves
+    fn mft2(inputs: usize, outputs: usize, corner: impl Fn(&[f64]) -> Vec<u16>) -> Vec<u8> {
+        let mut tag = b"mft2\0\0\0\0".to_vec();
+        tag.extend([inputs as u8, outputs as u8, 2, 0]);
+        for i in 0..9 {
+            tag.extend(if i % 4 == 0 { 0x10000i32 } else { 0 }.to_be_bytes());
+        }
+        tag.extend([0, 2, 0, 2]);
+        let identity = |count: usize| (0..count).flat_map(|_| [0u16, 65535]).flat_map(u16::to_be_bytes).collect::<Vec<_>>();
+        tag.extend(identity(inputs));
+        for index in 0..(1 << inputs) {
+            let corner_input: Vec<f64> = (0..inputs).map(|i| ((index >> (inputs - 1 - i)) & 1) as f64).collect();
+            tag.extend(corner(&corner_input).iter().flat_map(|v| v.to_be_bytes()));
+        }
+        tag.extend(identity(outputs));
+        tag
+    }
+
+    /// Profil CMYK neutre : seule l'encre noire compte, papier au Y donné
+    /// Neutral CMYK profile: only the black ink counts, paper at the given Y
+    fn test_profile(paper_y: f64) -> Vec<u8> {
+        // CMYK vers Lab hérité : L = 100 (1 - K) / CMYK to legacy Lab: L = 100 (1 - K)
+        let a2b = mft2(4, 3, |cmyk| vec![((1.0 - cmyk[3]) * 65280.0) as u16, 0x8000, 0x8000]);
+        // Lab vers CMYK : K = 1 - L / Lab to CMYK: K = 1 - L
+        let b2a = mft2(3, 4, |lab| vec![0, 0, 0, ((1.0 - lab[0]) * 65535.0) as u16]);
+        let mut wtpt = b"XYZ \0\0\0\0".to_vec();
+        for v in [0.9642 * paper_y, paper_y, 0.8249 * paper_y] {
+            wtpt.extend(((v * 65536.0) as i32).to_be_bytes());
+        }
+        let mut desc = b"desc\0\0\0\0".to_vec();
+        desc.extend(10u32.to_be_bytes());
+        desc.extend(b"Test CMYK\0");
+
+        let tags: [(&[u8; 4], Vec<u8>); 4] = [(b"A2B1", a2b), (b"B2A1", b2a), (b"wtpt", wtpt), (b"desc", desc)];
+        let mut data = vec![0u8; 128];
+        data[12..16].copy_from_slice(b"prtr");
+        data[16..20].copy_from_slice(b"CMYK");
+        data[20..24].copy_from_slice(b"Lab ");
+        data[36..40].copy_from_slice(b"acsp");
+        data.extend((tags.len() as u32).to_be_bytes());
+        let mut offset = 132 + 12 * tags.len();
+        let mut body = Vec::new();
+        for (signature, tag) in &tags {
+            data.extend(*signature);
+            data.extend((offset as u32).to_be_bytes());
+            data.extend((tag.len() as u32).to_be_bytes());
+            let padded = tag.len().div_ceil(4) * 4;
+            body.extend(tag);
+            body.resize(body.len() + padded - tag.len(), 0);
+            offset += padded;
+        }
+        data.extend(body);
+        data
+    }
+
+    #[test]
+    fn test_parse_output_profile() {
+        let profile = parse_output_profile(&test_profile(1.0), "fallback").unwrap();
+        assert_eq!(profile.name, "Test CMYK");
+
+        // Le noir ne prend que l'encre noire, le blanc reste sans encre
+        // Black only takes black ink, white stays without ink
+        assert!(profile.to_cmyk([0.0, 0.0, 0.0])[3] > 0.99);
+        assert!(profile.to_cmyk([1.0, 1.0, 1.0])[3] < 0.01);
+
+        // Un gris fait l'aller-retour presque sans écart / A grey makes the round trip almost unchanged
+        let grey = [0.5, 0.5, 0.5];
+        let proofed = profile.cmyk_to_srgb(profile.to_cmyk(grey), false);
+        let lab = |srgb| conversion::xyz_to_lab(conversion::srgb_to_xyz(srgb));
+        assert!(conversion::delta_e_2000_lab(lab(grey), lab(proofed)) < 1.0);
+
+        let mut rgb = test_profile(1.0);
+        rgb[16..20].copy_from_slice(b"RGB ");
+        assert!(matches!(parse_output_profile(&rgb, "rgb"), Err(crate::icc::IccError::Unsupported(_))));
+        assert!(matches!(parse_output_profile(&test_profile(1.0)[..200], "cut"), Err(crate::icc::IccError::Invalid(_))));
+    }
+
+    #[test]
+    fn test_paper_white_lowers_contrast() {
+        let store = store_with((0x55, 0x55, 0x55), (0xFF, 0xFF, 0xFF));
+        let profile = parse_output_profile(&test_profile(0.8), "test").unwrap();
+
+        let relative = soft_proof_pair(&store, &profile, false);
+        assert!(relative.keeps_compliance);
+        assert_eq!(relative.background.proofed_hex, "#FEFEFE");
+
+        // Le papier plus sombre fait tomber la paire sous 7:1
+        // The darker paper drops the pair below 7:1
+        let paper = soft_proof_pair(&store, &profile, true);
+        assert!(paper.proofed_contrast_ratio_rounded < relative.proofed_contrast_ratio_rounded);
+        assert!(!paper.keeps_compliance);
+    }
+}
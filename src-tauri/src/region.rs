@@ -0,0 +1,520 @@
+// =============================================================================
+// region.rs - Contraste du texte sur une zone d'image
+// region.rs - Contrast of text over an image region
+// =============================================================================
+//
+// Un seul pixel choisi ne suffit pas pour juger un texte posé sur une photo
+// ou un dégradé. Le premier plan du store est comparé à chaque pixel d'un
+// tampon RGBA (capture, fichier image ou presse-papiers, décodé par
+// l'interface) ; le minimum, le 5e centile et la médiane du contraste sont
+// rapportés avec la position du pire pixel.
+// A single picked pixel is not enough to judge text over a photo or a
+// gradient. The store foreground is compared with every pixel of an RGBA
+// buffer (capture, image file or clipboard, decoded by the UI); the minimum,
+// 5th percentile and median contrast are reported with the position of the
+// worst pixel.
+//
+// L'interface envoie le tampon une fois, en octets bruts, à
+// `load_region_image` ; l'analyse, la détection et le voile (scrim.rs)
+// travaillent ensuite sur l'image chargée. Le picker natif ne sélectionne
+// qu'un pixel : la capture d'une zone à l'écran passe par une capture
+// système collée depuis le presse-papiers.
+// The UI sends the buffer once, as raw bytes, to `load_region_image`;
+// analysis, detection and the overlay (scrim.rs) then work on the loaded
+// image. The native picker only selects one pixel: capturing a screen
+// region goes through a system screenshot pasted from the clipboard.
+//
+// Le même tampon peut aussi servir à détecter la couleur du texte et celle du
+// fond : k-moyennes à deux groupes dans OKLab, les pixels d'anticrénelage
+// (entre les deux groupes) étant exclus du calcul des couleurs.
+// The same buffer can also be used to detect the text and background colors:
+// two-cluster k-means in OKLab, anti-aliasing pixels (between the two
+// clusters) being excluded from the color calculation.
+
+use serde::Serialize;
+use tauri::ipc::{InvokeBody, Request};
+use tauri::{AppHandle, Emitter};
+use crate::color;
+use crate::compliance::{self, CriterionResult};
+use crate::conversion::{self, PreciseColor};
+use crate::picker::common::{format_hex_color, ColorPickerResult};
+use crate::store::{AppState, ResultStore};
+
+/// Octets par pixel RGBA / Bytes per RGBA pixel
+pub const BYTES_PER_PIXEL: usize = 4;
+
+/// Centile rapporté pour ignorer quelques pixels isolés (bruit, anticrénelage)
+/// Percentile reported to ignore a few isolated pixels (noise, anti-aliasing)
+const LOW_PERCENTILE: f64 = 0.05;
+
+/// Nombre maximal d'itérations des k-moyennes / Maximum number of k-means iterations
+const KMEANS_ITERATIONS: usize = 20;
+
+/// Part de l'écart entre les groupes au-delà de laquelle un pixel est un bord (anticrénelage)
+/// Share of the distance between clusters beyond which a pixel is an edge (anti-aliasing)
+const EDGE_FRACTION: f64 = 0.25;
+
+/// Écart OKLab entre les groupes donnant une séparation pleine (noir sur blanc ≈ 1.0)
+/// OKLab distance between clusters giving a full separation (black on white ≈ 1.0)
+const FULL_SEPARATION: f64 = 0.25;
+
+/// Nombre minimal de pixels du texte / Minimum number of text pixels
+const MIN_TEXT_PIXELS: usize = 3;
+
+/// Confiance en dessous de laquelle la sélection manuelle est conseillée
+/// Confidence below which manual picking is advised
+const LOW_CONFIDENCE: f64 = 0.5;
+
+// =============================================================================
+// STRUCTURES
+// =============================================================================
+
+/// Image RGBA chargée pour l'analyse de zone / RGBA image loaded for region analysis
+#[derive(Clone, Debug, PartialEq)]
+pub struct RegionImage {
+    pub width: u32,
+    pub height: u32,
+
+    /// Tampon RGBA 8 bits, ligne par ligne / 8-bit RGBA buffer, row by row
+    pub pixels: Vec<u8>,
+}
+
+/// Pixel au plus faible contraste / Pixel with the lowest contrast
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct WorstPixel {
+    /// Position depuis le coin supérieur gauche / Position from the top left corner
+    pub x: u32,
+    pub y: u32,
+
+    /// Pixel composé sur la page / Pixel composited over the page
+    pub rgb: (u8, u8, u8),
+    pub hex: String,
+
+    pub contrast_ratio_rounded: f32,
+}
+
+/// Statistiques de contraste d'une zone / Contrast statistics of a region
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct RegionReport {
+    pub width: u32,
+    pub height: u32,
+
+    pub min_rounded: f32,
+    pub p5_rounded: f32,
+    pub median_rounded: f32,
+
+    pub worst: WorstPixel,
+
+    /// Critères du profil actif évalués sur le minimum / Active profile criteria evaluated on the minimum
+    pub compliance: Vec<CriterionResult>,
+}
+
+/// Couleurs du texte et du fond détectées dans une zone / Text and background colors detected in a region
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct Detection {
+    pub foreground_rgb: (u8, u8, u8),
+    pub foreground_hex: String,
+    pub background_rgb: (u8, u8, u8),
+    pub background_hex: String,
+
+    /// Pixels de chaque groupe et pixels de bord exclus / Pixels of each cluster and excluded edge pixels
+    pub foreground_pixels: usize,
+    pub background_pixels: usize,
+    pub edge_pixels: usize,
+
+    /// Contraste de la paire détectée / Contrast of the detected pair
+    pub contrast_ratio_rounded: f32,
+
+    /// Confiance (0.0 - 1.0) : séparation des groupes × compacité
+    /// Confidence (0.0 - 1.0): cluster separation × compactness
+    pub confidence: f64,
+
+    /// Si la sélection manuelle est conseillée / If manual picking is advised
+    pub low_confidence: bool,
+}
+
+/// Erreur d'analyse de zone / Region analysis error
+#[derive(Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", content = "value", rename_all = "kebab-case")]
+pub enum RegionError {
+    /// Zone vide / Empty region
+    Empty,
+    /// Taille du tampon différente de largeur × hauteur × 4 (taille attendue)
+    /// Buffer size different from width × height × 4 (expected size)
+    SizeMismatch(usize),
+    /// Aucune image chargée / No image loaded
+    NoImage,
+}
+
+// =============================================================================
+// FONCTIONS DE CALCUL
+// CALCULATION FUNCTIONS
+// =============================================================================
+
+/// Vérifie la taille du tampon et retourne le nombre de pixels
+/// Checks the buffer size and returns the number of pixels
+pub fn check_size(width: u32, height: u32, pixels: &[u8]) -> Result<usize, RegionError> {
+    let count = width as usize * height as usize;
+    if count == 0 {
+        return Err(RegionError::Empty);
+    }
+    if pixels.len() != count * BYTES_PER_PIXEL {
+        return Err(RegionError::SizeMismatch(count * BYTES_PER_PIXEL));
+    }
+    Ok(count)
+}
+
+/// Compare le premier plan du store à chaque pixel d'un tampon RGBA
+/// Compares the store foreground with every pixel of an RGBA buffer
+///
+/// Les pixels translucides sont composés sur la couleur de page, puis le
+/// premier plan est composé sur chaque pixel.
+/// Translucent pixels are composited over the page colour, then the
+/// foreground is composited over each pixel.
+pub fn analyze(store: &ResultStore, width: u32, height: u32, pixels: &[u8]) -> Result<RegionReport, RegionError> {
+    let count = check_size(width, height, pixels)?;
+    let page = conversion::rgb8_to_srgb(store.page_rgb);
+    let foreground = store.foreground_precise.to_srgb();
+
+    // Les pixels identiques ne sont calculés qu'une fois / Identical pixels are only calculated once
+    let mut cache = std::collections::HashMap::new();
+    let ratios: Vec<f64> = pixels
+        .chunks_exact(BYTES_PER_PIXEL)
+        .map(|p| {
+            *cache.entry([p[0], p[1], p[2], p[3]]).or_insert_with(|| {
+                let background = color::composite(conversion::rgb8_to_srgb((p[0], p[1], p[2])), p[3] as f64 / 255.0, page);
+                color::contrast_ratio(color::composite(foreground, store.foreground_alpha, background), background)
+            })
+        })
+        .collect();
+
+    let (worst_index, &min) = ratios
+        .iter()
+        .enumerate()
+        .min_by(|a, b| a.1.total_cmp(b.1))
+        .expect("non-empty region");
+
+    let mut sorted = ratios.clone();
+    sorted.sort_by(f64::total_cmp);
+    // Rang le plus proche / Nearest rank
+    let p5 = sorted[((LOW_PERCENTILE * count as f64).ceil() as usize).saturating_sub(1)];
+    let median = if count % 2 == 1 {
+        sorted[count / 2]
+    } else {
+        (sorted[count / 2 - 1] + sorted[count / 2]) / 2.0
+    };
+
+    let p = &pixels[worst_index * BYTES_PER_PIXEL..(worst_index + 1) * BYTES_PER_PIXEL];
+    let worst_background = color::composite(conversion::rgb8_to_srgb((p[0], p[1], p[2])), p[3] as f64 / 255.0, page);
+    let (r, g, b) = conversion::srgb_to_rgb8(worst_background);
+
+    Ok(RegionReport {
+        width,
+        height,
+        min_rounded: color::round_ratio(min),
+        p5_rounded: color::round_ratio(p5),
+        median_rounded: color::round_ratio(median),
+        worst: WorstPixel {
+            x: (worst_index % width as usize) as u32,
+            y: (worst_index / width as usize) as u32,
+            rgb: (r, g, b),
+            hex: format_hex_color(r, g, b),
+            contrast_ratio_rounded: color::round_ratio(min),
+        },
+        compliance: compliance::evaluate(&store.profile.criteria, min, store.text_spec.as_ref().map(|t| t.large)),
+    })
+}
+
+/// Distance euclidienne dans OKLab / Euclidean distance in OKLab
+fn distance(a: [f64; 3], b: [f64; 3]) -> f64 {
+    ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt()
+}
+
+/// Détecte la couleur du texte et celle du fond par k-moyennes dans OKLab
+/// Detects the text and background colors by k-means in OKLab
+///
+/// Les groupes partent des 5e et 95e centiles de clarté, pour qu'un pixel
+/// isolé (bruit, poussière) ne capte pas un groupe. À chaque itération, les
+/// pixels trop loin de leur groupe (bords anticrénelés) sont exclus de la
+/// moyenne. Le groupe le plus grand est le fond.
+/// The clusters start from the 5th and 95th lightness percentiles, so that
+/// an isolated pixel (noise, dust) does not capture a cluster. At each
+/// iteration, pixels too far from their cluster (anti-aliased edges) are
+/// excluded from the mean. The largest cluster is the background.
+pub fn detect(store: &ResultStore, width: u32, height: u32, pixels: &[u8]) -> Result<Detection, RegionError> {
+    check_size(width, height, pixels)?;
+
+    let page = conversion::rgb8_to_srgb(store.page_rgb);
+    let labs: Vec<[f64; 3]> = pixels
+        .chunks_exact(BYTES_PER_PIXEL)
+        .map(|p| {
+            let srgb = color::composite(conversion::rgb8_to_srgb((p[0], p[1], p[2])), p[3] as f64 / 255.0, page);
+            conversion::xyz_to_oklab(conversion::srgb_to_xyz(srgb))
+        })
+        .collect();
+
+    // Rang le plus proche / Nearest rank
+    let mut by_lightness: Vec<&[f64; 3]> = labs.iter().collect();
+    by_lightness.sort_by(|a, b| a[0].total_cmp(&b[0]));
+    let rank = ((LOW_PERCENTILE * labs.len() as f64).ceil() as usize).saturating_sub(1);
+    let mut centroids = [*by_lightness[rank], *by_lightness[labs.len() - 1 - rank]];
+
+    // Groupe de chaque pixel, None pour un bord / Cluster of each pixel, None for an edge
+    let mut labels: Vec<Option<usize>> = vec![None; labs.len()];
+    for _ in 0..KMEANS_ITERATIONS {
+        let separation = distance(centroids[0], centroids[1]);
+        let mut sums = [[0.0; 3]; 2];
+        let mut counts = [0usize; 2];
+        for (lab, label) in labs.iter().zip(labels.iter_mut()) {
+            let d = [distance(*lab, centroids[0]), distance(*lab, centroids[1])];
+            let nearest = usize::from(d[1] < d[0]);
+            *label = (d[nearest] <= EDGE_FRACTION * separation).then_some(nearest);
+            if let Some(cluster) = *label {
+                counts[cluster] += 1;
+                (0..3).for_each(|i| sums[cluster][i] += lab[i]);
+            }
+        }
+
+        // Un groupe vide garde son centre / An empty cluster keeps its centre
+        let previous = centroids;
+        for cluster in 0..2 {
+            if counts[cluster] > 0 {
+                centroids[cluster] = sums[cluster].map(|v| v / counts[cluster] as f64);
+            }
+        }
+        if distance(previous[0], centroids[0]) < 1e-9 && distance(previous[1], centroids[1]) < 1e-9 {
+            break;
+        }
+    }
+
+    let count = |cluster: usize| labels.iter().filter(|l| **l == Some(cluster)).count();
+    let (text, background) = if count(0) <= count(1) { (0, 1) } else { (1, 0) };
+
+    // Compacité : écart quadratique moyen des pixels retenus, relatif à la séparation
+    // Compactness: root mean square distance of the kept pixels, relative to the separation
+    let separation = distance(centroids[0], centroids[1]);
+    let kept: Vec<f64> = labs
+        .iter()
+        .zip(&labels)
+        .filter_map(|(lab, label)| label.map(|cluster| distance(*lab, centroids[cluster]).powi(2)))
+        .collect();
+    let spread = (kept.iter().sum::<f64>() / kept.len().max(1) as f64).sqrt();
+    let confidence = if count(text) < MIN_TEXT_PIXELS || separation == 0.0 {
+        0.0
+    } else {
+        (separation / FULL_SEPARATION).min(1.0) * (1.0 - spread / separation).clamp(0.0, 1.0)
+    };
+
+    let to_rgb8 = |oklab: [f64; 3]| conversion::srgb_to_rgb8(conversion::gamut_map_srgb(conversion::xyz_to_srgb(conversion::oklab_to_xyz(oklab))));
+    let (fr, fg, fb) = to_rgb8(centroids[text]);
+    let (br, bg, bb) = to_rgb8(centroids[background]);
+    let ratio = color::contrast_ratio(conversion::rgb8_to_srgb((fr, fg, fb)), conversion::rgb8_to_srgb((br, bg, bb)));
+    let confidence = (confidence * 100.0).round() / 100.0;
+
+    Ok(Detection {
+        foreground_rgb: (fr, fg, fb),
+        foreground_hex: format_hex_color(fr, fg, fb),
+        background_rgb: (br, bg, bb),
+        background_hex: format_hex_color(br, bg, bb),
+        foreground_pixels: count(text),
+        background_pixels: count(background),
+        edge_pixels: labels.iter().filter(|l| l.is_none()).count(),
+        contrast_ratio_rounded: color::round_ratio(ratio),
+        confidence,
+        low_confidence: confidence < LOW_CONFIDENCE,
+    })
+}
+
+// =============================================================================
+// COMMANDES TAURI
+// TAURI COMMANDS
+// =============================================================================
+
+/// Prend le pire pixel, déjà composé sur la page, comme arrière-plan du store
+/// Takes the worst pixel, already composited over the page, as the store background
+///
+/// Le pire pixel passe par le même chemin qu'une couleur choisie à l'écran.
+/// The worst pixel goes through the same path as a color picked on screen.
+pub fn use_worst_pixel(store: &mut ResultStore, worst: &WorstPixel) {
+    let result = ColorPickerResult {
+        background: Some(PreciseColor::from_rgb8(worst.rgb)),
+        ..Default::default()
+    };
+    color::update_results_from_picker(store, &result);
+}
+
+/// Charge l'image analysée par les commandes de zone
+/// Loads the image analyzed by the region commands
+///
+/// Le corps de la requête est le tampon RGBA 8 bits brut, ligne par ligne ;
+/// les en-têtes `width` et `height` donnent ses dimensions en pixels.
+/// The request body is the raw 8-bit RGBA buffer, row by row; the `width`
+/// and `height` headers give its dimensions in pixels.
+#[tauri::command]
+pub fn load_region_image(state: tauri::State<AppState>, request: Request<'_>) -> Result<(), RegionError> {
+    let header = |name: &str| {
+        request.headers().get(name).and_then(|v| v.to_str().ok()).and_then(|v| v.parse().ok()).unwrap_or(0)
+    };
+    let (width, height) = (header("width"), header("height"));
+    let pixels = match request.body() {
+        InvokeBody::Raw(bytes) => bytes.clone(),
+        InvokeBody::Json(_) => Vec::new(),
+    };
+    // Une image refusée ne laisse pas la précédente en place / A rejected image does not leave the previous one in place
+    let mut image = state.region_image.lock().unwrap();
+    *image = None;
+    check_size(width, height, &pixels)?;
+    *image = Some(RegionImage { width, height, pixels });
+    Ok(())
+}
+
+/// Analyse le contraste du premier plan sur l'image chargée
+/// Analyzes the foreground contrast over the loaded image
+///
+/// # Arguments
+/// * `use_worst` - Prend le pire pixel comme arrière-plan du store / Takes the worst pixel as the store background
+#[tauri::command]
+pub fn analyze_region(
+    app: AppHandle,
+    state: tauri::State<AppState>,
+    use_worst: Option<bool>,
+) -> Result<RegionReport, RegionError> {
+    let image = state.region_image.lock().unwrap();
+    let image = image.as_ref().ok_or(RegionError::NoImage)?;
+    let mut store = state.store.lock().unwrap();
+    let report = analyze(&store, image.width, image.height, &image.pixels)?;
+
+    if use_worst.unwrap_or(false) {
+        use_worst_pixel(&mut store, &report.worst);
+        let _ = app.emit("store-updated", store.clone());
+    }
+
+    Ok(report)
+}
+
+/// Détecte le texte et le fond de l'image chargée et remplit les deux cases du store
+/// Detects the text and background of the loaded image and fills both store slots
+///
+/// # Arguments
+/// * `fill` - Remplit le store (true par défaut) / Fills the store (true by default)
+#[tauri::command]
+pub fn detect_colors(
+    app: AppHandle,
+    state: tauri::State<AppState>,
+    fill: Option<bool>,
+) -> Result<Detection, RegionError> {
+    let image = state.region_image.lock().unwrap();
+    let image = image.as_ref().ok_or(RegionError::NoImage)?;
+    let mut store = state.store.lock().unwrap();
+    let detection = detect(&store, image.width, image.height, &image.pixels)?;
+
+    if fill.unwrap_or(true) {
+        let result = ColorPickerResult {
+            foreground: Some(PreciseColor::from_rgb8(detection.foreground_rgb)),
+            background: Some(PreciseColor::from_rgb8(detection.background_rgb)),
+            ..Default::default()
+        };
+        color::update_results_from_picker(&mut store, &result);
+        let _ = app.emit("store-updated", store.clone());
+    }
+
+    Ok(detection)
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gradient_region() {
+        let mut store = ResultStore::default();
+        color::set_color(&mut store, "foreground", PreciseColor::from_rgb8((0xFF, 0xFF, 0xFF)), Some(1.0));
+        color::update_contrast(&mut store);
+
+        // Dégradé horizontal du noir au gris moyen sur deux lignes
+        // Horizontal gradient from black to mid grey over two rows
+        let (width, height) = (20u32, 2u32);
+        let pixels: Vec<u8> = (0..height)
+            .flat_map(|_| (0..width).flat_map(|x| {
+                let v = (x * 6) as u8;
+                [v, v, v, 255]
+            }))
+            .collect();
+
+        let report = analyze(&store, width, height, &pixels).unwrap();
+        assert_eq!((report.worst.x, report.worst.y), (19, 0));
+        assert_eq!(report.worst.hex, "#727272");
+        assert!(report.min_rounded <= report.p5_rounded && report.p5_rounded <= report.median_rounded);
+        assert_eq!(report.min_rounded, 4.8);
+
+        // Un pixel transparent laisse voir la page blanche : 1:1
+        // A transparent pixel shows the white page: 1:1
+        let mut transparent = pixels.clone();
+        transparent[40 * 4 - 1] = 0;
+        let report = analyze(&store, width, height, &transparent).unwrap();
+        assert_eq!((report.worst.x, report.worst.y, report.min_rounded), (19, 1, 1.0));
+        assert!(!report.compliance.iter().any(|c| c.pass));
+    }
+
+    #[test]
+    fn test_translucent_worst_pixel() {
+        let mut store = crate::store::store_with((0x00, 0x00, 0x00), (0xFF, 0xFF, 0xFF));
+        store.page_rgb = (0xFF, 0xFF, 0xFF);
+
+        // Gris clair opaque et noir à 60 % sur la page blanche (#666666)
+        // Opaque light grey and 60 % black over the white page (#666666)
+        let pixels = [0xC0, 0xC0, 0xC0, 255, 0x00, 0x00, 0x00, 153];
+        let report = analyze(&store, 2, 1, &pixels).unwrap();
+        assert_eq!((report.worst.x, report.worst.rgb), (1, (0x66, 0x66, 0x66)));
+
+        // L'arrière-plan reçoit la couleur composée, pas le noir du pixel
+        // The background receives the composited colour, not the pixel black
+        use_worst_pixel(&mut store, &report.worst);
+        assert_eq!((store.background_rgb, store.background_alpha), ((0x66, 0x66, 0x66), 1.0));
+        assert_eq!(store.contrast_ratio_rounded, report.worst.contrast_ratio_rounded);
+    }
+
+    #[test]
+    fn test_detect_text_colors() {
+        let store = ResultStore::default();
+
+        // Barre bleu nuit de 2 px bordée d'anticrénelage sur fond crème
+        // 2 px midnight blue bar edged with anti-aliasing on a cream background
+        let (width, height) = (12u32, 8u32);
+        let pixels: Vec<u8> = (0..height)
+            .flat_map(|_| (0..width).flat_map(|x| match x {
+                5 | 6 => [0x19, 0x19, 0x70, 255],
+                4 | 7 => [0x8A, 0x89, 0xA8, 255],
+                _ => [0xFB, 0xF8, 0xE0, 255],
+            }))
+            .collect();
+
+        let detection = detect(&store, width, height, &pixels).unwrap();
+        assert_eq!((detection.foreground_hex.as_str(), detection.background_hex.as_str()), ("#191970", "#FBF8E0"));
+        assert_eq!((detection.foreground_pixels, detection.background_pixels, detection.edge_pixels), (16, 64, 16));
+        assert!(detection.confidence > 0.9 && !detection.low_confidence);
+
+        // Un pixel noir isolé ne capte pas le groupe du texte
+        // An isolated black pixel does not capture the text cluster
+        let mut noisy = pixels.clone();
+        noisy[..4].copy_from_slice(&[0, 0, 0, 255]);
+        let detection = detect(&store, width, height, &noisy).unwrap();
+        assert_eq!((detection.foreground_hex.as_str(), detection.foreground_pixels), ("#191970", 16));
+
+        // Une zone uniforme n'a pas de texte / A uniform region has no text
+        let detection = detect(&store, 2, 2, &[0x80; 16]).unwrap();
+        assert_eq!(detection.confidence, 0.0);
+        assert!(detection.low_confidence);
+    }
+
+    #[test]
+    fn test_invalid_buffer() {
+        let store = ResultStore::default();
+        assert_eq!(analyze(&store, 0, 10, &[]), Err(RegionError::Empty));
+        assert_eq!(analyze(&store, 2, 2, &[0; 12]), Err(RegionError::SizeMismatch(16)));
+    }
+}
@@ -0,0 +1,500 @@
+// =============================================================================
+// scrim.rs - Opacité minimale d'un voile sous le texte posé sur une image
+// scrim.rs - Minimum opacity of an overlay under text over an image
+// =============================================================================
+//
+// Pour un bandeau avec texte sur photo, cherche l'opacité la plus faible d'un
+// voile (overlay, scrim) qui amène le pire pixel de la zone au seuil choisi.
+// Le voile est composé sur chaque pixel, puis le texte sur le voile, avec
+// `color::composite` comme le calcul du store. Un dégradé peut aussi être
+// proposé : l'opacité minimale de chaque arrêt, à positions régulières.
+// For a banner with text over a photo, finds the lowest opacity of an overlay
+// (scrim) that brings the worst pixel of the region up to the chosen
+// threshold. The overlay is composited over each pixel, then the text over the
+// overlay, with `color::composite` like the store calculation. A gradient can
+// also be proposed: the minimum opacity of each stop, at regular positions.
+
+use serde::{Deserialize, Serialize};
+use crate::apca;
+use crate::color;
+use crate::config;
+use crate::conversion;
+use crate::css_color::{self, ParseError, ParsedColor};
+use crate::fixes::{FixError, FixTarget};
+use crate::picker::common::format_hex_color;
+use crate::region::{self, RegionError, BYTES_PER_PIXEL};
+use crate::store::{AppState, ResultStore};
+
+/// Nombre d'itérations des recherches sur l'opacité
+/// Number of iterations of the searches on opacity
+const SEARCH_ITERATIONS: usize = 40;
+
+/// Précision de l'opacité rapportée, arrondie vers le haut (0.001)
+/// Precision of the reported opacity, rounded up (0.001)
+const ALPHA_PRECISION: f64 = 1000.0;
+
+/// Nombre d'arrêts du dégradé par défaut / Default number of gradient stops
+const DEFAULT_STOPS: usize = 5;
+
+// =============================================================================
+// STRUCTURES
+// =============================================================================
+
+/// Direction du dégradé : "bottom" pour `to bottom`…
+/// Gradient direction: "bottom" for `to bottom`…
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum GradientDirection {
+    Bottom,
+    Top,
+    Right,
+    Left,
+}
+
+/// Arrêt du dégradé / Gradient stop
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct ScrimStop {
+    /// Position en pourcentage le long de la direction / Position in percent along the direction
+    pub position: f64,
+
+    /// Opacité minimale du voile (0.0 - 1.0) / Minimum overlay opacity (0.0 - 1.0)
+    pub alpha: f64,
+}
+
+/// Voile minimal pour une zone / Minimum overlay for a region
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct ScrimReport {
+    pub text_hex: String,
+    pub overlay_hex: String,
+
+    /// Cible résolue (ratio ou Lc APCA) / Resolved target (ratio or APCA Lc)
+    pub target: FixTarget,
+
+    /// Opacité minimale uniforme, None si même un voile opaque ne suffit pas
+    /// Minimum uniform opacity, None if even an opaque overlay is not enough
+    pub alpha: Option<f64>,
+
+    /// Voile en CSS, par ex. `rgb(0 0 0 / 0.42)` / Overlay in CSS, e.g. `rgb(0 0 0 / 0.42)`
+    pub css: Option<String>,
+
+    /// Pire contraste avec ce voile (voile opaque si inatteignable)
+    /// Worst contrast with this overlay (opaque overlay if unreachable)
+    pub worst_contrast_ratio_rounded: f32,
+    pub worst_apca_lc_rounded: f64,
+
+    /// Arrêts minimaux du dégradé demandé / Minimum stops of the requested gradient
+    pub gradient: Option<Vec<ScrimStop>>,
+    pub gradient_css: Option<String>,
+}
+
+/// Erreur du calcul de voile / Overlay calculation error
+#[derive(Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", content = "value", rename_all = "kebab-case")]
+pub enum ScrimError {
+    /// Couleur CSS invalide / Invalid CSS color
+    InvalidColor(ParseError),
+    /// Zone invalide / Invalid region
+    Region(RegionError),
+    /// Critère inconnu ou absent du profil actif / Unknown criterion or missing from the active profile
+    UnknownCriterion(String),
+}
+
+impl From<RegionError> for ScrimError {
+    fn from(error: RegionError) -> Self {
+        ScrimError::Region(error)
+    }
+}
+
+impl From<FixError> for ScrimError {
+    fn from(error: FixError) -> Self {
+        match error {
+            FixError::UnknownCriterion(key) => ScrimError::UnknownCriterion(key),
+        }
+    }
+}
+
+// =============================================================================
+// FONCTIONS DE CALCUL
+// CALCULATION FUNCTIONS
+// =============================================================================
+
+impl GradientDirection {
+    /// Mot-clé CSS / CSS keyword
+    fn css(self) -> &'static str {
+        match self {
+            GradientDirection::Bottom => "to bottom",
+            GradientDirection::Top => "to top",
+            GradientDirection::Right => "to right",
+            GradientDirection::Left => "to left",
+        }
+    }
+}
+
+/// Opacités du voile pour lesquelles un pixel passe : [0, a1] ∪ [a2, 1]
+/// Overlay opacities for which a pixel passes: [0, a1] ∪ [a2, 1]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Passing {
+    /// Plus petite opacité qui passe / Smallest passing opacity
+    minimum: f64,
+
+    /// Début (a2) de la plage qui passe jusqu'au voile opaque, None si le voile opaque échoue
+    /// Start (a2) of the range passing up to the opaque overlay, None if the opaque overlay fails
+    rising: Option<f64>,
+}
+
+/// Texte et voile composés sur un pixel / Text and overlay composited over a pixel
+struct Layers {
+    text: [f64; 3],
+    text_alpha: f64,
+    overlay: [f64; 3],
+}
+
+impl Layers {
+    /// Paire composée pour une opacité du voile / Composited pair for an overlay opacity
+    fn pair(&self, pixel: [f64; 3], alpha: f64) -> ([f64; 3], [f64; 3]) {
+        let backdrop = color::composite(self.overlay, alpha, pixel);
+        (color::composite(self.text, self.text_alpha, backdrop), backdrop)
+    }
+
+    /// Mesure de la cible (ratio ou |Lc|) / Target measure (ratio or |Lc|)
+    fn measure(&self, target: &FixTarget, pixel: [f64; 3], alpha: f64) -> f64 {
+        let (foreground, background) = self.pair(pixel, alpha);
+        match target {
+            FixTarget::ApcaLc(_) => apca::contrast(foreground, background).abs(),
+            _ => color::contrast_ratio(foreground, background),
+        }
+    }
+
+    /// Si le pixel passe sous un voile de cette opacité / If the pixel passes under an overlay of this opacity
+    fn passes(&self, target: &FixTarget, pixel: [f64; 3], alpha: f64) -> bool {
+        let (foreground, background) = self.pair(pixel, alpha);
+        target.passes(foreground, background)
+    }
+
+    /// Opacités pour lesquelles le pixel passe, None s'il n'en existe aucune
+    /// Opacities for which the pixel passes, None if there are none
+    ///
+    /// La luminance du fond varie de façon monotone avec l'opacité : le contraste
+    /// descend jusqu'à un creux puis remonte, et atteint son maximum à l'une des
+    /// extrémités. Le creux est trouvé par recherche ternaire ; s'il échoue, la
+    /// remontée est parcourue par dichotomie.
+    /// The backdrop luminance varies monotonically with opacity: contrast goes
+    /// down to a trough then back up, and reaches its maximum at one of the ends.
+    /// The trough is found by ternary search; if it fails, the rising side is
+    /// walked by binary search.
+    fn passing(&self, target: &FixTarget, pixel: [f64; 3]) -> Option<Passing> {
+        let passes = |alpha: f64| self.passes(target, pixel, alpha);
+        let transparent = passes(0.0);
+        let rising = passes(1.0).then(|| {
+            let (mut low, mut high) = (0.0, 1.0);
+            for _ in 0..SEARCH_ITERATIONS {
+                let a = low + (high - low) / 3.0;
+                let b = high - (high - low) / 3.0;
+                if self.measure(target, pixel, a) < self.measure(target, pixel, b) {
+                    high = b;
+                } else {
+                    low = a;
+                }
+            }
+            if passes(low) {
+                return 0.0;
+            }
+
+            let (mut failing, mut passing) = (low, 1.0);
+            for _ in 0..SEARCH_ITERATIONS {
+                let mid = (failing + passing) / 2.0;
+                if passes(mid) {
+                    passing = mid;
+                } else {
+                    failing = mid;
+                }
+            }
+            passing
+        });
+
+        match (transparent, rising) {
+            (true, _) => Some(Passing { minimum: 0.0, rising }),
+            (false, Some(start)) => Some(Passing { minimum: start, rising }),
+            (false, None) => None,
+        }
+    }
+
+    /// Plus petite opacité (arrondie vers le haut) qui fait passer tous les pixels, None si aucune
+    /// Smallest opacity (rounded up) making every pixel pass, None if there is none
+    ///
+    /// Le maximum des minimums peut tomber dans le creux d'un pixel qui passait
+    /// sans voile : l'opacité est vérifiée sur chaque pixel et relevée au début
+    /// de la remontée des pixels qui échouent, jusqu'à ce que tous passent.
+    /// The maximum of the minimums can fall into the trough of a pixel that
+    /// passed without an overlay: the opacity is checked on every pixel and
+    /// raised to the start of the rising side of the failing pixels, until all pass.
+    fn common_alpha<'a, I>(&self, target: &FixTarget, pixels: I) -> Option<f64>
+    where
+        I: Iterator<Item = ([f64; 3], &'a Option<Passing>)> + Clone,
+    {
+        let mut alpha = ceil_alpha(pixels.clone().try_fold(0.0, |max: f64, (_, passing)| passing.map(|p| max.max(p.minimum)))?);
+        loop {
+            let mut raised = alpha;
+            for (pixel, passing) in pixels.clone() {
+                if !self.passes(target, pixel, alpha) {
+                    raised = raised.max(ceil_alpha((*passing)?.rising?));
+                }
+            }
+            if raised == alpha {
+                return Some(alpha);
+            }
+            alpha = raised;
+        }
+    }
+}
+
+/// Arrondit une opacité vers le haut / Rounds an opacity up
+fn ceil_alpha(alpha: f64) -> f64 {
+    ((alpha * ALPHA_PRECISION).ceil() / ALPHA_PRECISION).min(1.0)
+}
+
+/// Calcule le voile minimal pour une zone RGBA
+/// Calculates the minimum overlay for an RGBA region
+///
+/// # Arguments
+/// * `text` - Couleur du texte (son opacité est conservée) / Text color (its opacity is kept)
+/// * `overlay` - Couleur du voile (son opacité est ignorée) / Overlay color (its opacity is ignored)
+/// * `target` - Cible ; par défaut le seuil de texte du profil actif
+///   Target; the active profile text threshold by default
+/// * `gradient` - Direction et nombre d'arrêts (au moins 2) / Direction and number of stops (at least 2)
+#[allow(clippy::too_many_arguments)]
+pub fn calculate(
+    store: &ResultStore,
+    text: &ParsedColor,
+    overlay: &ParsedColor,
+    target: Option<&FixTarget>,
+    width: u32,
+    height: u32,
+    pixels: &[u8],
+    gradient: Option<(GradientDirection, usize)>,
+) -> Result<ScrimReport, ScrimError> {
+    region::check_size(width, height, pixels)?;
+    let target = match target {
+        Some(target) => target.resolve(&store.profile.criteria)?,
+        None => FixTarget::text_default(store)?,
+    };
+
+    let page = conversion::rgb8_to_srgb(store.page_rgb);
+    let layers = Layers { text: text.color.to_srgb(), text_alpha: text.alpha, overlay: overlay.color.to_srgb() };
+
+    // Pixels composés sur la page ; les pixels identiques ne sont calculés qu'une fois
+    // Pixels composited over the page; identical pixels are only calculated once
+    let backdrops: Vec<[f64; 3]> = pixels
+        .chunks_exact(BYTES_PER_PIXEL)
+        .map(|p| color::composite(conversion::rgb8_to_srgb((p[0], p[1], p[2])), p[3] as f64 / 255.0, page))
+        .collect();
+    let mut cache = std::collections::HashMap::new();
+    let required: Vec<Option<Passing>> = pixels
+        .chunks_exact(BYTES_PER_PIXEL)
+        .zip(&backdrops)
+        .map(|(p, backdrop)| *cache.entry([p[0], p[1], p[2], p[3]]).or_insert_with(|| layers.passing(&target, *backdrop)))
+        .collect();
+
+    let alpha = layers.common_alpha(&target, backdrops.iter().copied().zip(&required));
+
+    // Pire contraste avec le voile retenu / Worst contrast with the chosen overlay
+    let applied = alpha.unwrap_or(1.0);
+    let (worst_ratio, worst_lc) = backdrops.iter().fold((f64::INFINITY, f64::INFINITY), |(ratio, lc), pixel| {
+        let (foreground, background) = layers.pair(*pixel, applied);
+        (
+            ratio.min(color::contrast_ratio(foreground, background)),
+            lc.min(apca::contrast(foreground, background).abs()),
+        )
+    });
+
+    let (r, g, b) = conversion::srgb_to_rgb8(conversion::gamut_map_srgb(layers.overlay));
+    let rgba = |alpha: f64| format!("rgb({} {} {} / {})", r, g, b, alpha);
+    let (tr, tg, tb) = conversion::srgb_to_rgb8(conversion::gamut_map_srgb(layers.text));
+
+    let gradient = gradient.and_then(|(direction, stops)| {
+        // Ligne de chaque pixel le long de la direction / Line of each pixel along the direction
+        let lines = match direction {
+            GradientDirection::Bottom | GradientDirection::Top => height as usize,
+            GradientDirection::Right | GradientDirection::Left => width as usize,
+        };
+        let line = |index: usize| {
+            let (x, y) = (index % width as usize, index / width as usize);
+            match direction {
+                GradientDirection::Bottom => y,
+                GradientDirection::Top => lines - 1 - y,
+                GradientDirection::Right => x,
+                GradientDirection::Left => lines - 1 - x,
+            }
+        };
+
+        // Le dégradé est linéaire entre deux arrêts : chaque arrêt couvre les
+        // lignes de ses deux segments voisins, le minimum d'un segment étant à
+        // l'une de ses extrémités.
+        // The gradient is linear between two stops: each stop covers the lines
+        // of its two neighbouring segments, the minimum of a segment being at
+        // one of its ends.
+        let stops = stops.max(2);
+        let position = |k: usize| k as f64 / (stops - 1) as f64;
+        (0..stops)
+            .map(|k| {
+                let from = position(k.saturating_sub(1));
+                let to = position((k + 1).min(stops - 1));
+                let covered = backdrops
+                    .iter()
+                    .copied()
+                    .zip(&required)
+                    .enumerate()
+                    .filter(move |(index, _)| (from..=to).contains(&((line(*index) as f64 + 0.5) / lines as f64)))
+                    .map(|(_, pixel)| pixel);
+                layers
+                    .common_alpha(&target, covered)
+                    .map(|alpha| ScrimStop { position: (position(k) * 10000.0).round() / 100.0, alpha })
+            })
+            .collect::<Option<Vec<_>>>()
+            .map(|stops| {
+                let css = stops.iter().map(|s| format!("{} {}%", rgba(s.alpha), s.position)).collect::<Vec<_>>().join(", ");
+                (format!("linear-gradient({}, {})", direction.css(), css), stops)
+            })
+    });
+
+    Ok(ScrimReport {
+        text_hex: format_hex_color(tr, tg, tb),
+        overlay_hex: format_hex_color(r, g, b),
+        target,
+        alpha,
+        css: alpha.map(rgba),
+        worst_contrast_ratio_rounded: color::round_ratio(worst_ratio),
+        worst_apca_lc_rounded: (worst_lc * config::APCA_ROUNDING_FACTOR).round() / config::APCA_ROUNDING_FACTOR,
+        gradient_css: gradient.as_ref().map(|(css, _)| css.clone()),
+        gradient: gradient.map(|(_, stops)| stops),
+    })
+}
+
+// =============================================================================
+// COMMANDES TAURI
+// TAURI COMMANDS
+// =============================================================================
+
+/// Calcule l'opacité minimale d'un voile entre l'image chargée (`load_region_image`) et le texte
+/// Calculates the minimum opacity of an overlay between the loaded image (`load_region_image`) and the text
+///
+/// # Arguments
+/// * `text`, `overlay` - Saisies CSS Color 4 / CSS Color 4 entries
+/// * `target` - Cible ; par défaut le seuil de texte du profil actif
+///   Target; the active profile text threshold by default
+/// * `gradient` - Direction d'un dégradé à proposer aussi / Direction of a gradient to propose as well
+/// * `stops` - Nombre d'arrêts du dégradé (5 par défaut) / Number of gradient stops (5 by default)
+#[tauri::command]
+pub fn scrim_opacity(
+    state: tauri::State<AppState>,
+    text: String,
+    overlay: String,
+    target: Option<FixTarget>,
+    gradient: Option<GradientDirection>,
+    stops: Option<usize>,
+) -> Result<ScrimReport, ScrimError> {
+    let text = css_color::parse(&text).map_err(ScrimError::InvalidColor)?;
+    let overlay = css_color::parse(&overlay).map_err(ScrimError::InvalidColor)?;
+    let image = state.region_image.lock().unwrap();
+    let image = image.as_ref().ok_or(RegionError::NoImage)?;
+    let store = state.store.lock().unwrap();
+    let gradient = gradient.map(|direction| (direction, stops.unwrap_or(DEFAULT_STOPS)));
+    calculate(&store, &text, &overlay, target.as_ref(), image.width, image.height, &image.pixels, gradient)
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Zone d'une couleur par ligne / Region with one color per row
+    fn rows(width: u32, colors: &[u8]) -> Vec<u8> {
+        colors.iter().flat_map(|v| (0..width).flat_map(move |_| [*v, *v, *v, 255])).collect()
+    }
+
+    fn parse(input: &str) -> ParsedColor {
+        css_color::parse(input).unwrap()
+    }
+
+    #[test]
+    fn test_minimum_uniform_alpha() {
+        let store = ResultStore::default();
+        let (white, black) = (parse("white"), parse("black"));
+        let pixels = rows(4, &[0x20, 0x77, 0xCC]);
+
+        let report = calculate(&store, &white, &black, None, 4, 3, &pixels, None).unwrap();
+        let alpha = report.alpha.unwrap();
+        assert_eq!(report.target, FixTarget::Ratio(4.5));
+        assert_eq!(report.css.as_deref(), Some(format!("rgb(0 0 0 / {})", alpha).as_str()));
+        assert!(report.worst_contrast_ratio_rounded >= 4.5);
+
+        // Un millième de moins ne suffit plus pour le pixel le plus clair
+        // One thousandth less is no longer enough for the lightest pixel
+        let layers = Layers { text: [1.0; 3], text_alpha: 1.0, overlay: [0.0; 3] };
+        let lightest = conversion::rgb8_to_srgb((0xCC, 0xCC, 0xCC));
+        let (fg, bg) = layers.pair(lightest, alpha - 0.001);
+        assert!(color::contrast_ratio(fg, bg) < 4.5);
+
+        // Une zone déjà assez sombre n'a pas besoin de voile
+        // A region already dark enough needs no overlay
+        let dark = calculate(&store, &white, &black, None, 4, 1, &rows(4, &[0x20]), None).unwrap();
+        assert_eq!(dark.alpha, Some(0.0));
+
+        // Un voile blanc sous un texte blanc n'atteint jamais le seuil
+        // A white overlay under white text never reaches the threshold
+        let unreachable = calculate(&store, &white, &white, None, 4, 3, &pixels, None).unwrap();
+        assert_eq!((unreachable.alpha, unreachable.css), (None, None));
+        assert!(matches!(
+            calculate(&store, &white, &black, None, 4, 3, &pixels[..8], None),
+            Err(ScrimError::Region(RegionError::SizeMismatch(48)))
+        ));
+    }
+
+    #[test]
+    fn test_alpha_checked_on_every_pixel() {
+        let store = ResultStore::default();
+
+        // Texte blanc sur noir : 21:1 sans voile, même si un voile blanc opaque échoue
+        // White text over black: 21:1 without an overlay, even though an opaque white overlay fails
+        let report = calculate(&store, &parse("white"), &parse("white"), None, 4, 1, &rows(4, &[0x00]), None).unwrap();
+        assert_eq!((report.alpha, report.css.as_deref()), (Some(0.0), Some("rgb(255 255 255 / 0)")));
+        assert_eq!(report.worst_contrast_ratio_rounded, 21.0);
+
+        // Gris moyen sous un voile blanc : le noir passe sans voile puis retombe dans un
+        // creux ; le gris clair demande une opacité qui tombe dans ce creux
+        // Mid grey under a white overlay: black passes without an overlay then falls into
+        // a trough; light grey needs an opacity that falls into that trough
+        let target = FixTarget::Ratio(3.0);
+        let (text, overlay) = (parse("#767676"), parse("white"));
+        let light = calculate(&store, &text, &overlay, Some(&target), 4, 1, &rows(4, &[0xA0]), None).unwrap();
+        let report = calculate(&store, &text, &overlay, Some(&target), 4, 2, &rows(4, &[0x00, 0xA0]), None).unwrap();
+        assert_eq!((light.alpha, report.alpha), (Some(0.525), Some(0.823)));
+        assert!(report.worst_contrast_ratio_rounded >= 3.0);
+    }
+
+    #[test]
+    fn test_gradient_stops() {
+        let store = ResultStore::default();
+        // Haut blanc, bas noir : seul le haut demande un voile
+        // White top, black bottom: only the top needs an overlay
+        let pixels = rows(2, &[0xFF, 0xFF, 0x00, 0x00]);
+        let report = calculate(&store, &parse("white"), &parse("black"), None, 2, 4, &pixels, Some((GradientDirection::Bottom, 3))).unwrap();
+        let stops = report.gradient.unwrap();
+        let top = stops[0].alpha;
+
+        assert_eq!(stops.iter().map(|s| s.position).collect::<Vec<_>>(), vec![0.0, 50.0, 100.0]);
+        assert_eq!(stops.iter().map(|s| s.alpha).collect::<Vec<_>>(), vec![top, top, 0.0]);
+        assert_eq!(report.alpha, Some(top));
+        assert_eq!(
+            report.gradient_css.unwrap(),
+            format!("linear-gradient(to bottom, rgb(0 0 0 / {0}) 0%, rgb(0 0 0 / {0}) 50%, rgb(0 0 0 / 0) 100%)", top)
+        );
+
+        // Vers le haut, les arrêts sont inversés / Towards the top, the stops are reversed
+        let report = calculate(&store, &parse("white"), &parse("black"), None, 2, 4, &pixels, Some((GradientDirection::Top, 3))).unwrap();
+        assert_eq!(report.gradient.unwrap().iter().map(|s| s.alpha).collect::<Vec<_>>(), vec![0.0, top, top]);
+    }
+}
@@ -0,0 +1,431 @@
+// =============================================================================
+// states.rs - Matrice de contraste des états d'un composant
+// states.rs - Component state contrast matrix
+// =============================================================================
+//
+// Un espace de travail contient des états nommés (default, hover, focus,
+// active, disabled…), chacun avec un premier plan, un arrière-plan et un
+// indicateur optionnel (contour, soulignement…). Pour chaque état, on calcule
+// le contraste du texte, le contraste non textuel (3:1) de l'indicateur face
+// aux couleurs adjacentes, puis le contraste entre l'état « focus » et l'état
+// « default » pour WCAG 2.4.13.
+// A workspace holds named states (default, hover, focus, active, disabled…),
+// each with a foreground, a background and an optional indicator (outline,
+// underline…). For each state, the text contrast, the non-text contrast (3:1)
+// of the indicator against the adjacent colors, then the contrast between the
+// "focus" and "default" states for WCAG 2.4.13 are calculated.
+// Reference: https://www.w3.org/WAI/WCAG22/Understanding/focus-appearance
+
+use serde::{Deserialize, Serialize};
+use crate::apca;
+use crate::color;
+use crate::compliance::{self, CriterionResult};
+use crate::config;
+use crate::conversion::{self, PreciseColor};
+use crate::css_color::{self, ParseError};
+use crate::picker::common::format_hex_color;
+use crate::store::{AppState, ResultStore};
+
+/// Ratio minimal des éléments non textuels (1.4.11) et du changement au focus (2.4.13)
+/// Minimum ratio of non-text elements (1.4.11) and of the focus change (2.4.13)
+const NON_TEXT_THRESHOLD: f64 = 3.0;
+
+/// État sans focus comparé à l'état « focus » / Unfocused state compared with the "focus" state
+const UNFOCUSED_STATE: &str = "default";
+
+/// État avec focus / Focused state
+const FOCUSED_STATE: &str = "focus";
+
+/// État désactivé, exempté des critères de contraste / Disabled state, exempt from the contrast criteria
+const DISABLED_STATE: &str = "disabled";
+
+// =============================================================================
+// STRUCTURES
+// =============================================================================
+
+/// Couleur d'un état avec son opacité / State color with its opacity
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct StateColor {
+    pub color: PreciseColor,
+    pub alpha: f64,
+}
+
+/// Position de l'indicateur / Indicator placement
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum IndicatorPlacement {
+    /// Contour à l'extérieur du composant, dessiné sur la page / Outline outside the component, drawn over the page
+    #[default]
+    Outside,
+    /// Indicateur dessiné sur le fond du composant (soulignement, bordure intérieure)
+    /// Indicator drawn over the component fill (underline, inset border)
+    Inside,
+}
+
+/// État d'un composant tel que saisi / Component state as entered
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ComponentState {
+    /// Nom de l'état (default, hover, focus, active, disabled…) / State name (default, hover, focus, active, disabled…)
+    pub name: String,
+
+    pub foreground: StateColor,
+    pub background: StateColor,
+
+    /// Indicateur optionnel (contour, soulignement…) / Optional indicator (outline, underline…)
+    pub indicator: Option<StateColor>,
+
+    /// Position de l'indicateur / Indicator placement
+    #[serde(default)]
+    pub placement: IndicatorPlacement,
+}
+
+impl ComponentState {
+    /// Si l'état porte ce nom, sans tenir compte des espaces ni de la casse
+    /// If the state has this name, ignoring whitespace and case
+    pub fn is_named(&self, name: &str) -> bool {
+        self.name.trim().eq_ignore_ascii_case(name.trim())
+    }
+}
+
+/// Contraste de l'indicateur face aux couleurs adjacentes
+/// Indicator contrast against the adjacent colors
+#[derive(Serialize, Clone, Debug)]
+pub struct IndicatorResult {
+    pub hex: String,
+
+    /// Indicateur / arrière-plan du composant / Indicator vs component background
+    pub vs_background_rounded: f32,
+
+    /// Indicateur / couleur de page / Indicator vs page color
+    pub vs_page_rounded: f32,
+
+    /// Si l'indicateur atteint 3:1 face à ce qu'il touche : le fond et la page
+    /// à l'extérieur, le fond seul à l'intérieur
+    /// If the indicator reaches 3:1 against what it touches: the background and
+    /// the page outside, the background alone inside
+    pub pass: bool,
+}
+
+/// Résultats d'un état / State results
+#[derive(Serialize, Clone, Debug)]
+pub struct StateResult {
+    pub name: String,
+
+    /// Couleurs composées / Composited colors
+    pub foreground_hex: String,
+    pub background_hex: String,
+
+    /// Contraste du texte / Text contrast
+    pub contrast_ratio_rounded: f32,
+    pub apca_lc_rounded: f64,
+
+    /// Critères du profil actif pour le texte / Active profile criteria for the text
+    pub compliance: Vec<CriterionResult>,
+
+    /// Contraste non textuel de l'indicateur / Non-text contrast of the indicator
+    pub indicator: Option<IndicatorResult>,
+
+    /// Composant inactif : exempté de 1.4.3 et 1.4.11 / Inactive component: exempt from 1.4.3 and 1.4.11
+    pub exempt: bool,
+}
+
+/// Changement entre les états sans et avec focus (2.4.13)
+/// Change between the unfocused and focused states (2.4.13)
+#[derive(Serialize, Clone, Debug)]
+pub struct FocusChange {
+    /// Ratio entre les mêmes pixels, avec et sans focus
+    /// Ratio between the same pixels, focused and unfocused
+    pub contrast_ratio_rounded: f32,
+    pub pass: bool,
+}
+
+/// Matrice complète, exportable en JSON / Complete matrix, exportable as JSON
+#[derive(Serialize, Clone, Debug)]
+pub struct StateMatrix {
+    /// Couleur de page sous les composants / Page color under the components
+    pub page_hex: String,
+
+    pub states: Vec<StateResult>,
+
+    /// None sans états « default » et « focus » / None without "default" and "focus" states
+    pub focus_change: Option<FocusChange>,
+}
+
+/// Erreur de l'espace de travail / Workspace error
+#[derive(Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", content = "value", rename_all = "kebab-case")]
+pub enum StateError {
+    /// Couleur invalide / Invalid color
+    InvalidColor(ParseError),
+    /// État inconnu / Unknown state
+    UnknownState(String),
+    /// Écriture impossible / Write failure
+    Io(String),
+}
+
+// =============================================================================
+// FONCTIONS DE CALCUL
+// CALCULATION FUNCTIONS
+// =============================================================================
+
+/// Couleur sRGB 8 bits au format hexadécimal / 8-bit sRGB color in hexadecimal format
+fn hex(srgb: [f64; 3]) -> String {
+    let (r, g, b) = conversion::srgb_to_rgb8(srgb);
+    format_hex_color(r, g, b)
+}
+
+/// Analyse une saisie CSS Color 4 en couleur d'état / Parses a CSS Color 4 entry into a state color
+pub fn parse_state_color(value: &str) -> Result<StateColor, StateError> {
+    let parsed = css_color::parse(value).map_err(StateError::InvalidColor)?;
+    Ok(StateColor { color: parsed.color, alpha: parsed.alpha })
+}
+
+/// Calcule la matrice des états avec la page, le profil et le texte du store
+/// Calculates the state matrix with the store page, profile and text
+pub fn matrix(states: &[ComponentState], store: &ResultStore) -> StateMatrix {
+    let page = conversion::rgb8_to_srgb(store.page_rgb);
+    let large_text = store.text_spec.as_ref().map(|t| t.large);
+
+    // Couleurs composées : arrière-plan sur la page, texte sur l'arrière-plan,
+    // indicateur sur la page ou sur l'arrière-plan selon sa position
+    // Composited colors: background over the page, text over the background,
+    // indicator over the page or over the background depending on its placement
+    let underneath = |state: &ComponentState, background: [f64; 3]| match state.placement {
+        IndicatorPlacement::Outside => page,
+        IndicatorPlacement::Inside => background,
+    };
+    let composited = |state: &ComponentState| {
+        let background = color::composite(state.background.color.to_srgb(), state.background.alpha, page);
+        let foreground = color::composite(state.foreground.color.to_srgb(), state.foreground.alpha, background);
+        let indicator = state.indicator.map(|i| color::composite(i.color.to_srgb(), i.alpha, underneath(state, background)));
+        (foreground, background, indicator)
+    };
+
+    let results = states
+        .iter()
+        .map(|state| {
+            let (foreground, background, indicator) = composited(state);
+            let ratio = color::contrast_ratio(foreground, background);
+            let lc = apca::contrast(foreground, background);
+
+            StateResult {
+                name: state.name.clone(),
+                foreground_hex: hex(foreground),
+                background_hex: hex(background),
+                contrast_ratio_rounded: color::round_ratio(ratio),
+                apca_lc_rounded: (lc * config::APCA_ROUNDING_FACTOR).round() / config::APCA_ROUNDING_FACTOR,
+                compliance: compliance::evaluate(&store.profile.criteria, ratio, large_text),
+                indicator: indicator.map(|indicator| {
+                    let vs_background = color::contrast_ratio(indicator, background);
+                    let vs_page = color::contrast_ratio(indicator, page);
+                    IndicatorResult {
+                        hex: hex(indicator),
+                        vs_background_rounded: color::round_ratio(vs_background),
+                        vs_page_rounded: color::round_ratio(vs_page),
+                        pass: match state.placement {
+                            IndicatorPlacement::Outside => vs_background.min(vs_page),
+                            IndicatorPlacement::Inside => vs_background,
+                        } >= NON_TEXT_THRESHOLD,
+                    }
+                }),
+                exempt: state.is_named(DISABLED_STATE),
+            }
+        })
+        .collect();
+
+    // 2.4.13 : les pixels de l'indicateur (ou de l'arrière-plan sans indicateur)
+    // sont comparés à la couleur qu'ils remplacent dans l'état sans focus : la page
+    // pour un contour extérieur, l'arrière-plan pour un indicateur intérieur, ou
+    // l'indicateur sans focus placé au même endroit
+    // 2.4.13: the indicator pixels (or the background without an indicator) are
+    // compared with the color they replace in the unfocused state: the page for an
+    // outside outline, the background for an inside indicator, or the unfocused
+    // indicator at the same place
+    let find = |name: &str| states.iter().find(|s| s.is_named(name));
+    let focus_change = find(UNFOCUSED_STATE).zip(find(FOCUSED_STATE)).map(|(unfocused, focused)| {
+        let (_, unfocused_bg, unfocused_indicator) = composited(unfocused);
+        let (_, focused_bg, focused_indicator) = composited(focused);
+        let (changed, replaced) = match focused_indicator {
+            Some(indicator) => {
+                let same_place = unfocused.placement == focused.placement;
+                let replaced = unfocused_indicator.filter(|_| same_place).unwrap_or(underneath(focused, unfocused_bg));
+                (indicator, replaced)
+            }
+            None => (focused_bg, unfocused_bg),
+        };
+        let ratio = color::contrast_ratio(changed, replaced);
+        FocusChange { contrast_ratio_rounded: color::round_ratio(ratio), pass: ratio >= NON_TEXT_THRESHOLD }
+    });
+
+    StateMatrix { page_hex: store.page_hex.clone(), states: results, focus_change }
+}
+
+// =============================================================================
+// COMMANDES TAURI
+// TAURI COMMANDS
+// =============================================================================
+
+/// Ajoute ou remplace un état du composant
+/// Adds or replaces a component state
+///
+/// # Arguments
+/// * `name` - Nom de l'état / State name
+/// * `foreground`, `background` - Saisies CSS Color 4 ; les couleurs composées du store si absentes
+///   CSS Color 4 entries; the store composited colors if absent
+/// * `indicator` - Saisie CSS Color 4 optionnelle / Optional CSS Color 4 entry
+/// * `placement` - Position de l'indicateur, à l'extérieur par défaut / Indicator placement, outside by default
+#[tauri::command]
+pub fn set_component_state(
+    state: tauri::State<AppState>,
+    name: String,
+    foreground: Option<String>,
+    background: Option<String>,
+    indicator: Option<String>,
+    placement: Option<IndicatorPlacement>,
+) -> Result<StateMatrix, StateError> {
+    let store = state.store.lock().unwrap();
+    let from_store = |srgb: [f64; 3]| StateColor { color: PreciseColor::from_srgb(srgb), alpha: 1.0 };
+
+    let component_state = ComponentState {
+        name: name.trim().to_string(),
+        foreground: match foreground {
+            Some(value) => parse_state_color(&value)?,
+            None => from_store(store.foreground_composited_srgb),
+        },
+        background: match background {
+            Some(value) => parse_state_color(&value)?,
+            None => from_store(store.background_composited_srgb),
+        },
+        indicator: indicator.as_deref().map(parse_state_color).transpose()?,
+        placement: placement.unwrap_or_default(),
+    };
+
+    let mut states = state.component_states.lock().unwrap();
+    match states.iter_mut().find(|s| s.is_named(&component_state.name)) {
+        Some(existing) => *existing = component_state,
+        None => states.push(component_state),
+    }
+    Ok(matrix(&states, &store))
+}
+
+/// Supprime un état du composant / Removes a component state
+#[tauri::command]
+pub fn remove_component_state(state: tauri::State<AppState>, name: String) -> Result<StateMatrix, StateError> {
+    let store = state.store.lock().unwrap();
+    let mut states = state.component_states.lock().unwrap();
+    let count = states.len();
+    states.retain(|s| !s.is_named(&name));
+    if states.len() == count {
+        return Err(StateError::UnknownState(name));
+    }
+    Ok(matrix(&states, &store))
+}
+
+/// Retourne la matrice des états / Returns the state matrix
+#[tauri::command]
+pub fn get_state_matrix(state: tauri::State<AppState>) -> StateMatrix {
+    let store = state.store.lock().unwrap();
+    let states = state.component_states.lock().unwrap();
+    matrix(&states, &store)
+}
+
+/// Exporte la matrice en JSON, et l'écrit dans un fichier si un chemin est donné
+/// Exports the matrix as JSON, and writes it to a file if a path is given
+#[tauri::command]
+pub fn export_state_matrix(state: tauri::State<AppState>, path: Option<String>) -> Result<String, StateError> {
+    let json = serde_json::to_string_pretty(&get_state_matrix(state)).map_err(|e| StateError::Io(e.to_string()))?;
+    if let Some(path) = path {
+        std::fs::write(&path, &json).map_err(|e| StateError::Io(format!("{}: {}", path, e)))?;
+    }
+    Ok(json)
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn component_state(name: &str, foreground: &str, background: &str, indicator: Option<&str>) -> ComponentState {
+        ComponentState {
+            name: name.to_string(),
+            foreground: parse_state_color(foreground).unwrap(),
+            background: parse_state_color(background).unwrap(),
+            indicator: indicator.map(|i| parse_state_color(i).unwrap()),
+            placement: IndicatorPlacement::Inside,
+        }
+    }
+
+    #[test]
+    fn test_button_states() {
+        let store = ResultStore::default();
+        let states = [
+            component_state("default", "white", "#0055cc", None),
+            component_state("focus", "white", "#0055cc", Some("#000")),
+            component_state("disabled", "#999", "#ddd", None),
+        ];
+        let matrix = matrix(&states, &store);
+
+        assert!(matrix.states[0].compliance[0].pass);
+        assert!(matrix.states[0].indicator.is_none());
+
+        // Contour noir : 3.2:1 face au bleu, 21:1 face à la page blanche
+        // Black outline: 3.2:1 against the blue, 21:1 against the white page
+        let indicator = matrix.states[1].indicator.as_ref().unwrap();
+        assert_eq!(indicator.vs_page_rounded, 21.0);
+        assert_eq!(indicator.vs_background_rounded, 3.2);
+        assert!(indicator.pass);
+
+        assert!(matrix.states[2].exempt);
+        assert!(!matrix.states[2].compliance[0].pass);
+
+        // Le contour noir remplace le bleu au focus / The black outline replaces the blue on focus
+        let change = matrix.focus_change.unwrap();
+        assert!(change.pass);
+    }
+
+    #[test]
+    fn test_outside_outline_replaces_page() {
+        // Un contour extérieur gris clair remplace la page blanche, pas le fond bleu :
+        // il ressort du bleu mais pas de la page qu'il recouvre
+        // A light grey outside outline replaces the white page, not the blue fill:
+        // it stands out from the blue but not from the page it covers
+        let store = ResultStore::default();
+        let mut focused = component_state(" Focus ", "white", "#0055cc", Some("#ccc"));
+        focused.placement = IndicatorPlacement::Outside;
+        let states = [component_state("DEFAULT", "white", "#0055cc", None), focused];
+        let matrix = matrix(&states, &store);
+        assert!(matrix.states[1].indicator.as_ref().unwrap().vs_background_rounded > 3.0);
+        assert!(!matrix.focus_change.unwrap().pass);
+
+        // Un contour translucide est composé sur la page / A translucent outline is composited over the page
+        let mut translucent = component_state("focus", "white", "#0055cc", Some("rgb(0 0 0 / 50%)"));
+        translucent.placement = IndicatorPlacement::Outside;
+        let matrix = super::matrix(&[translucent], &store);
+        assert_eq!(matrix.states[0].indicator.as_ref().unwrap().hex, "#808080");
+    }
+
+    #[test]
+    fn test_inside_indicator_ignores_page() {
+        // Bordure intérieure gris clair sur le bleu : elle ne touche pas la page blanche
+        // Light grey inside border over the blue: it does not touch the white page
+        let store = ResultStore::default();
+        let inside = component_state("focus", "white", "#0055cc", Some("#ccc"));
+        let matrix = matrix(std::slice::from_ref(&inside), &store);
+        let indicator = matrix.states[0].indicator.as_ref().unwrap();
+        assert!(indicator.vs_background_rounded > 3.0 && indicator.vs_page_rounded < 3.0);
+        assert!(indicator.pass);
+
+        // Le même gris à l'extérieur doit aussi ressortir de la page
+        // The same grey outside must also stand out from the page
+        let outside = ComponentState { placement: IndicatorPlacement::Outside, ..inside };
+        assert!(!super::matrix(&[outside], &store).states[0].indicator.as_ref().unwrap().pass);
+    }
+
+    #[test]
+    fn test_invalid_color() {
+        assert!(matches!(parse_state_color("#12"), Err(StateError::InvalidColor(_))));
+    }
+}
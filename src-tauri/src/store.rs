@@ -7,8 +7,21 @@ use std::sync::Mutex;
 use serde::{Serialize, Deserialize};
 use crate::config;
 use crate::picker;
-use crate::color;
+use crate::color::{self, ColorDifference, ReferenceDifference};
 use crate::color_names;
+use crate::css_color;
+use crate::dictionaries::ColorDictionary;
+use crate::icc::OutputProfile;
+use crate::apca;
+use crate::apca_fonts::FontReport;
+use crate::compliance::{CriterionResult, LinkContrast};
+use crate::profiles::Profile;
+use crate::region::RegionImage;
+use crate::states::ComponentState;
+use crate::surfaces::{Surface, SurfaceReport};
+use crate::tokens::Token;
+use crate::text_spec::{FontSizeUnit, TextSpec, TextSpecError};
+use crate::conversion::{ColorInput, ColorModels, PreciseColor};
 use bigcolor::BigColor;
 
 // =============================================================================
@@ -58,6 +71,100 @@ pub struct ResultStore {
     /// If the colour is dark
     pub background_is_dark: bool,
 
+    /// Couleur de premier plan dans tous les modèles (HSL, Lab, OKLCH, CMJN…)
+    /// Foreground color in every model (HSL, Lab, OKLCH, CMYK…)
+    pub foreground_models: ColorModels,
+
+    /// Couleur d'arrière-plan dans tous les modèles (HSL, Lab, OKLCH, CMJN…)
+    /// Background color in every model (HSL, Lab, OKLCH, CMYK…)
+    pub background_models: ColorModels,
+
+    /// Valeur exacte du premier plan, étiquetée avec son espace (sRGB, Display P3…)
+    /// Exact foreground value, tagged with its space (sRGB, Display P3…)
+    pub foreground_precise: PreciseColor,
+
+    /// Valeur exacte de l'arrière-plan, étiquetée avec son espace (sRGB, Display P3…)
+    /// Exact background value, tagged with its space (sRGB, Display P3…)
+    pub background_precise: PreciseColor,
+
+    /// Si le premier plan sort du gamut sRGB
+    /// If the foreground is outside the sRGB gamut
+    pub foreground_out_of_srgb: bool,
+
+    /// Si l'arrière-plan sort du gamut sRGB
+    /// If the background is outside the sRGB gamut
+    pub background_out_of_srgb: bool,
+
+    /// Texte courant autour d'un lien (le premier plan), None si la case est vide
+    /// Body text around a link (the foreground), None if the slot is empty
+    pub adjacent_rgb: Option<(u8, u8, u8)>,
+
+    /// Texte adjacent au format hexadécimal
+    /// Adjacent text in hexadecimal format
+    pub adjacent_hex: Option<String>,
+
+    /// Valeur exacte du texte adjacent (toujours opaque)
+    /// Exact value of the adjacent text (always opaque)
+    pub adjacent_precise: Option<PreciseColor>,
+
+    /// Arrière-plans supplémentaires sous le même premier plan
+    /// Additional backgrounds under the same foreground
+    pub surfaces: Vec<Surface>,
+
+    /// Couleur de référence (charte graphique), None si la case est vide
+    /// Reference colour (brand spec), None if the slot is empty
+    pub reference_hex: Option<String>,
+
+    /// Valeur exacte de la couleur de référence (toujours opaque)
+    /// Exact value of the reference colour (always opaque)
+    pub reference_precise: Option<PreciseColor>,
+
+    /// Opacité de la couleur de premier plan (0.0 - 1.0)
+    /// Foreground color opacity (0.0 - 1.0)
+    pub foreground_alpha: f64,
+
+    /// Opacité de la couleur d'arrière-plan (0.0 - 1.0)
+    /// Background color opacity (0.0 - 1.0)
+    pub background_alpha: f64,
+
+    /// Couleur de page sous un arrière-plan translucide (r, g, b)
+    /// Page color under a translucent background (r, g, b)
+    pub page_rgb: (u8, u8, u8),
+
+    /// Couleur de page au format hexadécimal
+    /// Page color in hexadecimal format
+    pub page_hex: String,
+
+    /// Premier plan composé sur l'arrière-plan (r, g, b)
+    /// Foreground composited over the background (r, g, b)
+    pub foreground_composited_rgb: (u8, u8, u8),
+
+    /// Premier plan composé au format hexadécimal
+    /// Composited foreground in hexadecimal format
+    pub foreground_composited_hex: String,
+
+    /// Arrière-plan composé sur la couleur de page (r, g, b)
+    /// Background composited over the page color (r, g, b)
+    pub background_composited_rgb: (u8, u8, u8),
+
+    /// Arrière-plan composé au format hexadécimal
+    /// Composited background in hexadecimal format
+    pub background_composited_hex: String,
+
+    /// Premier plan composé en sRGB étendu (non borné), utilisé pour le contraste
+    /// Composited foreground in extended sRGB (unclamped), used for contrast
+    #[serde(skip)]
+    pub foreground_composited_srgb: [f64; 3],
+
+    /// Arrière-plan composé en sRGB étendu (non borné), utilisé pour le contraste
+    /// Composited background in extended sRGB (unclamped), used for contrast
+    #[serde(skip)]
+    pub background_composited_srgb: [f64; 3],
+
+    /// Si le résultat dépend de ce qui se trouve sous l'arrière-plan translucide
+    /// If the result depends on what is underneath the translucent background
+    pub depends_on_backdrop: bool,
+
     /// Mode continue activé
     /// Continue mode enabled
     pub continue_mode: bool,
@@ -65,22 +172,98 @@ pub struct ResultStore {
     // Contast Ratio value, not rounded
     // Valeur du Ratio de Contraste, non arrondi
     #[serde(skip)]
-    pub contrast_ratio_raw: f32,
+    pub contrast_ratio_raw: f64,
 
     // Contast Ratio value, rounded
     // Valeur du Ratio de Contraste, arrondi
     pub contrast_ratio_rounded: f32,
+
+    /// Contraste APCA (Lc) du premier plan sur l'arrière-plan, non arrondi
+    /// APCA contrast (Lc) of foreground on background, not rounded
+    #[serde(skip)]
+    pub apca_lc_raw: f64,
+
+    /// Contraste APCA (Lc) du premier plan sur l'arrière-plan, arrondi
+    /// APCA contrast (Lc) of foreground on background, rounded
+    pub apca_lc_rounded: f64,
+
+    /// Contraste APCA (Lc) de l'arrière-plan sur le premier plan, non arrondi
+    /// APCA contrast (Lc) of background on foreground, not rounded
+    #[serde(skip)]
+    pub apca_lc_reverse_raw: f64,
+
+    /// Contraste APCA (Lc) de l'arrière-plan sur le premier plan, arrondi
+    /// APCA contrast (Lc) of background on foreground, rounded
+    pub apca_lc_reverse_rounded: f64,
+
+    /// Polarité du premier plan sur l'arrière-plan
+    /// Polarity of foreground on background
+    pub apca_polarity: apca::Polarity,
+
+    /// Ratio de contraste après gamut mapping, non arrondi
+    /// Contrast ratio after gamut mapping, not rounded
+    #[serde(skip)]
+    pub contrast_ratio_mapped_raw: f64,
+
+    /// Ratio de contraste après gamut mapping, arrondi
+    /// Contrast ratio after gamut mapping, rounded
+    pub contrast_ratio_mapped_rounded: f32,
+
+    /// Contraste APCA (Lc) après gamut mapping, non arrondi
+    /// APCA contrast (Lc) after gamut mapping, not rounded
+    #[serde(skip)]
+    pub apca_lc_mapped_raw: f64,
+
+    /// Contraste APCA (Lc) après gamut mapping, arrondi
+    /// APCA contrast (Lc) after gamut mapping, rounded
+    pub apca_lc_mapped_rounded: f64,
+
+    /// Tailles de police minimales APCA et usages permis
+    /// APCA minimum font sizes and allowed use cases
+    pub apca_fonts: FontReport,
+
+    /// Taille et graisse du texte testé, None si inconnues
+    /// Size and weight of the tested text, None if unknown
+    pub text_spec: Option<TextSpec>,
+
+    /// Résultat G183 / F73 du lien (premier plan) face au texte adjacent, None sans texte adjacent
+    /// G183 / F73 result of the link (foreground) against the adjacent text, None without adjacent text
+    pub link_contrast: Option<LinkContrast>,
+
+    /// Résultats du premier plan sur l'arrière-plan et chaque surface, None sans surface
+    /// Foreground results over the background and each surface, None without surfaces
+    pub surface_report: Option<SurfaceReport>,
+
+    /// Différences entre le premier plan et l'arrière-plan (valeurs exactes)
+    /// Differences between the foreground and the background (exact values)
+    pub color_difference: ColorDifference,
+
+    /// Différence de chaque case avec la couleur de référence, vide sans référence
+    /// Difference of each slot with the reference colour, empty without a reference
+    pub reference_differences: Vec<ReferenceDifference>,
+
+    /// Profil de norme actif (critères, seuils et libellés) - ignoré par la sérialisation
+    /// Active standards profile (criteria, thresholds and labels) - ignored by serialization
+    #[serde(skip)]
+    pub profile: Profile,
+
+    /// Identifiant du profil actif, seul envoyé au frontend
+    /// Active profile id, the only part sent to the frontend
+    pub profile_id: String,
+
+    /// Résultat de chaque critère, évalué avec le ratio non arrondi
+    /// Result of each criterion, evaluated with the unrounded ratio
+    pub compliance: Vec<CriterionResult>,
 }
 
 impl Default for ResultStore {
     fn default() -> Self {
         let (fr, fg, fb) = config::DEFAULT_FOREGROUND_RGB;
         let (br, bg, bb) = config::DEFAULT_BACKGROUND_RGB;
+        let (pr, pg, pb) = config::DEFAULT_PAGE_RGB;
         let fc = BigColor::from_rgb(fr, fg, fb, 1.0);
         let bc = BigColor::from_rgb(br, bg, bb, 1.0);
-        let contrast_ratio = fc.get_contrast_ratio(&bc);
-        let contrast_ratio_rounded = (contrast_ratio * config::ROUNDING_FACTOR).round() / config::ROUNDING_FACTOR;
-        Self {
+        let mut store = Self {
             // Plateforme détectée à la compilation
             // Platform detected at compile time
             #[cfg(target_os = "macos")]
@@ -99,13 +282,70 @@ impl Default for ResultStore {
             background_rgb: config::DEFAULT_BACKGROUND_RGB,
             background_hex: format!("#{:02X}{:02X}{:02X}", br, bg, bb),
             background_is_dark: false,
+            foreground_models: ColorModels::from_rgb8(config::DEFAULT_FOREGROUND_RGB),
+            background_models: ColorModels::from_rgb8(config::DEFAULT_BACKGROUND_RGB),
+            foreground_precise: PreciseColor::from_rgb8(config::DEFAULT_FOREGROUND_RGB),
+            background_precise: PreciseColor::from_rgb8(config::DEFAULT_BACKGROUND_RGB),
+            foreground_out_of_srgb: false,
+            background_out_of_srgb: false,
+            adjacent_rgb: None,
+            adjacent_hex: None,
+            adjacent_precise: None,
+            surfaces: Vec::new(),
+            reference_hex: None,
+            reference_precise: None,
+            foreground_alpha: 1.0,
+            background_alpha: 1.0,
+            page_rgb: config::DEFAULT_PAGE_RGB,
+            page_hex: format!("#{:02X}{:02X}{:02X}", pr, pg, pb),
+            foreground_composited_rgb: config::DEFAULT_FOREGROUND_RGB,
+            foreground_composited_hex: String::new(),
+            background_composited_rgb: config::DEFAULT_BACKGROUND_RGB,
+            background_composited_hex: String::new(),
+            foreground_composited_srgb: [0.0; 3],
+            background_composited_srgb: [0.0; 3],
+            depends_on_backdrop: false,
             continue_mode: false,
-            contrast_ratio_raw: contrast_ratio,
-            contrast_ratio_rounded: contrast_ratio_rounded,
-        }
+            contrast_ratio_raw: 0.0,
+            contrast_ratio_rounded: 0.0,
+            apca_lc_raw: 0.0,
+            apca_lc_rounded: 0.0,
+            apca_lc_reverse_raw: 0.0,
+            apca_lc_reverse_rounded: 0.0,
+            apca_polarity: apca::Polarity::DarkOnLight,
+            contrast_ratio_mapped_raw: 0.0,
+            contrast_ratio_mapped_rounded: 0.0,
+            apca_lc_mapped_raw: 0.0,
+            apca_lc_mapped_rounded: 0.0,
+            apca_fonts: FontReport::default(),
+            link_contrast: None,
+            surface_report: None,
+            color_difference: ColorDifference::default(),
+            reference_differences: Vec::new(),
+            text_spec: None,
+            profile: Profile::default(),
+            profile_id: String::new(),
+            compliance: Vec::new(),
+        };
+
+        // Calcule les valeurs de contraste initiales
+        // Calculate initial contrast values
+        color::update_contrast(&mut store);
+        store
     }
 }
 
+/// Store de test avec un premier plan et un arrière-plan opaques
+/// Test store with an opaque foreground and background
+#[cfg(test)]
+pub fn store_with(foreground: (u8, u8, u8), background: (u8, u8, u8)) -> ResultStore {
+    let mut store = ResultStore::default();
+    color::set_color(&mut store, "foreground", PreciseColor::from_rgb8(foreground), None);
+    color::set_color(&mut store, "background", PreciseColor::from_rgb8(background), None);
+    color::update_contrast(&mut store);
+    store
+}
+
 /// Modèle de copie (template + raccourci)
 /// Copy template (template + shortcut)
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -121,6 +361,12 @@ pub struct AppState {
     pub store: Mutex<ResultStore>,
     pub locale: Mutex<String>,
     pub templates: Mutex<Vec<CopyTemplate>>,
+    pub profiles: Mutex<Vec<Profile>>,
+    pub component_states: Mutex<Vec<ComponentState>>,
+    pub tokens: Mutex<Vec<Token>>,
+    pub dictionaries: Mutex<Vec<ColorDictionary>>,
+    pub output_profile: Mutex<Option<OutputProfile>>,
+    pub region_image: Mutex<Option<RegionImage>>,
 }
 
 // =============================================================================
@@ -139,11 +385,27 @@ pub fn get_store(state: tauri::State<AppState>) -> ResultStore {
 
 /// Lance le color picker et met à jour le store automatiquement
 /// Launches the color picker and automatically updates the store
+///
+/// # Arguments
+/// * `fg` - Sélectionne le premier plan (sinon l'arrière-plan) / Picks the foreground (otherwise the background)
+/// * `adjacent` - Sélectionne le texte adjacent à la place / Picks the adjacent text instead
+/// * `surface` - Ajoute l'arrière-plan choisi aux surfaces / Adds the picked background to the surfaces
 #[tauri::command]
-pub fn pick_color(app: AppHandle, state: tauri::State<AppState>, fg: bool) {
-    // Lance le picker natif
-    // Launch the native picker
-    let result = picker::run(fg);
+pub fn pick_color(app: AppHandle, state: tauri::State<AppState>, fg: bool, adjacent: Option<bool>, surface: Option<bool>) {
+    let adjacent = adjacent.unwrap_or(false);
+    let surface = surface.unwrap_or(false) && !adjacent;
+
+    // Lance le picker natif (le texte adjacent se sélectionne comme un premier plan)
+    // Launch the native picker (the adjacent text is picked like a foreground)
+    let mut result = picker::run((fg || adjacent) && !surface);
+
+    // Dirige la couleur choisie vers la case du texte adjacent
+    // Route the picked color to the adjacent text slot
+    let adjacent_color = if adjacent { result.foreground.take() } else { None };
+
+    // Ajoute l'arrière-plan choisi aux surfaces, sans remplacer celui du store
+    // Add the picked background to the surfaces, without replacing the store one
+    let surface_color = if surface { result.background.take() } else { None };
 
     // Met à jour le store avec les couleurs sélectionnées
     // Update the store with selected colors
@@ -152,6 +414,13 @@ pub fn pick_color(app: AppHandle, state: tauri::State<AppState>, fg: bool) {
         // Lock the mutex
         let mut store = state.store.lock().unwrap();
 
+        if let Some(color) = adjacent_color {
+            color::set_color(&mut store, "adjacent", color, None);
+        }
+        if let Some(color) = surface_color {
+            color::set_color(&mut store, "surface", color, None);
+        }
+
         // Met à jour les couleurs à partir du résultat du picker
         // Update colors from picker result
         color::update_results_from_picker(&mut store, &result);
@@ -168,56 +437,210 @@ pub fn pick_color(app: AppHandle, state: tauri::State<AppState>, fg: bool) {
 
 /// Met à jour une valeur du store manuellement
 /// Manually updates a store value
+///
+/// # Arguments
+/// * `key` - "foreground", "background", "page", "adjacent", "surface" ou "reference" / "foreground", "background", "page", "adjacent", "surface" or "reference"
+/// * `r`, `g`, `b` - Composantes RGB / RGB components
+/// * `a` - Opacité optionnelle (0.0 - 1.0), conservée si absente / Optional opacity (0.0 - 1.0), kept if absent
+/// * `color` - Couleur dans un autre modèle, prioritaire sur `r`, `g`, `b` / Color in another model, takes precedence over `r`, `g`, `b`
 #[tauri::command]
-pub fn update_store(app: AppHandle, state: tauri::State<AppState>, key: String, r: u8, g: u8, b: u8) {
+#[allow(clippy::too_many_arguments)]
+pub fn update_store(
+    app: AppHandle,
+    state: tauri::State<AppState>,
+    key: String,
+    r: Option<u8>,
+    g: Option<u8>,
+    b: Option<u8>,
+    a: Option<f64>,
+    color: Option<ColorInput>,
+) {
+    // Détermine la couleur à partir du modèle saisi, sans arrondi
+    // Determine the color from the entered model, without rounding
+    let precise = match (color, r, g, b) {
+        (Some(input), _, _, _) => PreciseColor::from_srgb(input.to_srgb()),
+        (None, Some(r), Some(g), Some(b)) => PreciseColor::from_rgb8((r, g, b)),
+        _ => return, // Couleur manquante / Missing color
+    };
+
     {
         let mut store = state.store.lock().unwrap();
 
         // Met à jour la clé correspondante
         // Update the corresponding key
-        match key.as_str() {
-            "foreground" => {
-                store.foreground_rgb = (r, g, b);
-                store.foreground_hex = format!("#{:02X}{:02X}{:02X}", r, g, b);
-                store.foreground = BigColor::from_rgb(r, g, b, 1.0);
-                store.foreground_is_dark = store.foreground.is_dark();
-            }
-            "background" => {
-                store.background_rgb = (r, g, b);
-                store.background_hex = format!("#{:02X}{:02X}{:02X}", r, g, b);
-                store.background = BigColor::from_rgb(r, g, b, 1.0);
-                store.background_is_dark = store.background.is_dark();
-            }
-            _ => return, // Clé inconnue / Unknown key
+        if !color::set_color(&mut store, &key, precise, a) {
+            return; // Clé inconnue / Unknown key
+        }
+
+        // Recalcule les valeurs de contraste
+        // Recalculate contrast values
+        color::update_contrast(&mut store);
+
+        // Émet l'événement
+        // Emit the event
+        let _ = app.emit("store-updated", store.clone());
+    }
+}
+
+/// Met à jour une couleur du store à partir d'une saisie libre CSS Color 4
+/// Updates a store color from CSS Color 4 free text entry
+///
+/// # Arguments
+/// * `key` - "foreground", "background", "page", "adjacent", "surface" (ajoutée à la liste) ou "reference"
+///   "foreground", "background", "page", "adjacent", "surface" (appended to the list) or "reference"
+/// * `value` - Saisie libre, par ex. `#0a3`, `oklch(62% 0.2 29)` / Free text, e.g. `#0a3`, `oklch(62% 0.2 29)`
+///
+/// # Returns
+/// L'erreur d'analyse avec la position de la partie invalide, ou `unknown-key`
+/// si la clé ne désigne aucune couleur du store (rien n'est modifié)
+/// The parse error with the position of the invalid part, or `unknown-key`
+/// if the key names no store color (nothing is changed)
+#[tauri::command]
+pub fn set_color_from_string(
+    app: AppHandle,
+    state: tauri::State<AppState>,
+    key: String,
+    value: String,
+) -> Result<(), css_color::ParseError> {
+    let parsed = css_color::parse(&value)?;
+
+    {
+        let mut store = state.store.lock().unwrap();
+
+        // Met à jour la clé correspondante avec l'opacité saisie
+        // Update the corresponding key with the entered opacity
+        if !color::set_color(&mut store, &key, parsed.color, Some(parsed.alpha)) {
+            return Err(css_color::ParseError::new(
+                css_color::ParseErrorKind::UnknownKey,
+                format!("Unknown colour key \"{}\"", key),
+                0,
+                0,
+            ));
         }
 
-        // Recalcule le ratio de contraste
-        // Recalculate contrast ratio
-        store.contrast_ratio_raw = store.foreground.get_contrast_ratio(&store.background);
-        store.contrast_ratio_rounded = (store.contrast_ratio_raw * config::ROUNDING_FACTOR).round() / config::ROUNDING_FACTOR;
+        // Recalcule les valeurs de contraste
+        // Recalculate contrast values
+        color::update_contrast(&mut store);
+
+        // Émet l'événement
+        // Emit the event
+        let _ = app.emit("store-updated", store.clone());
+    }
+
+    Ok(())
+}
+
+/// Définit la taille et la graisse du texte testé, ou les efface
+/// Sets the size and weight of the tested text, or clears them
+///
+/// # Arguments
+/// * `size`, `unit` - Taille et unité (px, pt, rem) / Size and unit (px, pt, rem)
+/// * `weight` - Graisse optionnelle (400 par défaut) / Optional weight (400 by default)
+/// * `css` - Forme abrégée CSS, par ex. `600 15px`, prioritaire sur `size` / CSS shorthand, e.g. `600 15px`, takes precedence over `size`
+///
+/// Sans `css` ni `size`, la spécification est effacée et tous les critères s'appliquent.
+/// Without `css` or `size`, the spec is cleared and every criterion applies.
+#[tauri::command]
+pub fn set_text_spec(
+    app: AppHandle,
+    state: tauri::State<AppState>,
+    size: Option<f64>,
+    unit: Option<FontSizeUnit>,
+    weight: Option<u16>,
+    css: Option<String>,
+) -> Result<(), TextSpecError> {
+    let spec = match (css, size) {
+        (Some(css), _) if !css.trim().is_empty() => Some(TextSpec::parse(&css)?),
+        (_, Some(size)) => Some(TextSpec::new(size, unit.unwrap_or(FontSizeUnit::Px), weight)?),
+        _ => None,
+    };
+
+    {
+        let mut store = state.store.lock().unwrap();
+        store.text_spec = spec;
+
+        // Réévalue les critères applicables
+        // Re-evaluate the applicable criteria
+        color::update_contrast(&mut store);
 
         // Émet l'événement
         // Emit the event
         let _ = app.emit("store-updated", store.clone());
     }
+
+    Ok(())
+}
+
+/// Retourne le nom de couleur CSS exact pour une valeur RGB, sinon un nom descriptif
+/// Returns the exact CSS color name for a given RGB value, otherwise a descriptive name
+///
+/// # Arguments
+/// * `locale` - Locale du nom descriptif, celle de l'application si absente
+///   Locale of the descriptive name, the application one if absent
+#[tauri::command]
+pub fn get_color_name(state: tauri::State<AppState>, r: u8, g: u8, b: u8, locale: Option<String>) -> String {
+    let locale = locale.unwrap_or_else(|| state.locale.lock().unwrap().clone());
+    color_names::color_name(r, g, b, &locale)
+}
+
+/// Vide la case du texte adjacent
+/// Clears the adjacent text slot
+#[tauri::command]
+pub fn clear_adjacent_text(app: AppHandle, state: tauri::State<AppState>) {
+    {
+        let mut store = state.store.lock().unwrap();
+        store.adjacent_rgb = None;
+        store.adjacent_hex = None;
+        store.adjacent_precise = None;
+        color::update_contrast(&mut store);
+        let _ = app.emit("store-updated", store.clone());
+    }
 }
 
-/// Retourne le nom de couleur CSS exact pour une valeur RGB, ou vide
-/// Returns the exact CSS color name for a given RGB value, or empty
+/// Vide la case de la couleur de référence
+/// Clears the reference colour slot
 #[tauri::command]
-pub fn get_color_name(r: u8, g: u8, b: u8) -> String {
-    color_names::exact_color_name(r, g, b)
-        .unwrap_or("")
-        .to_string()
+pub fn clear_reference_color(app: AppHandle, state: tauri::State<AppState>) {
+    {
+        let mut store = state.store.lock().unwrap();
+        store.reference_hex = None;
+        store.reference_precise = None;
+        color::update_contrast(&mut store);
+        let _ = app.emit("store-updated", store.clone());
+    }
+}
+
+/// Calcule les différences de couleur entre deux saisies CSS Color 4
+/// Calculates the color differences between two CSS Color 4 entries
+///
+/// # Arguments
+/// * `reference` - Couleur de référence (CIE94 n'est pas symétrique) / Reference color (CIE94 is not symmetric)
+/// * `sample` - Couleur comparée / Compared color
+///
+/// L'opacité saisie est ignorée : les couleurs sont comparées telles quelles.
+/// The entered opacity is ignored: the colors are compared as they are.
+#[tauri::command]
+pub fn get_color_difference(reference: String, sample: String) -> Result<ColorDifference, css_color::ParseError> {
+    let reference = css_color::parse(&reference)?;
+    let sample = css_color::parse(&sample)?;
+    Ok(color::color_difference(reference.color.to_srgb(), sample.color.to_srgb()))
 }
 
-/// Efface le store
-/// Clears the store
+/// Efface les couleurs et résultats du store
+/// Clears the colors and results of the store
 #[tauri::command]
 pub fn clear_store(app: AppHandle, state: tauri::State<AppState>) {
     {
         let mut store = state.store.lock().unwrap();
-        *store = ResultStore::default();
+
+        // Le profil de norme et le texte testé sont des réglages : ils sont conservés
+        // The standards profile and the tested text are settings: they are kept
+        *store = ResultStore {
+            profile: std::mem::take(&mut store.profile),
+            text_spec: store.text_spec.take(),
+            ..ResultStore::default()
+        };
+        color::update_contrast(&mut store);
         let _ = app.emit("store-updated", store.clone());
     }
 }
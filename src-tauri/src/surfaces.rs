@@ -0,0 +1,168 @@
+// =============================================================================
+// surfaces.rs - Un premier plan sur plusieurs arrière-plans
+// surfaces.rs - One foreground over several backgrounds
+// =============================================================================
+//
+// Le texte passe souvent sur plusieurs surfaces : lignes alternées d'un
+// tableau, carte posée sur la page, en-tête fixe. Les surfaces s'ajoutent à
+// l'arrière-plan du store ; chacune reçoit ses résultats, et la plus faible
+// donne le ratio qui compte pour la conformité.
+// Text often runs over several surfaces: zebra table rows, a card on a page,
+// a sticky header. The surfaces are added to the store background; each one
+// gets its results, and the weakest gives the ratio that counts for compliance.
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+use crate::apca;
+use crate::color;
+use crate::compliance::{self, CriterionResult};
+use crate::config;
+use crate::conversion::{self, PreciseColor};
+use crate::picker::common::format_hex_color;
+use crate::store::{AppState, ResultStore};
+
+// =============================================================================
+// STRUCTURES
+// =============================================================================
+
+/// Arrière-plan supplémentaire / Additional background
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Surface {
+    /// Couleur au format hexadécimal / Color in hexadecimal format
+    pub hex: String,
+
+    /// Opacité (0.0 - 1.0) / Opacity (0.0 - 1.0)
+    pub alpha: f64,
+
+    /// Valeur exacte / Exact value
+    pub precise: PreciseColor,
+}
+
+/// Résultats du premier plan sur une surface / Foreground results over a surface
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SurfaceResult {
+    /// Surface composée sur la page / Surface composited over the page
+    pub background_hex: String,
+
+    pub contrast_ratio_rounded: f32,
+    pub apca_lc_rounded: f64,
+
+    /// Critères du profil actif / Active profile criteria
+    pub compliance: Vec<CriterionResult>,
+}
+
+/// Résultats par surface et pire cas / Per-surface results and worst case
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SurfaceReport {
+    /// L'arrière-plan du store, puis les surfaces dans l'ordre d'ajout
+    /// The store background, then the surfaces in the order they were added
+    pub results: Vec<SurfaceResult>,
+
+    /// Index du plus faible ratio dans `results` / Index of the lowest ratio in `results`
+    pub worst: usize,
+}
+
+// =============================================================================
+// FONCTIONS DE CALCUL
+// CALCULATION FUNCTIONS
+// =============================================================================
+
+/// Évalue le premier plan du store sur chaque surface, None sans surface supplémentaire
+/// Evaluates the store foreground over each surface, None without an additional surface
+pub fn evaluate(store: &ResultStore) -> Option<SurfaceReport> {
+    if store.surfaces.is_empty() {
+        return None;
+    }
+
+    let page = conversion::rgb8_to_srgb(store.page_rgb);
+    let large_text = store.text_spec.as_ref().map(|t| t.large);
+    let backgrounds = std::iter::once((store.background_precise, store.background_alpha))
+        .chain(store.surfaces.iter().map(|s| (s.precise, s.alpha)));
+
+    // Ratios non arrondis, pour choisir le pire cas sans effet d'arrondi
+    // Unrounded ratios, to choose the worst case without rounding effects
+    let (results, ratios): (Vec<SurfaceResult>, Vec<f64>) = backgrounds
+        .map(|(precise, alpha)| {
+            let background = color::composite(precise.to_srgb(), alpha, page);
+            let foreground = color::composite(store.foreground_precise.to_srgb(), store.foreground_alpha, background);
+            let ratio = color::contrast_ratio(foreground, background);
+            let lc = apca::contrast(foreground, background);
+            let (r, g, b) = conversion::srgb_to_rgb8(background);
+
+            let result = SurfaceResult {
+                background_hex: format_hex_color(r, g, b),
+                contrast_ratio_rounded: color::round_ratio(ratio),
+                apca_lc_rounded: (lc * config::APCA_ROUNDING_FACTOR).round() / config::APCA_ROUNDING_FACTOR,
+                compliance: compliance::evaluate(&store.profile.criteria, ratio, large_text),
+            };
+            (result, ratio)
+        })
+        .unzip();
+
+    let worst = ratios
+        .iter()
+        .enumerate()
+        .min_by(|a, b| a.1.total_cmp(b.1))
+        .map(|(index, _)| index)
+        .unwrap_or(0);
+
+    Some(SurfaceReport { results, worst })
+}
+
+// =============================================================================
+// COMMANDES TAURI
+// TAURI COMMANDS
+// =============================================================================
+
+/// Retire une surface / Removes a surface
+///
+/// # Arguments
+/// * `index` - Position dans la liste des surfaces (sans l'arrière-plan du store)
+///   Position in the surface list (without the store background)
+#[tauri::command]
+pub fn remove_surface(app: AppHandle, state: tauri::State<AppState>, index: usize) {
+    let mut store = state.store.lock().unwrap();
+    if index < store.surfaces.len() {
+        store.surfaces.remove(index);
+        color::update_contrast(&mut store);
+        let _ = app.emit("store-updated", store.clone());
+    }
+}
+
+/// Vide la liste des surfaces / Clears the surface list
+#[tauri::command]
+pub fn clear_surfaces(app: AppHandle, state: tauri::State<AppState>) {
+    let mut store = state.store.lock().unwrap();
+    store.surfaces.clear();
+    color::update_contrast(&mut store);
+    let _ = app.emit("store-updated", store.clone());
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zebra_rows() {
+        let mut store = ResultStore::default();
+        color::set_color(&mut store, "foreground", PreciseColor::from_rgb8((0x66, 0x66, 0x66)), None);
+        color::set_color(&mut store, "background", PreciseColor::from_rgb8((0xFF, 0xFF, 0xFF)), None);
+        assert!(evaluate(&store).is_none());
+
+        // Ligne grise, puis en-tête translucide / Grey row, then translucent header
+        color::set_color(&mut store, "surface", PreciseColor::from_rgb8((0xDD, 0xDD, 0xDD)), None);
+        color::set_color(&mut store, "surface", PreciseColor::from_rgb8((0x00, 0x00, 0x00)), Some(0.05));
+        color::update_contrast(&mut store);
+
+        let report = store.surface_report.as_ref().unwrap();
+        assert_eq!(report.results.len(), 3);
+        assert_eq!(report.results[0].contrast_ratio_rounded, 5.7);
+        assert_eq!(report.worst, 1);
+        assert!(!report.results[1].compliance[0].pass);
+        assert_eq!(report.results[2].background_hex, "#F2F2F2");
+    }
+}
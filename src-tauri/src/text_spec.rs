@@ -0,0 +1,250 @@
+// =============================================================================
+// text_spec.rs - Taille et graisse du texte testé
+// text_spec.rs - Size and weight of the tested text
+// =============================================================================
+//
+// Classe le texte en « grand » ou « normal » selon WCAG : au moins 18pt, ou
+// 14pt en gras (graisse 700 ou plus). Accepte une taille en px, pt ou rem
+// et la forme abrégée CSS `font`, par ex. `600 15px` ou `italic bold 14pt serif`.
+// Classifies text as "large" or "normal" per WCAG: at least 18pt, or 14pt
+// bold (weight 700 or more). Accepts a size in px, pt or rem and the CSS
+// `font` shorthand, e.g. `600 15px` or `italic bold 14pt serif`.
+// Reference: https://www.w3.org/TR/WCAG22/#dfn-large-scale
+
+use serde::{Deserialize, Serialize};
+
+/// Nombre de pixels CSS par point / CSS pixels per point
+const PX_PER_PT: f64 = 4.0 / 3.0;
+
+/// Taille de police racine utilisée pour les rem (px) / Root font size used for rem (px)
+const ROOT_FONT_SIZE_PX: f64 = 16.0;
+
+/// Taille minimale d'un grand texte (pt) / Minimum size of large text (pt)
+const LARGE_TEXT_PT: f64 = 18.0;
+
+/// Taille minimale d'un grand texte en gras (pt) / Minimum size of bold large text (pt)
+const LARGE_BOLD_TEXT_PT: f64 = 14.0;
+
+/// Graisse minimale considérée comme grasse / Minimum weight considered bold
+const BOLD_WEIGHT: u16 = 700;
+
+/// Graisse par défaut / Default weight
+const NORMAL_WEIGHT: u16 = 400;
+
+/// Mots-clés de chasse (font-stretch) admis dans la forme abrégée
+/// Stretch keywords (font-stretch) allowed in the shorthand
+const STRETCH_KEYWORDS: [&str; 8] = [
+    "ultra-condensed",
+    "extra-condensed",
+    "condensed",
+    "semi-condensed",
+    "semi-expanded",
+    "expanded",
+    "extra-expanded",
+    "ultra-expanded",
+];
+
+// =============================================================================
+// STRUCTURES
+// =============================================================================
+
+/// Unité de taille de police / Font size unit
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum FontSizeUnit {
+    Px,
+    Pt,
+    Rem,
+}
+
+impl FontSizeUnit {
+    /// Analyse un nom d'unité CSS / Parses a CSS unit name
+    fn from_css_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "px" => Some(FontSizeUnit::Px),
+            "pt" => Some(FontSizeUnit::Pt),
+            "rem" => Some(FontSizeUnit::Rem),
+            _ => None,
+        }
+    }
+
+    /// Convertit une taille dans cette unité en pixels CSS
+    /// Converts a size in this unit into CSS pixels
+    fn to_px(self, size: f64) -> f64 {
+        match self {
+            FontSizeUnit::Px => size,
+            FontSizeUnit::Pt => size * PX_PER_PT,
+            FontSizeUnit::Rem => size * ROOT_FONT_SIZE_PX,
+        }
+    }
+
+    /// Convertit une taille dans cette unité en points, sans aller-retour par les pixels
+    /// Converts a size in this unit into points, without a round trip through pixels
+    fn to_pt(self, size: f64) -> f64 {
+        match self {
+            FontSizeUnit::Pt => size,
+            _ => self.to_px(size) / PX_PER_PT,
+        }
+    }
+}
+
+/// Caractéristiques du texte testé / Characteristics of the tested text
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct TextSpec {
+    /// Taille saisie / Entered size
+    pub size: f64,
+
+    /// Unité de la taille saisie / Unit of the entered size
+    pub unit: FontSizeUnit,
+
+    /// Graisse (1 - 1000) / Weight (1 - 1000)
+    pub weight: u16,
+
+    /// Taille en pixels CSS / Size in CSS pixels
+    pub size_px: f64,
+
+    /// Taille en points / Size in points
+    pub size_pt: f64,
+
+    /// Si le texte est un grand texte au sens WCAG / If the text is large-scale per WCAG
+    pub large: bool,
+}
+
+/// Erreur de saisie du texte / Text entry error
+#[derive(Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", content = "value", rename_all = "kebab-case")]
+pub enum TextSpecError {
+    /// Taille absente, négative ou illisible / Missing, negative or unreadable size
+    InvalidSize(String),
+    /// Unité non prise en charge / Unsupported unit
+    UnknownUnit(String),
+    /// Graisse hors de 1 - 1000 ou mot-clé relatif / Weight outside 1 - 1000 or relative keyword
+    InvalidWeight(String),
+}
+
+// =============================================================================
+// FONCTIONS
+// FUNCTIONS
+// =============================================================================
+
+impl TextSpec {
+    /// Crée une spécification et la classe / Creates a spec and classifies it
+    ///
+    /// # Arguments
+    /// * `size` - Taille dans `unit` / Size in `unit`
+    /// * `unit` - Unité / Unit
+    /// * `weight` - Graisse (400 par défaut) / Weight (400 by default)
+    pub fn new(size: f64, unit: FontSizeUnit, weight: Option<u16>) -> Result<Self, TextSpecError> {
+        if !size.is_finite() || size <= 0.0 {
+            return Err(TextSpecError::InvalidSize(size.to_string()));
+        }
+        let weight = weight.unwrap_or(NORMAL_WEIGHT);
+        if !(1..=1000).contains(&weight) {
+            return Err(TextSpecError::InvalidWeight(weight.to_string()));
+        }
+
+        let size_px = unit.to_px(size);
+        let size_pt = unit.to_pt(size);
+        let large = size_pt >= LARGE_TEXT_PT || (size_pt >= LARGE_BOLD_TEXT_PT && weight >= BOLD_WEIGHT);
+
+        Ok(Self { size, unit, weight, size_px, size_pt, large })
+    }
+
+    /// Analyse une forme abrégée CSS `font` :
+    /// `[style || variante || graisse || chasse] taille[/interligne] [famille…]`
+    /// Parses a CSS `font` shorthand:
+    /// `[style || variant || weight || stretch] size[/line-height] [family…]`
+    ///
+    /// Les valeurs avant la taille sont acceptées dans n'importe quel ordre, chacune au
+    /// plus une fois ; seule la graisse est retenue. La famille et l'interligne sont ignorés.
+    /// The values before the size are accepted in any order, each at most once; only
+    /// the weight is kept. Family and line height are ignored.
+    pub fn parse(value: &str) -> Result<Self, TextSpecError> {
+        let mut tokens = value.split_whitespace().peekable();
+        let mut weight = None;
+        let (mut style, mut variant, mut stretch) = (false, false, false);
+
+        // Jusqu'à quatre valeurs optionnelles avant la taille, `normal` valant pour l'une d'elles
+        // Up to four optional values before the size, `normal` standing for any of them
+        for _ in 0..4 {
+            let Some(token) = tokens.peek() else { break };
+            match token.to_ascii_lowercase().as_str() {
+                "normal" => {}
+                "italic" if !style => style = true,
+                "oblique" if !style => {
+                    style = true;
+                    tokens.next();
+                    // Angle optionnel de l'oblique / Optional oblique angle
+                    if tokens.peek().is_some_and(|t| t.to_ascii_lowercase().ends_with("deg")) {
+                        tokens.next();
+                    }
+                    continue;
+                }
+                "small-caps" if !variant => variant = true,
+                t if !stretch && STRETCH_KEYWORDS.contains(&t) => stretch = true,
+                "bold" if weight.is_none() => weight = Some(BOLD_WEIGHT),
+                "lighter" | "bolder" => return Err(TextSpecError::InvalidWeight(token.to_string())),
+                t if weight.is_none() && t.chars().all(|c| c.is_ascii_digit()) => {
+                    weight = Some(t.parse::<u16>().map_err(|_| TextSpecError::InvalidWeight(token.to_string()))?);
+                }
+                _ => break,
+            }
+            tokens.next();
+        }
+
+        // Taille avec son unité, sans l'interligne / Size with its unit, without the line height
+        let token = tokens.next().ok_or_else(|| TextSpecError::InvalidSize(value.trim().to_string()))?;
+        let size_token = token.split('/').next().unwrap_or(token);
+        let split = size_token
+            .find(|c: char| c.is_ascii_alphabetic())
+            .ok_or_else(|| TextSpecError::UnknownUnit(size_token.to_string()))?;
+        let (number, unit) = size_token.split_at(split);
+        let size = number.parse::<f64>().map_err(|_| TextSpecError::InvalidSize(size_token.to_string()))?;
+        let unit = FontSizeUnit::from_css_name(unit).ok_or_else(|| TextSpecError::UnknownUnit(unit.to_string()))?;
+
+        Self::new(size, unit, weight)
+    }
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classification() {
+        // 18pt = 24px ; 14pt gras = 18.67px / 18pt = 24px; 14pt bold = 18.67px
+        assert!(TextSpec::new(24.0, FontSizeUnit::Px, None).unwrap().large);
+        assert!(!TextSpec::new(23.9, FontSizeUnit::Px, None).unwrap().large);
+        assert!(TextSpec::new(14.0, FontSizeUnit::Pt, Some(700)).unwrap().large);
+        assert!(!TextSpec::new(14.0, FontSizeUnit::Pt, Some(600)).unwrap().large);
+        assert!(TextSpec::new(1.5, FontSizeUnit::Rem, None).unwrap().large);
+    }
+
+    #[test]
+    fn test_shorthand() {
+        let spec = TextSpec::parse("600 15px").unwrap();
+        assert_eq!((spec.size, spec.unit, spec.weight), (15.0, FontSizeUnit::Px, 600));
+        assert!(!spec.large);
+        assert!(TextSpec::parse("bold 14pt/1.5 Arial, sans-serif").unwrap().large);
+        assert_eq!(TextSpec::parse("1.25rem").unwrap().size_px, 20.0);
+        assert_eq!(TextSpec::parse("15em"), Err(TextSpecError::UnknownUnit("em".to_string())));
+        assert!(matches!(TextSpec::parse("bolder 15px"), Err(TextSpecError::InvalidWeight(_))));
+        assert!(matches!(TextSpec::parse("600"), Err(TextSpecError::InvalidSize(_))));
+    }
+
+    #[test]
+    fn test_shorthand_prefix_order() {
+        // Style, variante, graisse et chasse dans n'importe quel ordre
+        // Style, variant, weight and stretch in any order
+        for css in ["italic bold 16px serif", "bold italic 16px serif", "small-caps 700 condensed 16px/1.2 serif", "oblique 10deg normal bold 16px Arial"] {
+            let spec = TextSpec::parse(css).unwrap();
+            assert_eq!((spec.size, spec.weight), (16.0, 700), "{}", css);
+        }
+        assert_eq!(TextSpec::parse("normal italic 15px").unwrap().weight, 400);
+        assert!(matches!(TextSpec::parse("italic italic 15px"), Err(TextSpecError::InvalidSize(_))));
+    }
+}
@@ -0,0 +1,408 @@
+// =============================================================================
+// tokens.rs - Palette de jetons du design system
+// tokens.rs - Design system token palette
+// =============================================================================
+//
+// Charge une palette de jetons de couleur (JSON ou propriétés personnalisées
+// CSS), puis cherche pour chaque case du store le jeton le plus proche
+// (deltaEOK), et la paire de jetons la plus proche qui atteint le seuil actif.
+// Loads a palette of color tokens (JSON or CSS custom properties), then finds
+// for each store slot the nearest token (deltaEOK), and the nearest token pair
+// that reaches the active threshold.
+//
+// Formats acceptés / Accepted formats:
+// - JSON plat / flat JSON: { "brand-primary": "#0055CC" }
+// - Design Tokens (W3C) ou Style Dictionary, groupes imbriqués, `$value` ou
+//   `value`, alias `{color.brand.primary}` / nested groups, `$value` or
+//   `value`, `{color.brand.primary}` aliases
+// - CSS: `--brand-primary: #0055cc;`, alias `var(--brand-primary)`
+//
+// Les valeurs qui ne sont pas des couleurs (espacements, polices…) sont ignorées.
+// Values that are not colors (spacing, fonts…) are ignored.
+
+use serde::Serialize;
+use crate::apca;
+use crate::color;
+use crate::config;
+use crate::conversion::{self, PreciseColor};
+use crate::css_color;
+use crate::fixes::{FixError, FixTarget};
+use crate::picker::common::format_hex_color;
+use crate::store::{AppState, ResultStore};
+
+/// Profondeur maximale des alias / Maximum alias depth
+const MAX_ALIAS_DEPTH: usize = 8;
+
+// =============================================================================
+// STRUCTURES
+// =============================================================================
+
+/// Jeton de couleur / Color token
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct Token {
+    /// Nom du jeton ("color.brand.primary" ou "--brand-primary")
+    /// Token name ("color.brand.primary" or "--brand-primary")
+    pub name: String,
+
+    /// Couleur au format hexadécimal / Color in hexadecimal format
+    pub hex: String,
+
+    /// Opacité (0.0 - 1.0) / Opacity (0.0 - 1.0)
+    pub alpha: f64,
+
+    /// Valeur exacte / Exact value
+    pub precise: PreciseColor,
+}
+
+/// Jeton le plus proche d'une couleur / Nearest token to a color
+#[derive(Serialize, Clone, Debug)]
+pub struct TokenMatch {
+    pub name: String,
+    pub hex: String,
+
+    /// Distance perceptuelle (deltaEOK) / Perceptual distance (deltaEOK)
+    pub delta_e_ok: f64,
+}
+
+/// Jeton le plus proche pour une case du store / Nearest token for a store slot
+#[derive(Serialize, Clone, Debug)]
+pub struct SlotMatch {
+    /// "foreground", "background", "page" ou "adjacent" / "foreground", "background", "page" or "adjacent"
+    pub key: String,
+    pub token: TokenMatch,
+}
+
+/// Paire de jetons qui atteint la cible / Token pair reaching the target
+#[derive(Serialize, Clone, Debug)]
+pub struct TokenPair {
+    pub foreground: TokenMatch,
+    pub background: TokenMatch,
+    pub contrast_ratio_rounded: f32,
+    pub apca_lc_rounded: f64,
+}
+
+/// Suggestions de jetons / Token suggestions
+#[derive(Serialize, Clone, Debug)]
+pub struct TokenSuggestions {
+    /// Jeton le plus proche de chaque case / Nearest token for each slot
+    pub slots: Vec<SlotMatch>,
+
+    /// Paire la plus proche qui passe (somme des deltaEOK), None si aucune
+    /// Closest passing pair (sum of deltaEOK), None if there is none
+    pub pair: Option<TokenPair>,
+}
+
+/// Erreur de palette / Palette error
+#[derive(Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", content = "value", rename_all = "kebab-case")]
+pub enum TokenError {
+    /// Fichier illisible / Unreadable file
+    Io(String),
+    /// JSON invalide / Invalid JSON
+    Invalid(String),
+    /// Aucun jeton de couleur / No color token
+    EmptyPalette,
+    /// Critère inconnu ou absent du profil actif / Unknown criterion or missing from the active profile
+    UnknownCriterion(String),
+}
+
+impl From<FixError> for TokenError {
+    fn from(error: FixError) -> Self {
+        match error {
+            FixError::UnknownCriterion(key) => TokenError::UnknownCriterion(key),
+        }
+    }
+}
+
+// =============================================================================
+// CHARGEMENT
+// LOADING
+// =============================================================================
+
+/// Parcourt un arbre JSON de jetons / Walks a JSON token tree
+fn collect_json(prefix: &str, value: &serde_json::Value, out: &mut Vec<(String, String)>) {
+    use serde_json::Value;
+    match value {
+        Value::String(text) => out.push((prefix.to_string(), text.clone())),
+        Value::Object(map) => {
+            // Jeton W3C (`$value`) ou Style Dictionary (`value`)
+            // W3C (`$value`) or Style Dictionary (`value`) token
+            if let Some(Value::String(text)) = map.get("$value").or_else(|| map.get("value")) {
+                out.push((prefix.to_string(), text.clone()));
+                return;
+            }
+            for (key, child) in map {
+                if key.starts_with('$') {
+                    continue;
+                }
+                let name = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+                collect_json(&name, child, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Retire les commentaires `/* … */`, un commentaire non fermé allant jusqu'à la fin
+/// Removes `/* … */` comments, an unclosed comment running to the end
+fn strip_css_comments(source: &str) -> String {
+    let mut text = String::with_capacity(source.len());
+    let mut rest = source;
+    while let Some(start) = rest.find("/*") {
+        text.push_str(&rest[..start]);
+        rest = match rest[start + 2..].find("*/") {
+            Some(end) => &rest[start + 2 + end + 2..],
+            None => "",
+        };
+    }
+    text.push_str(rest);
+    text
+}
+
+/// Lit les propriétés personnalisées CSS / Reads CSS custom properties
+fn collect_css(source: &str, out: &mut Vec<(String, String)>) {
+    for declaration in strip_css_comments(source).split([';', '{', '}']) {
+        let Some((name, value)) = declaration.split_once(':') else { continue };
+        let name = name.trim();
+        if name.len() > 2 && name.starts_with("--") {
+            out.push((name.to_string(), value.trim().to_string()));
+        }
+    }
+}
+
+/// Nom référencé par un alias, None si la valeur n'en est pas un
+/// Name referenced by an alias, None if the value is not one
+fn alias(value: &str) -> Option<&str> {
+    if let Some(name) = value.strip_prefix('{').and_then(|v| v.strip_suffix('}')) {
+        return Some(name.trim());
+    }
+    // `var(--a)` ou `var(--a, repli)` : le repli est ignoré
+    // `var(--a)` or `var(--a, fallback)`: the fallback is ignored
+    value
+        .strip_prefix("var(")
+        .and_then(|v| v.strip_suffix(')'))
+        .map(|v| v.split(',').next().unwrap_or(v).trim())
+}
+
+/// Analyse une palette JSON ou CSS / Parses a JSON or CSS palette
+pub fn parse_palette(source: &str) -> Result<Vec<Token>, TokenError> {
+    let mut entries = Vec::new();
+    if source.trim_start().starts_with('{') {
+        let value: serde_json::Value = serde_json::from_str(source).map_err(|e| TokenError::Invalid(e.to_string()))?;
+        collect_json("", &value, &mut entries);
+    } else {
+        collect_css(source, &mut entries);
+    }
+
+    let resolve = |value: &str| {
+        let mut value = value;
+        for _ in 0..MAX_ALIAS_DEPTH {
+            match alias(value).and_then(|name| entries.iter().find(|(n, _)| n == name)) {
+                Some((_, target)) => value = target,
+                None => break,
+            }
+        }
+        css_color::parse(value).ok()
+    };
+
+    let tokens: Vec<Token> = entries
+        .iter()
+        .filter_map(|(name, value)| {
+            let parsed = resolve(value)?;
+            let (r, g, b) = parsed.color.to_rgb8();
+            Some(Token { name: name.clone(), hex: format_hex_color(r, g, b), alpha: parsed.alpha, precise: parsed.color })
+        })
+        .collect();
+
+    if tokens.is_empty() {
+        return Err(TokenError::EmptyPalette);
+    }
+    Ok(tokens)
+}
+
+// =============================================================================
+// FONCTIONS DE CALCUL
+// CALCULATION FUNCTIONS
+// =============================================================================
+
+/// Distance entre une couleur et un jeton / Distance between a color and a token
+fn token_match(token: &Token, srgb: [f64; 3]) -> TokenMatch {
+    TokenMatch {
+        name: token.name.clone(),
+        hex: token.hex.clone(),
+        delta_e_ok: conversion::delta_e_ok(srgb, token.precise.to_srgb()),
+    }
+}
+
+/// Jeton le plus proche d'une couleur / Nearest token to a color
+pub fn nearest_token(tokens: &[Token], srgb: [f64; 3]) -> Option<TokenMatch> {
+    let mut best: Option<TokenMatch> = None;
+    for token in tokens {
+        let candidate = token_match(token, srgb);
+        if candidate.delta_e_ok == 0.0 {
+            return Some(candidate);
+        }
+        if best.as_ref().is_none_or(|b| candidate.delta_e_ok < b.delta_e_ok) {
+            best = Some(candidate);
+        }
+    }
+    best
+}
+
+/// Calcule les suggestions de jetons pour un store
+/// Computes the token suggestions for a store
+///
+/// # Arguments
+/// * `target` - Cible ; par défaut le seuil de texte du profil actif
+///   Target; the active profile text threshold by default
+pub fn suggest(store: &ResultStore, tokens: &[Token], target: Option<&FixTarget>) -> Result<TokenSuggestions, TokenError> {
+    if tokens.is_empty() {
+        return Err(TokenError::EmptyPalette);
+    }
+    let target = match target {
+        Some(target) => target.resolve(&store.profile.criteria)?,
+        None => FixTarget::text_default(store)?,
+    };
+
+    let foreground = store.foreground_precise.to_srgb();
+    let background = store.background_precise.to_srgb();
+    let page = conversion::rgb8_to_srgb(store.page_rgb);
+
+    let mut slots = vec![("foreground", foreground), ("background", background), ("page", page)];
+    if let Some(adjacent) = store.adjacent_precise {
+        slots.push(("adjacent", adjacent.to_srgb()));
+    }
+    let slots = slots
+        .into_iter()
+        .filter_map(|(key, srgb)| nearest_token(tokens, srgb).map(|token| SlotMatch { key: key.to_string(), token }))
+        .collect();
+
+    // Toutes les paires, composées comme le store ; la plus proche qui passe est retenue
+    // Every pair, composited like the store; the closest passing one is kept
+    let mut best: Option<(f64, &Token, &Token)> = None;
+    for bg_token in tokens {
+        let bg = color::composite(bg_token.precise.to_srgb(), bg_token.alpha, page);
+        for fg_token in tokens {
+            let fg = color::composite(fg_token.precise.to_srgb(), fg_token.alpha, bg);
+            if !target.passes(fg, bg) {
+                continue;
+            }
+            let distance = conversion::delta_e_ok(foreground, fg_token.precise.to_srgb())
+                + conversion::delta_e_ok(background, bg_token.precise.to_srgb());
+            if best.is_none_or(|(d, ..)| distance < d) {
+                best = Some((distance, fg_token, bg_token));
+            }
+        }
+    }
+
+    let pair = best.map(|(_, fg_token, bg_token)| {
+        let bg = color::composite(bg_token.precise.to_srgb(), bg_token.alpha, page);
+        let fg = color::composite(fg_token.precise.to_srgb(), fg_token.alpha, bg);
+        let ratio = color::contrast_ratio(fg, bg);
+        let lc = apca::contrast(fg, bg);
+        TokenPair {
+            foreground: token_match(fg_token, foreground),
+            background: token_match(bg_token, background),
+            contrast_ratio_rounded: color::round_ratio(ratio),
+            apca_lc_rounded: (lc * config::APCA_ROUNDING_FACTOR).round() / config::APCA_ROUNDING_FACTOR,
+        }
+    });
+
+    Ok(TokenSuggestions { slots, pair })
+}
+
+// =============================================================================
+// COMMANDES TAURI
+// TAURI COMMANDS
+// =============================================================================
+
+/// Charge une palette de jetons depuis un fichier JSON ou CSS (remplace la précédente)
+/// Loads a token palette from a JSON or CSS file (replaces the previous one)
+///
+/// # Arguments
+/// * `path` - Chemin du fichier / File path
+#[tauri::command]
+pub fn load_token_palette(state: tauri::State<AppState>, path: String) -> Result<Vec<Token>, TokenError> {
+    let source = std::fs::read_to_string(&path).map_err(|e| TokenError::Io(format!("{}: {}", path, e)))?;
+    let tokens = parse_palette(&source)?;
+    *state.tokens.lock().unwrap() = tokens.clone();
+    Ok(tokens)
+}
+
+/// Retourne la palette chargée / Returns the loaded palette
+#[tauri::command]
+pub fn get_token_palette(state: tauri::State<AppState>) -> Vec<Token> {
+    state.tokens.lock().unwrap().clone()
+}
+
+/// Propose le jeton le plus proche de chaque case et la paire conforme la plus proche
+/// Suggests the nearest token for each slot and the nearest compliant pair
+///
+/// # Arguments
+/// * `target` - Critère, ratio ou Lc APCA optionnel / Optional criterion, ratio or APCA Lc
+#[tauri::command]
+pub fn suggest_tokens(state: tauri::State<AppState>, target: Option<FixTarget>) -> Result<TokenSuggestions, TokenError> {
+    let store = state.store.lock().unwrap();
+    let tokens = state.tokens.lock().unwrap();
+    suggest(&store, &tokens, target.as_ref())
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::store_with;
+
+    #[test]
+    fn test_parse_formats() {
+        let json = parse_palette(
+            r##"{ "color": {
+                "brand": { "$type": "color", "$value": "#0055cc" },
+                "link": { "value": "{color.brand}" },
+                "space": { "$value": "16px" }
+            } }"##,
+        )
+        .unwrap();
+        let names: Vec<&str> = json.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["color.brand", "color.link"]);
+        assert_eq!(json[1].hex, "#0055CC");
+
+        let css = parse_palette(":root { --grey-90: #1a1a1a; --text: var(--grey-90); --radius: 4px; --overlay: rgb(0 0 0 / 50%) }").unwrap();
+        assert_eq!(css.len(), 3);
+        assert_eq!(css[1].hex, "#1A1A1A");
+        assert_eq!(css[2].alpha, 0.5);
+
+        assert_eq!(parse_palette("--gap: 4px;"), Err(TokenError::EmptyPalette));
+    }
+
+    #[test]
+    fn test_css_comments() {
+        // Les commentaires, même avec `;` ou `:`, ne coupent ni ne créent de déclaration
+        // Comments, even with `;` or `:`, neither split nor create a declaration
+        let css = parse_palette(
+            "/* Marque ; --old: #f00; */ :root { --brand: /* bleu */ #0055cc; /* --muted: #999 */ --text: #1a1a1a /* fin */ }",
+        )
+        .unwrap();
+        let names: Vec<&str> = css.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["--brand", "--text"]);
+        assert_eq!(css[0].hex, "#0055CC");
+        assert_eq!(parse_palette("--brand: #0055cc; /* --muted: #999"), parse_palette("--brand: #0055cc;"));
+    }
+
+    #[test]
+    fn test_nearest_pair() {
+        let tokens = parse_palette(r##"{ "white": "#ffffff", "grey-50": "#808080", "grey-60": "#666666", "grey-90": "#1a1a1a" }"##).unwrap();
+        let store = store_with((0x7a, 0x7a, 0x7a), (0xff, 0xff, 0xff));
+
+        // #7A7A7A est plus proche de grey-50, mais seul grey-60 atteint 4.5:1
+        // #7A7A7A is closer to grey-50, but only grey-60 reaches 4.5:1
+        let suggestions = suggest(&store, &tokens, None).unwrap();
+        assert_eq!(suggestions.slots[0].token.name, "grey-50");
+        let pair = suggestions.pair.unwrap();
+        assert_eq!((pair.foreground.name.as_str(), pair.background.name.as_str()), ("grey-60", "white"));
+        assert_eq!(pair.background.delta_e_ok, 0.0);
+    }
+}
@@ -25,7 +25,7 @@ import Alpine from 'alpinejs';
 
 // Import du store et des interfaces depuis store.ts
 // Import store and interfaces from store.ts
-import { UIStore, BackendStore } from './store';
+import { UIStore, BackendStore, ColorModels } from './store';
 
 // Import du module i18n
 // Import i18n module
@@ -92,15 +92,102 @@ function eventToShortcut(e: KeyboardEvent): string {
   return parts.join('+');
 }
 
+// Formate une couleur en syntaxe CSS à partir des valeurs calculées par le backend
+// Formats a colour in CSS syntax from the values computed by the backend
+function formatModel(model: keyof ColorModels, values: number[]): string {
+  switch (model) {
+    case 'hsl':
+    case 'hwb':
+      return `${model}(${values[0]} ${values[1]}% ${values[2]}%)`;
+    case 'hsv':
+      return `hsv(${values[0]}, ${values[1]}%, ${values[2]}%)`;
+    case 'xyz':
+      return `color(xyz-d65 ${values.join(' ')})`;
+    case 'cmyk':
+      return `device-cmyk(${values.map(v => `${v}%`).join(' ')})`;
+    default:
+      return `${model}(${values.join(' ')})`;
+  }
+}
+
+// Remplace les balises %f.<modèle>% et %b.<modèle>% (ex. %f.oklch%)
+// Replaces %f.<model>% and %b.<model>% tags (e.g. %f.oklch%)
+function formatModelTags(template: string, store: UIStore): string {
+  return template.replace(/%([fb])\.(hsl|hsv|hwb|lab|lch|oklab|oklch|xyz|cmyk)%/g, (tag, slot, model: keyof ColorModels) => {
+    const models = slot === 'f' ? store.foregroundModels : store.backgroundModels;
+    return models ? formatModel(model, models[model]) : tag;
+  });
+}
+
 function formatTemplate(template: string, store: UIStore): string {
-  return template
+  const text = formatModelTags(template, store)
     .replace(/%f\.hex%/g, store.foregroundHex)
     .replace(/%b\.hex%/g, store.backgroundHex)
+    .replace(/%f\.name%/g, store.foregroundName)
+    .replace(/%b\.name%/g, store.backgroundName)
+    .replace(/%f\.nearest%/g, store.foregroundNearest?.name ?? '')
+    .replace(/%b\.nearest%/g, store.backgroundNearest?.name ?? '')
     .replace(/%cr%/g, store.contrastRatio)
     .replace(/%crr%/g, store.contrastRatio)
-    .replace(/%1\.4\.3%/g, store.level143Regular ? 'Pass' : 'Fail')
-    .replace(/%1\.4\.6%/g, store.level146Regular ? 'Pass' : 'Fail')
-    .replace(/%1\.4\.11%/g, store.level1411 ? 'Pass' : 'Fail');
+    .replace(/%apca%/g, store.apcaLc)
+    .replace(/%apca\.rev%/g, store.apcaLcReverse)
+    .replace(/%apca\.pol%/g, store.apcaPolarity)
+    .replace(/%cr\.mapped%/g, store.contrastRatioMapped)
+    .replace(/%apca\.mapped%/g, store.apcaLcMapped);
+  return formatCriteriaTags(formatApcaFontTags(formatDeltaETags(text, store), store), store);
+}
+
+// Remplace %de2000% (premier plan / arrière-plan) et %f.de2000%, %b.de2000%
+// (case / référence) pour les formules 76, 94, 2000 et ok
+// Replaces %de2000% (foreground / background) and %f.de2000%, %b.de2000%
+// (slot / reference) for the 76, 94, 2000 and ok formulas
+function formatDeltaETags(template: string, store: UIStore): string {
+  return template.replace(/%(?:([fb])\.)?de(76|94|2000|ok)%/g, (tag, slot, formula) => {
+    const key = slot === 'f' ? 'foreground' : 'background';
+    const difference = slot
+      ? store.referenceDifferences.find((d) => d.key === key)?.difference
+      : store.colorDifference;
+    if (!difference) return tag;
+    const value = difference[`delta_e_${formula}` as keyof typeof difference];
+    return value.toFixed(formula === 'ok' ? 3 : 2);
+  });
+}
+
+// Remplace %apca.font.400% par la taille minimale de la graisse (ou non-text / n/a)
+// et %apca.use% par les usages permis
+// Replaces %apca.font.400% with the weight's minimum size (or non-text / n/a)
+// and %apca.use% with the allowed use cases
+function formatApcaFontTags(template: string, store: UIStore): string {
+  const report = store.apcaFonts;
+  if (!report) return template;
+  return template
+    .replace(/%apca\.font\.([1-9]00)%/g, (tag, weight) => {
+      const size = report.sizes.find((s) => s.weight === Number(weight));
+      if (!size) return tag;
+      if (size.min_size_px !== null) return `${size.min_size_px}px`;
+      return size.non_text_only ? 'non-text' : 'n/a';
+    })
+    .replace(/%apca\.use%/g, () => {
+      const uses = [
+        report.use_cases.body_text && 'body text',
+        report.use_cases.spot_text && 'spot text',
+        report.use_cases.non_text && 'non-text',
+      ].filter(Boolean);
+      return uses.length > 0 ? uses.join(', ') : 'none';
+    });
+}
+
+// Remplace %1.4.3%, %1.4.3-large%… par le résultat du critère dans le profil actif,
+// ou N/A si le profil ne le contient pas
+// Replaces %1.4.3%, %1.4.3-large%… with the criterion result in the active profile,
+// or N/A when the profile does not contain it
+function formatCriteriaTags(template: string, store: UIStore): string {
+  let text = template;
+  for (const result of store.compliance) {
+    const key = result.content === 'large' ? `${result.id}-large` : result.id;
+    text = text.split(`%${key}%`).join(result.pass ? 'Pass' : 'Fail');
+  }
+  return text.replace(/%1\.4\.(3|6|11)(-large)?%/g, 'N/A');
 }
 
 let toastTimeout: ReturnType<typeof setTimeout>;
@@ -207,6 +294,9 @@ onLocaleChange((locale) => {
   const alpineStore = Alpine.store('uiStore') as UIStore;
   alpineStore.locale = locale;
 
+  // Traduit les noms de couleur descriptifs / Translate the descriptive colour names
+  alpineStore.refreshColorNames();
+
   // Notifie le backend Rust pour reconstruire les menus
   // Notify Rust backend to rebuild menus
   invoke('set_locale', { locale }).catch((err) => {
@@ -337,6 +427,54 @@ initTheme();
     console.error('Error sending templates to backend:', error);
   }
 
+  // Étape 5b bis : Recharge les profils de normes personnalisés et réactive le profil choisi
+  // Step 5b bis: Reload custom standards profiles and re-activate the chosen profile
+  try {
+    const paths: string[] = JSON.parse(localStorage.getItem('cca-standards-profile-paths') ?? '[]');
+    for (const path of paths) {
+      await invoke('load_standards_profile', { path }).catch((error: unknown) => {
+        console.error('Error loading standards profile:', path, error);
+      });
+    }
+    const profileId = localStorage.getItem('cca-standards-profile');
+    if (profileId) {
+      await invoke('select_standards_profile', { id: profileId });
+    }
+  } catch (error) {
+    console.error('Error restoring standards profile:', error);
+  }
+
+  // Étape 5b ter : Recharge la palette de jetons du design system
+  // Step 5b ter: Reload the design system token palette
+  const tokenPath = localStorage.getItem('cca-token-palette-path');
+  if (tokenPath) {
+    await invoke('load_token_palette', { path: tokenPath }).catch((error: unknown) => {
+      console.error('Error loading token palette:', tokenPath, error);
+    });
+  }
+
+  // Étape 5b quater : Recharge les dictionnaires de noms de couleur
+  // Step 5b quater: Reload the colour name dictionaries
+  try {
+    const entries: { id: string; path: string }[] = JSON.parse(localStorage.getItem('cca-color-dictionary-paths') ?? '[]');
+    for (const { path } of entries) {
+      await invoke('load_color_dictionary', { path }).catch((error: unknown) => {
+        console.error('Error loading colour dictionary:', path, error);
+      });
+    }
+  } catch (error) {
+    console.error('Error restoring colour dictionaries:', error);
+  }
+
+  // Étape 5b quinquies : Recharge le profil de sortie CMYK de l'épreuvage
+  // Step 5b quinquies: Reload the CMYK output profile for proofing
+  const outputProfilePath = localStorage.getItem('cca-output-profile-path');
+  if (outputProfilePath) {
+    await invoke('load_output_profile', { path: outputProfilePath }).catch((error: unknown) => {
+      console.error('Error loading output profile:', outputProfilePath, error);
+    });
+  }
+
   // Étape 5c : Écoute les clics sur les modèles de copie depuis le menu natif
   // Step 5c: Listen for copy template clicks from native menu
   await listen<number>('copy-template', (event) => {
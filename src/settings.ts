@@ -76,6 +76,41 @@ function loadTemplates(): CopyTemplate[] {
   return structuredClone(DEFAULT_TEMPLATES);
 }
 
+interface StandardsProfile {
+  id: string;
+  name: string;
+}
+
+interface Token {
+  name: string;
+  hex: string;
+}
+
+interface ColorDictionary {
+  id: string;
+  name: string;
+  locale: string | null;
+  built_in: boolean;
+  size: number;
+}
+
+// Fichiers de dictionnaire chargés, rechargés au démarrage / Loaded dictionary files, reloaded at startup
+function loadDictionaryPaths(): { id: string; path: string }[] {
+  try {
+    const raw = localStorage.getItem('cca-color-dictionary-paths');
+    if (raw) return JSON.parse(raw);
+  } catch {}
+  return [];
+}
+
+function loadProfilePaths(): string[] {
+  try {
+    const raw = localStorage.getItem('cca-standards-profile-paths');
+    if (raw) return JSON.parse(raw);
+  } catch {}
+  return [];
+}
+
 function keyboardEventToShortcut(event: KeyboardEvent): string {
   const parts: string[] = [];
   if (event.metaKey) parts.push('Cmd');
@@ -102,6 +137,27 @@ Alpine.store('settings', {
   // Thème light/dark/auto / Theme light/dark/auto
   theme: getThemePreference() as ThemePreference,
 
+  // Profils de normes et profil actif / Standards profiles and active profile
+  profiles: [] as StandardsProfile[],
+  profileId: localStorage.getItem('cca-standards-profile') ?? 'wcag22',
+  profilePath: '',
+  profileError: '',
+
+  // Palette de jetons du design system / Design system token palette
+  tokens: [] as Token[],
+  tokenPath: localStorage.getItem('cca-token-palette-path') ?? '',
+  tokenError: '',
+
+  // Profil de sortie CMYK pour l'épreuvage / CMYK output profile for proofing
+  outputProfile: '',
+  outputProfilePath: localStorage.getItem('cca-output-profile-path') ?? '',
+  outputProfileError: '',
+
+  // Dictionnaires de noms de couleur / Colour name dictionaries
+  dictionaries: [] as ColorDictionary[],
+  dictionaryPath: '',
+  dictionaryError: '',
+
   // Durée du toast en secondes (0 = manuel) / Toast duration in seconds (0 = manual)
   toastDuration: parseInt(localStorage.getItem('cca-toast-duration') ?? '3', 10),
 
@@ -126,6 +182,98 @@ Alpine.store('settings', {
     });
   },
 
+  // Active un profil de norme / Activate a standards profile
+  async selectProfile(id: string): Promise<void> {
+    try {
+      await invoke('select_standards_profile', { id });
+      (this as any).profileId = id;
+      localStorage.setItem('cca-standards-profile', id);
+    } catch (error) {
+      console.error('Error selecting standards profile:', error);
+    }
+  },
+
+  // Charge un profil personnalisé depuis un fichier JSON
+  // Load a custom profile from a JSON file
+  async loadProfile(): Promise<void> {
+    const path = (this as any).profilePath.trim();
+    if (path === '') return;
+    try {
+      const profile = await invoke<StandardsProfile>('load_standards_profile', { path });
+      const paths = loadProfilePaths().filter((p) => p !== path);
+      localStorage.setItem('cca-standards-profile-paths', JSON.stringify([...paths, path]));
+      (this as any).profiles = await invoke<StandardsProfile[]>('list_standards_profiles');
+      (this as any).profilePath = '';
+      (this as any).profileError = '';
+      await (this as any).selectProfile(profile.id);
+    } catch (error: any) {
+      (this as any).profileError = error?.value ?? String(error);
+    }
+  },
+
+  // Charge une palette de jetons (JSON ou CSS) / Load a token palette (JSON or CSS)
+  async loadTokens(): Promise<void> {
+    const path = (this as any).tokenPath.trim();
+    if (path === '') return;
+    try {
+      (this as any).tokens = await invoke<Token[]>('load_token_palette', { path });
+      localStorage.setItem('cca-token-palette-path', path);
+      (this as any).tokenError = '';
+    } catch (error: any) {
+      (this as any).tokenError = error?.value ?? error?.kind ?? String(error);
+    }
+  },
+
+  // Charge un profil de sortie CMYK (.icc) / Load a CMYK output profile (.icc)
+  async loadOutputProfile(): Promise<void> {
+    const path = (this as any).outputProfilePath.trim();
+    if (path === '') return;
+    try {
+      (this as any).outputProfile = await invoke<string>('load_output_profile', { path });
+      localStorage.setItem('cca-output-profile-path', path);
+      (this as any).outputProfileError = '';
+    } catch (error: any) {
+      (this as any).outputProfileError = error?.value ?? error?.kind ?? String(error);
+    }
+  },
+
+  // Retire le profil de sortie / Remove the output profile
+  async clearOutputProfile(): Promise<void> {
+    await invoke('clear_output_profile');
+    localStorage.removeItem('cca-output-profile-path');
+    (this as any).outputProfile = '';
+    (this as any).outputProfilePath = '';
+  },
+
+  // Charge un dictionnaire de noms (JSON, xkcd ou X11 rgb.txt)
+  // Load a name dictionary (JSON, xkcd or X11 rgb.txt)
+  async loadDictionary(): Promise<void> {
+    const path = (this as any).dictionaryPath.trim();
+    if (path === '') return;
+    try {
+      const dictionary = await invoke<ColorDictionary>('load_color_dictionary', { path });
+      const paths = loadDictionaryPaths().filter((p) => p.path !== path && p.id !== dictionary.id);
+      localStorage.setItem('cca-color-dictionary-paths', JSON.stringify([...paths, { id: dictionary.id, path }]));
+      (this as any).dictionaries = await invoke<ColorDictionary[]>('list_color_dictionaries');
+      (this as any).dictionaryPath = '';
+      (this as any).dictionaryError = '';
+    } catch (error: any) {
+      (this as any).dictionaryError = error?.value ?? error?.kind ?? String(error);
+    }
+  },
+
+  // Retire un dictionnaire chargé / Remove a loaded dictionary
+  async removeDictionary(id: string): Promise<void> {
+    try {
+      await invoke('remove_color_dictionary', { id });
+      (this as any).dictionaries = await invoke<ColorDictionary[]>('list_color_dictionaries');
+      const paths = loadDictionaryPaths().filter((p) => p.id !== id);
+      localStorage.setItem('cca-color-dictionary-paths', JSON.stringify(paths));
+    } catch (error: any) {
+      (this as any).dictionaryError = error?.value ?? error?.kind ?? String(error);
+    }
+  },
+
   // Met à jour un raccourci / Update a shortcut
   updateShortcut(index: number, event: KeyboardEvent): void {
     if (['Control', 'Alt', 'Shift', 'Meta'].includes(event.key)) return;
@@ -217,6 +365,27 @@ initTheme();
   store.locale = detectedLocale;
   store.preference = getLocalePreference();
 
+  // Liste les profils de normes / List the standards profiles
+  try {
+    store.profiles = await invoke<StandardsProfile[]>('list_standards_profiles');
+  } catch (error) {
+    console.error('Error listing standards profiles:', error);
+  }
+
+  // Palette de jetons déjà chargée / Token palette already loaded
+  try {
+    store.tokens = await invoke<Token[]>('get_token_palette');
+  } catch (error) {
+    console.error('Error getting token palette:', error);
+  }
+
+  // Dictionnaires de noms de couleur / Colour name dictionaries
+  try {
+    store.dictionaries = await invoke<ColorDictionary[]>('list_color_dictionaries');
+  } catch (error) {
+    console.error('Error listing colour dictionaries:', error);
+  }
+
   // Écoute les changements de locale depuis le menu natif ou d'autres fenêtres
   // Listen for locale changes from native menu or other windows
   await listen<string>('locale-changed', (event) => {
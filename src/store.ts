@@ -11,6 +11,374 @@ import { invoke } from "@tauri-apps/api/core";
 // Import i18n module
 import { t as i18nT, setLocale } from './i18n';
 
+// Erreur d'analyse d'une couleur CSS (positions en caractères)
+// CSS colour parse error (positions in characters)
+export interface ColorParseError {
+  kind: string;
+  message: string;
+  start: number;
+  end: number;
+}
+
+// Taille de police minimale APCA pour une graisse
+// APCA minimum font size for a weight
+export interface ApcaWeightSize {
+  weight: number;
+  min_size_px: number | null;
+  non_text_only: boolean;
+}
+
+// Tailles de police minimales APCA et usages permis
+// APCA minimum font sizes and allowed use cases
+export interface ApcaFontReport {
+  lc: number;
+  sizes: ApcaWeightSize[];
+  use_cases: { body_text: boolean; spot_text: boolean; non_text: boolean };
+}
+
+// Résultat G183 / F73 d'un lien face au texte adjacent
+// G183 / F73 result of a link against the adjacent text
+export interface LinkContrast {
+  link_vs_background_rounded: number;
+  link_vs_background_pass: boolean;
+  link_vs_text_rounded: number;
+  link_vs_text_pass: boolean;
+  text_vs_background_rounded: number;
+  text_vs_background_pass: boolean;
+  pass: boolean;
+}
+
+// Taille et graisse du texte testé, classées par le backend
+// Size and weight of the tested text, classified by the backend
+export interface TextSpec {
+  size: number;
+  unit: 'px' | 'pt' | 'rem';
+  weight: number;
+  size_px: number;
+  size_pt: number;
+  large: boolean;
+}
+
+// Erreur de saisie du texte / Text entry error
+export interface TextSpecError {
+  kind: 'invalid-size' | 'unknown-unit' | 'invalid-weight';
+  value: string;
+}
+
+// Représentations d'une couleur dans tous les modèles, calculées par le backend
+// Representations of a colour in every model, computed by the backend
+export interface ColorModels {
+  hsl: [number, number, number];
+  hsv: [number, number, number];
+  hwb: [number, number, number];
+  lab: [number, number, number];
+  lch: [number, number, number];
+  oklab: [number, number, number];
+  oklch: [number, number, number];
+  xyz: [number, number, number];
+  cmyk: [number, number, number, number];
+}
+
+// Couleur exacte, étiquetée avec son espace RGB
+// Exact colour, tagged with its RGB space
+export interface PreciseColor {
+  space: 'srgb' | 'srgb-linear' | 'display-p3' | 'a98-rgb' | 'prophoto-rgb' | 'rec2020';
+  components: [number, number, number];
+}
+
+// Simulation d'une déficience de la vision des couleurs
+// Colour vision deficiency simulation
+export interface CvdSimulation {
+  deficiency: 'protan' | 'deutan' | 'tritan' | 'achromatopsia';
+  severity: number;
+  foreground_rgb: [number, number, number];
+  foreground_hex: string;
+  background_rgb: [number, number, number];
+  background_hex: string;
+  contrast_ratio_rounded: number;
+  apca_lc_rounded: number;
+}
+
+// Rapport de simulation (vision typique et une entrée par déficience)
+// Simulation report (typical vision and one entry per deficiency)
+export interface CvdReport {
+  contrast_ratio_rounded: number;
+  apca_lc_rounded: number;
+  simulations: CvdSimulation[];
+  min_contrast_ratio_rounded: number;
+  min_apca_lc_rounded: number;
+}
+
+// Cible de contraste pour les suggestions de correction
+// Contrast target for repair suggestions
+export type FixTarget =
+  | { type: 'criterion'; value: string }
+  | { type: 'ratio'; value: number }
+  | { type: 'apca-lc'; value: number };
+
+// Couleur proposée pour une case, applicable via update_store
+// Colour suggested for a slot, applicable through update_store
+export interface FixSuggestion {
+  key: 'foreground' | 'background';
+  rgb: [number, number, number];
+  hex: string;
+  oklch: [number, number, number];
+  delta_e_ok: number;
+  contrast_ratio_rounded: number;
+  apca_lc_rounded: number;
+}
+
+// Suggestions pour les deux cases / Suggestions for both slots
+export interface FixSuggestions {
+  already_passes: boolean;
+  foreground: FixSuggestion | null;
+  background: FixSuggestion | null;
+}
+
+// Critère d'un profil de norme / Criterion of a standards profile
+export interface Criterion {
+  id: string;
+  level: 'A' | 'AA' | 'AAA';
+  content: 'regular' | 'large' | 'non-text';
+  threshold: number;
+  label: string;
+}
+
+// Profil de norme (WCAG, EN 301 549, Section 508, BITV ou personnalisé)
+// Standards profile (WCAG, EN 301 549, Section 508, BITV or custom)
+export interface StandardsProfile {
+  id: string;
+  name: string;
+  built_in: boolean;
+  criteria: Criterion[];
+}
+
+// Résultat d'un critère, évalué côté backend avec le ratio non arrondi
+// Criterion result, evaluated on the backend with the unrounded ratio
+export interface CriterionResult extends Criterion {
+  pass: boolean;
+  applies: boolean;
+  margin: number;
+}
+
+// Arrière-plan supplémentaire sous le même premier plan
+// Additional background under the same foreground
+export interface Surface {
+  hex: string;
+  alpha: number;
+}
+
+// Résultats par surface (l'arrière-plan du store en premier) et index du pire cas
+// Per-surface results (the store background first) and index of the worst case
+export interface SurfaceReport {
+  results: {
+    background_hex: string;
+    contrast_ratio_rounded: number;
+    apca_lc_rounded: number;
+    compliance: CriterionResult[];
+  }[];
+  worst: number;
+}
+
+// Différences entre deux couleurs (CIE76, CIE94, CIEDE2000, OKLab)
+// Differences between two colours (CIE76, CIE94, CIEDE2000, OKLab)
+export interface ColorDifference {
+  delta_e_76: number;
+  delta_e_94: number;
+  delta_e_2000: number;
+  delta_e_ok: number;
+}
+
+// Différence d'une case avec la couleur de référence
+// Difference of a slot with the reference colour
+export interface ReferenceDifference {
+  key: 'foreground' | 'background' | 'page' | 'adjacent';
+  hex: string;
+  difference: ColorDifference;
+}
+
+// Paire composée d'un thème / Composited pair of a theme
+export interface ThemePair {
+  foreground_hex: string;
+  background_hex: string;
+  foreground_oklch: [number, number, number];
+  background_oklch: [number, number, number];
+  contrast_ratio_rounded: number;
+  apca_lc_rounded: number;
+  compliance: CriterionResult[];
+}
+
+// Thèmes clair et sombre côte à côte / Light and dark themes side by side
+export interface DarkModeReport {
+  light: ThemePair;
+  dark: ThemePair;
+  derived: boolean;
+  keeps_contrast: boolean;
+}
+
+// Contraste du premier plan sur une zone d'image / Foreground contrast over an image region
+export interface RegionReport {
+  width: number;
+  height: number;
+  min_rounded: number;
+  p5_rounded: number;
+  median_rounded: number;
+  worst: { x: number; y: number; rgb: [number, number, number]; hex: string; contrast_ratio_rounded: number };
+  compliance: CriterionResult[];
+}
+
+// Couleurs du texte et du fond détectées dans une zone / Text and background colours detected in a region
+export interface Detection {
+  foreground_hex: string;
+  background_hex: string;
+  foreground_pixels: number;
+  background_pixels: number;
+  edge_pixels: number;
+  contrast_ratio_rounded: number;
+  confidence: number;
+  low_confidence: boolean;
+}
+
+// Contraste du premier plan le long d'un dégradé CSS / Foreground contrast along a CSS gradient
+export interface GradientSample {
+  position: number;
+  angle: number | null;
+  hex: string;
+  contrast_ratio_rounded: number;
+}
+
+export interface GradientReport {
+  gradient: {
+    kind: 'linear' | 'radial' | 'conic';
+    repeating: boolean;
+    space: string;
+    hue: 'shorter' | 'longer' | 'increasing' | 'decreasing';
+    angle: number | null;
+    stops: { hex: string; alpha: number; position: number }[];
+  };
+  lowest: GradientSample;
+  highest: GradientSample;
+  compliance: CriterionResult[];
+}
+
+// Erreur d'analyse de dégradé / Gradient parse error
+export type GradientError =
+  | { kind: 'unknown-function'; value: string }
+  | { kind: 'invalid-syntax'; value: string }
+  | { kind: 'invalid-color'; value: ColorParseError }
+  | { kind: 'unknown-color-space'; value: string }
+  | { kind: 'unsupported-unit'; value: string }
+  | { kind: 'too-few-stops' };
+
+// Voile minimal sous le texte posé sur une image / Minimum overlay under text over an image
+export type GradientDirection = 'bottom' | 'top' | 'right' | 'left';
+
+export interface ScrimReport {
+  text_hex: string;
+  overlay_hex: string;
+  target: FixTarget;
+  alpha: number | null;
+  css: string | null;
+  worst_contrast_ratio_rounded: number;
+  worst_apca_lc_rounded: number;
+  gradient: { position: number; alpha: number }[] | null;
+  gradient_css: string | null;
+}
+
+// Couleur avant et après impression / Colour before and after printing
+export interface ProofedColor {
+  original_hex: string;
+  proofed_hex: string;
+  cmyk: [number, number, number, number];
+  delta_e_2000: number;
+}
+
+// Contraste de la paire après épreuvage CMYK / Contrast of the pair after CMYK proofing
+export interface SoftProofReport {
+  profile: string;
+  simulate_paper: boolean;
+  foreground: ProofedColor;
+  background: ProofedColor;
+  contrast_ratio_rounded: number;
+  proofed_contrast_ratio_rounded: number;
+  proofed_apca_lc_rounded: number;
+  compliance: CriterionResult[];
+  keeps_compliance: boolean;
+}
+
+// Jeton du design system / Design system token
+export interface Token {
+  name: string;
+  hex: string;
+  alpha: number;
+}
+
+// Jeton le plus proche d'une couleur / Nearest token to a colour
+export interface TokenMatch {
+  name: string;
+  hex: string;
+  delta_e_ok: number;
+}
+
+// Jeton le plus proche de chaque case et paire conforme la plus proche
+// Nearest token for each slot and nearest compliant pair
+export interface TokenSuggestions {
+  slots: { key: 'foreground' | 'background' | 'page' | 'adjacent'; token: TokenMatch }[];
+  pair: {
+    foreground: TokenMatch;
+    background: TokenMatch;
+    contrast_ratio_rounded: number;
+    apca_lc_rounded: number;
+  } | null;
+}
+
+// Nom le plus proche dans un dictionnaire de couleurs (CIEDE2000)
+// Nearest name in a colour dictionary (CIEDE2000)
+export interface NearestName {
+  dictionary: string;
+  dictionary_name: string;
+  name: string;
+  hex: string;
+  delta_e_2000: number;
+  approximate: boolean;
+}
+
+// Résultat d'un état de composant (default, hover, focus, disabled…)
+// Result of a component state (default, hover, focus, disabled…)
+export interface ComponentStateResult {
+  name: string;
+  foreground_hex: string;
+  background_hex: string;
+  contrast_ratio_rounded: number;
+  apca_lc_rounded: number;
+  compliance: CriterionResult[];
+  indicator: {
+    hex: string;
+    vs_background_rounded: number;
+    vs_page_rounded: number;
+    pass: boolean;
+  } | null;
+  exempt: boolean;
+}
+
+// Position de l'indicateur : contour extérieur sur la page, ou sur le fond du composant
+// Indicator placement: outside outline over the page, or over the component fill
+export type IndicatorPlacement = 'outside' | 'inside';
+
+// Matrice des états, avec le changement au focus (2.4.13)
+// State matrix, with the focus change (2.4.13)
+export interface StateMatrix {
+  page_hex: string;
+  states: ComponentStateResult[];
+  focus_change: { contrast_ratio_rounded: number; pass: boolean } | null;
+}
+
+// Erreur de l'espace de travail des états / State workspace error
+export type StateError =
+  | { kind: 'invalid-color'; value: ColorParseError }
+  | { kind: 'unknown-state'; value: string }
+  | { kind: 'io'; value: string };
+
 // Interface pour le store Tauri (état global côté backend)
 // Interface for Tauri store (global state on backend side)
 export interface BackendStore {
@@ -41,9 +409,82 @@ export interface BackendStore {
   /// If the colour is dark
   background_is_dark: boolean;
 
+  // Couleurs dans tous les modèles
+  // Colours in every model
+  foreground_models: ColorModels;
+  background_models: ColorModels;
+
+  // Valeurs exactes et indicateurs hors gamut sRGB
+  // Exact values and out-of-sRGB-gamut flags
+  foreground_precise: PreciseColor;
+  background_precise: PreciseColor;
+  foreground_out_of_srgb: boolean;
+  background_out_of_srgb: boolean;
+
+  // Opacité des couleurs (0 - 1)
+  // Colour opacity (0 - 1)
+  foreground_alpha: number;
+  background_alpha: number;
+
+  // Couleur de page sous un arrière-plan translucide
+  // Page colour under a translucent background
+  page_rgb: [number, number, number];
+  page_hex: string;
+
+  // Couleurs composées, utilisées pour le contraste
+  // Composited colours, used for contrast
+  foreground_composited_rgb: [number, number, number];
+  foreground_composited_hex: string;
+  background_composited_rgb: [number, number, number];
+  background_composited_hex: string;
+
+  // Si le résultat dépend de ce qui se trouve sous l'arrière-plan
+  // If the result depends on what is underneath the background
+  depends_on_backdrop: boolean;
+
   // Contrast Ratio (Rounded)
   contrast_ratio_rounded: number;
 
+  // Contraste APCA (Lc) du premier plan sur l'arrière-plan, et inverse
+  // APCA contrast (Lc) of foreground on background, and reverse
+  apca_lc_rounded: number;
+  apca_lc_reverse_rounded: number;
+
+  // Polarité APCA / APCA polarity
+  apca_polarity: 'dark-on-light' | 'light-on-dark';
+
+  // Contraste après gamut mapping (couleurs affichées)
+  // Contrast after gamut mapping (displayed colours)
+  contrast_ratio_mapped_rounded: number;
+  apca_lc_mapped_rounded: number;
+
+  // Texte adjacent (texte courant autour d'un lien), null si vide
+  // Adjacent text (body text around a link), null if empty
+  adjacent_hex: string | null;
+
+  // Résultat G183 / F73, null sans texte adjacent / G183 / F73 result, null without adjacent text
+  link_contrast: LinkContrast | null;
+  surfaces: Surface[];
+  surface_report: SurfaceReport | null;
+
+  // Couleur de référence (charte), null si vide, et différences des cases
+  // Reference colour (brand spec), null if empty, and slot differences
+  reference_hex: string | null;
+  color_difference: ColorDifference;
+  reference_differences: ReferenceDifference[];
+
+  // Tailles de police minimales APCA / APCA minimum font sizes
+  apca_fonts: ApcaFontReport;
+
+  // Taille et graisse du texte testé / Size and weight of the tested text
+  text_spec: TextSpec | null;
+
+  // Identifiant du profil de norme actif / Active standards profile id
+  profile_id: string;
+
+  // Résultat de chaque critère du profil actif / Result of each criterion of the active profile
+  compliance: CriterionResult[];
+
   // Indique si le mode continu est activé
   // Indicates if continue mode is enabled
   continue_mode: boolean;
@@ -80,6 +521,10 @@ export interface UIStore {
   // CSS name of foreground color (empty if no exact match)
   foregroundName: string;
 
+  // Nom le plus proche dans les dictionnaires (null avant la première synchronisation)
+  // Nearest name in the dictionaries (null before the first synchronization)
+  foregroundNearest: NearestName | null;
+
   /// Si la couleur est sombre
   /// If the colour is dark
   foregroundIsDark: boolean;
@@ -96,19 +541,106 @@ export interface UIStore {
   // CSS name of background color (empty if no exact match)
   backgroundName: string;
 
+  // Nom le plus proche dans les dictionnaires / Nearest name in the dictionaries
+  backgroundNearest: NearestName | null;
+
   /// Si la couleur est sombre
   /// If the colour is dark
   backgroundIsDark: boolean;
 
+  // Couleurs dans tous les modèles (null avant la première synchronisation)
+  // Colours in every model (null before the first synchronization)
+  foregroundModels: ColorModels | null;
+  backgroundModels: ColorModels | null;
+
+  // Valeurs exactes (null avant la première synchronisation)
+  // Exact values (null before the first synchronization)
+  foregroundPrecise: PreciseColor | null;
+  backgroundPrecise: PreciseColor | null;
+
+  // Si la couleur sort du gamut sRGB (RGB / hex ramenés dans le gamut)
+  // If the colour is outside the sRGB gamut (RGB / hex gamut mapped)
+  foregroundOutOfSrgb: boolean;
+  backgroundOutOfSrgb: boolean;
+
+  // Opacité des couleurs (0 - 1)
+  // Colour opacity (0 - 1)
+  foregroundAlpha: number;
+  backgroundAlpha: number;
+
+  // Couleur de page au format hexadécimal
+  // Page colour in hexadecimal format
+  pageHex: string;
+
+  // Couleurs composées au format hexadécimal
+  // Composited colours in hexadecimal format
+  foregroundCompositedHex: string;
+  backgroundCompositedHex: string;
+
+  // Si le résultat dépend de ce qui se trouve sous l'arrière-plan
+  // If the result depends on what is underneath the background
+  dependsOnBackdrop: boolean;
+
+  // Texte adjacent au lien et résultat G183 / F73
+  // Text adjacent to the link and G183 / F73 result
+  adjacentHex: string | null;
+  linkContrast: LinkContrast | null;
+
+  // Autres arrière-plans sous le premier plan et pire cas
+  // Other backgrounds under the foreground and worst case
+  surfaces: Surface[];
+  surfaceReport: SurfaceReport | null;
+
+  // Différences premier plan / arrière-plan, et de chaque case avec la référence
+  // Foreground / background differences, and of each slot with the reference
+  referenceHex: string | null;
+  colorDifference: ColorDifference | null;
+  referenceDifferences: ReferenceDifference[];
+
   // Contrast Ratio Rounded
   contrastRatio: string;
 
+  // Contraste APCA (Lc) du premier plan sur l'arrière-plan, et inverse
+  // APCA contrast (Lc) of foreground on background, and reverse
+  apcaLc: string;
+  apcaLcReverse: string;
+
+  // Polarité APCA / APCA polarity
+  apcaPolarity: string;
+
+  // Contraste après gamut mapping / Contrast after gamut mapping
+  contrastRatioMapped: string;
+  apcaLcMapped: string;
+
 
   // Profil ICC actuellement sélectionné
   // Currently selected ICC profile
   currentICCProfile: string;
 
 
+  // Profil de norme actif / Active standards profile
+  profileId: string;
+  profileName: string;
+
+  // Résultats des critères (backend) / Criteria results (backend)
+  compliance: CriterionResult[];
+
+  // Seuil d'un critère du profil actif, null s'il n'en fait pas partie
+  // Threshold of a criterion of the active profile, null if not part of it
+  threshold(id: string, content: Criterion['content']): number | null;
+
+  // Si le profil actif contient le critère / If the active profile contains the criterion
+  hasCriterion(id: string): boolean;
+
+  // Si le critère s'applique au texte testé / If the criterion applies to the tested text
+  applies(id: string, content: Criterion['content']): boolean;
+
+  // Texte testé (null si inconnu) / Tested text (null if unknown)
+  textSpec: TextSpec | null;
+
+  // Tailles de police minimales APCA / APCA minimum font sizes
+  apcaFonts: ApcaFontReport | null;
+
   // WCAG Levels
   level143Regular: boolean;
   level143Large: boolean;
@@ -120,6 +652,23 @@ export interface UIStore {
   // Method to launch the color picker
   pickColor(fg: boolean): Promise<void>;
 
+  // Méthodes pour sélectionner ou vider le texte adjacent au lien
+  // Methods to pick or clear the text adjacent to the link
+  pickAdjacentText(): Promise<void>;
+  clearAdjacentText(): Promise<void>;
+
+  // Méthodes pour ajouter (saisie CSS ou sélection), retirer ou vider les surfaces
+  // Methods to add (CSS entry or pick), remove or clear the surfaces
+  addSurface(value: string): Promise<ColorParseError | null>;
+  pickSurface(): Promise<void>;
+  removeSurface(index: number): Promise<void>;
+  clearSurfaces(): Promise<void>;
+
+  // Méthodes pour définir (saisie CSS) ou vider la couleur de référence
+  // Methods to set (CSS entry) or clear the reference colour
+  setReference(value: string): Promise<ColorParseError | null>;
+  clearReference(): Promise<void>;
+
   // Méthode pour intervertir les couleurs de premier plan et d'arrière-plan
   // Method to swap foreground and background colors
   switchColor(): Promise<void>;
@@ -128,11 +677,91 @@ export interface UIStore {
   // Method to update an RGB component of a color
   updateColor(key: string, component: 'r' | 'g' | 'b', value: number): Promise<void>;
 
+  // Méthode pour mettre à jour l'opacité d'une couleur
+  // Method to update the opacity of a colour
+  updateAlpha(key: string, value: number): Promise<void>;
+
+  // Méthode pour définir une couleur depuis une saisie libre CSS Color 4
+  // Method to set a colour from CSS Color 4 free text entry
+  setColorFromString(key: string, value: string): Promise<ColorParseError | null>;
+
+  // Méthode pour définir le texte testé (forme abrégée CSS, vide pour effacer)
+  // Method to set the tested text (CSS shorthand, empty to clear)
+  setTextSpec(css: string): Promise<TextSpecError | null>;
+
+  // Méthode pour simuler les déficiences de la vision des couleurs
+  // Method to simulate colour vision deficiencies
+  simulateCvd(severity?: number): Promise<CvdReport>;
+
+  // Méthodes pour proposer et appliquer une correction du contraste
+  // Methods to suggest and apply a contrast repair
+  suggestFixes(target: FixTarget): Promise<FixSuggestions>;
+  applySuggestion(suggestion: FixSuggestion): Promise<void>;
+
+  // Méthode pour proposer les jetons les plus proches (seuil du profil actif par défaut)
+  // Method to suggest the nearest tokens (active profile threshold by default)
+  suggestTokens(target?: FixTarget): Promise<TokenSuggestions>;
+
+  // Méthodes pour dériver ou vérifier la paire du thème sombre
+  // Methods to derive or verify the dark theme pair
+  deriveDarkMode(): Promise<DarkModeReport>;
+  verifyDarkMode(foreground: string, background: string): Promise<DarkModeReport>;
+
+  // Méthode pour charger une image (fichier ou presse-papiers) avant les analyses de zone
+  // Method to load an image (file or clipboard) before the region analyses
+  loadImage(image: Blob): Promise<void>;
+
+  // Méthode pour analyser l'image chargée sous le premier plan
+  // Method to analyse the loaded image under the foreground
+  analyzeImage(useWorst?: boolean): Promise<RegionReport>;
+
+  // Méthode pour détecter le texte et le fond de l'image chargée et remplir les deux couleurs
+  // Method to detect the text and background of the loaded image and fill both colours
+  detectImage(): Promise<Detection>;
+
+  // Méthode pour calculer le voile minimal entre l'image chargée et le premier plan
+  // Method to calculate the minimum overlay between the loaded image and the foreground
+  scrimImage(overlay: string, gradient?: GradientDirection): Promise<ScrimReport>;
+
+  // Méthode pour mesurer le contraste du premier plan le long d'un dégradé CSS
+  // Method to measure the foreground contrast along a CSS gradient
+  gradientContrast(gradient: string): Promise<GradientReport>;
+
+  // Méthode pour traduire une erreur de dégradé dans la locale courante
+  // Method to translate a gradient error into the current locale
+  gradientErrorMessage(error: GradientError): string;
+
+  // Méthode pour épreuver la paire avec le profil de sortie chargé (null sans profil)
+  // Method to proof the pair with the loaded output profile (null without a profile)
+  softProof(simulatePaper?: boolean): Promise<SoftProofReport | null>;
+
+  // Méthodes de la matrice des états du composant (saisies CSS, couleurs du store si absentes)
+  // Component state matrix methods (CSS entries, store colours if absent)
+  setComponentState(name: string, foreground?: string, background?: string, indicator?: string, placement?: IndicatorPlacement): Promise<StateMatrix>;
+  removeComponentState(name: string): Promise<StateMatrix>;
+  getStateMatrix(): Promise<StateMatrix>;
+  exportStateMatrix(path?: string): Promise<string>;
+
+  // Méthode pour recalculer les noms de couleur dans la locale courante
+  // Method to recompute the colour names in the current locale
+  refreshColorNames(): Promise<void>;
+
   // Méthode pour mettre à jour le store Alpine depuis le store Tauri
   // Method to update Alpine store from Tauri store
   updateFromTauriStore(store: BackendStore): void;
 }
 
+// Décode une image en pixels RGBA pour l'analyse de zone
+// Decodes an image into RGBA pixels for region analysis
+async function decodeImage(image: Blob): Promise<{ width: number; height: number; pixels: ArrayBuffer }> {
+  const bitmap = await createImageBitmap(image);
+  const canvas = new OffscreenCanvas(bitmap.width, bitmap.height);
+  const context = canvas.getContext('2d')!;
+  context.drawImage(bitmap, 0, 0);
+  const { data } = context.getImageData(0, 0, bitmap.width, bitmap.height);
+  return { width: bitmap.width, height: bitmap.height, pixels: data.buffer };
+}
+
 // =============================================================================
 // CONFIGURATION DU STORE
 // STORE CONFIGURATION
@@ -178,6 +807,9 @@ export const UIStore = {
   // CSS name of foreground color
   foregroundName: '',
 
+  // Nom le plus proche dans les dictionnaires / Nearest name in the dictionaries
+  foregroundNearest: null,
+
   /// Si la couleur est sombre
   /// If the colour is dark
   foregroundIsDark: true,
@@ -194,19 +826,73 @@ export const UIStore = {
   // CSS name of background color
   backgroundName: '',
 
+  // Nom le plus proche dans les dictionnaires / Nearest name in the dictionaries
+  backgroundNearest: null,
+
   /// Si la couleur est sombre
   /// If the colour is dark
   backgroundIsDark: false,
 
+  // État initial : modèles non encore reçus du backend
+  // Initial state: models not yet received from the backend
+  foregroundModels: null,
+  backgroundModels: null,
+  foregroundPrecise: null,
+  backgroundPrecise: null,
+  foregroundOutOfSrgb: false,
+  backgroundOutOfSrgb: false,
+
+  // État initial : couleurs opaques sur une page blanche
+  // Initial state: opaque colours on a white page
+  foregroundAlpha: 1,
+  backgroundAlpha: 1,
+  pageHex: '',
+  foregroundCompositedHex: '',
+  backgroundCompositedHex: '',
+  dependsOnBackdrop: false,
+  adjacentHex: null,
+  linkContrast: null,
+  surfaces: [],
+  surfaceReport: null,
+  referenceHex: null,
+  colorDifference: null,
+  referenceDifferences: [],
+
   // Initial state: Contrast ratio
   contrastRatio: '0',
 
+  // État initial : contraste APCA / Initial state: APCA contrast
+  apcaLc: '0',
+  apcaLcReverse: '0',
+  contrastRatioMapped: '0',
+  apcaLcMapped: '0',
+  apcaPolarity: 'dark-on-light',
+
 
   // État initial : profil ICC par défaut (Auto)
   // Initial state: default ICC profile (Auto)
   currentICCProfile: 'Auto',
 
 
+  profileId: 'wcag22',
+  profileName: 'WCAG 2.2',
+  compliance: [],
+
+  threshold(this: UIStore, id: string, content: Criterion['content']) {
+    return this.compliance.find((r) => r.id === id && r.content === content)?.threshold ?? null;
+  },
+
+  hasCriterion(this: UIStore, id: string) {
+    return this.compliance.some((r) => r.id === id);
+  },
+
+  applies(this: UIStore, id: string, content: Criterion['content']) {
+    return this.compliance.some((r) => r.id === id && r.content === content && r.applies);
+  },
+
+  textSpec: null,
+  apcaFonts: null,
+
   // WCAG Levels
   level143Regular: true,
   level143Large: true,
@@ -240,6 +926,79 @@ export const UIStore = {
     }
   },
 
+  // Méthode pour sélectionner le texte adjacent au lien (premier plan)
+  // Method to pick the text adjacent to the link (foreground)
+  async pickAdjacentText(this: UIStore) {
+    this.isPicking = true;
+    try {
+      await invoke('pick_color', { fg: true, adjacent: true });
+    } catch (error) {
+      console.error('Error:', error);
+    } finally {
+      this.isPicking = false;
+    }
+  },
+
+  // Méthode pour vider le texte adjacent / Method to clear the adjacent text
+  async clearAdjacentText(this: UIStore) {
+    try {
+      await invoke('clear_adjacent_text');
+    } catch (error) {
+      console.error('Error clearing adjacent text:', error);
+    }
+  },
+
+  // Méthode pour ajouter une surface depuis une saisie CSS Color 4
+  // Method to add a surface from a CSS Color 4 entry
+  async addSurface(this: UIStore, value: string) {
+    return this.setColorFromString('surface', value);
+  },
+
+  // Méthode pour sélectionner une surface (arrière-plan) à l'écran
+  // Method to pick a surface (background) on screen
+  async pickSurface(this: UIStore) {
+    this.isPicking = true;
+    try {
+      await invoke('pick_color', { fg: false, surface: true });
+    } catch (error) {
+      console.error('Error:', error);
+    } finally {
+      this.isPicking = false;
+    }
+  },
+
+  // Méthodes pour retirer une surface ou les vider toutes
+  // Methods to remove one surface or clear them all
+  async removeSurface(this: UIStore, index: number) {
+    try {
+      await invoke('remove_surface', { index });
+    } catch (error) {
+      console.error('Error removing surface:', error);
+    }
+  },
+
+  async clearSurfaces(this: UIStore) {
+    try {
+      await invoke('clear_surfaces');
+    } catch (error) {
+      console.error('Error clearing surfaces:', error);
+    }
+  },
+
+  // Méthode pour définir la couleur de référence depuis une saisie CSS Color 4
+  // Method to set the reference colour from a CSS Color 4 entry
+  async setReference(this: UIStore, value: string) {
+    return this.setColorFromString('reference', value);
+  },
+
+  async clearReference(this: UIStore) {
+    try {
+      await invoke('clear_reference_color');
+    } catch (error) {
+      console.error('Error clearing reference colour:', error);
+    }
+  },
+
   // Méthode pour intervertir les couleurs de premier plan et d'arrière-plan
   // Method to swap foreground and background colors
   async switchColor(this: UIStore) {
@@ -252,15 +1011,17 @@ export const UIStore = {
     // Parse RGB values from "r, g, b" strings
     const [fr, fg, fb] = fgRgb.split(',').map(v => parseInt(v.trim()));
     const [br, bg, bb] = bgRgb.split(',').map(v => parseInt(v.trim()));
+    const fa = this.foregroundAlpha;
+    const ba = this.backgroundAlpha;
 
     try {
       // Met à jour le foreground avec les anciennes valeurs du background
       // Update foreground with old background values
-      await invoke('update_store', { key: 'foreground', r: br, g: bg, b: bb });
+      await invoke('update_store', { key: 'foreground', r: br, g: bg, b: bb, a: ba });
 
       // Met à jour le background avec les anciennes valeurs du foreground
       // Update background with old foreground values
-      await invoke('update_store', { key: 'background', r: fr, g: fg, b: fb });
+      await invoke('update_store', { key: 'background', r: fr, g: fg, b: fb, a: fa });
     } catch (error) {
       console.error('Error switching colors:', error);
     }
@@ -279,6 +1040,174 @@ export const UIStore = {
     }
   },
 
+  // Méthode pour mettre à jour l'opacité d'une couleur
+  // Method to update the opacity of a colour
+  async updateAlpha(this: UIStore, key: string, value: number) {
+    const rgb = key === 'foreground' ? this.foregroundRgb : this.backgroundRgb;
+    const [r, g, b] = rgb.split(',').map(v => parseInt(v.trim()));
+    try {
+      await invoke('update_store', { key, r, g, b, a: value });
+    } catch (error) {
+      console.error('Error updating alpha:', error);
+    }
+  },
+
+  // Méthode pour définir une couleur depuis une saisie libre CSS Color 4
+  // Retourne l'erreur d'analyse (avec sa position) ou null
+  // Method to set a colour from CSS Color 4 free text entry
+  // Returns the parse error (with its position) or null
+  async setColorFromString(this: UIStore, key: string, value: string) {
+    try {
+      await invoke('set_color_from_string', { key, value });
+      return null;
+    } catch (error) {
+      return error as ColorParseError;
+    }
+  },
+
+  // Méthode pour définir le texte testé (forme abrégée CSS, vide pour effacer)
+  // Method to set the tested text (CSS shorthand, empty to clear)
+  async setTextSpec(this: UIStore, css: string) {
+    try {
+      await invoke('set_text_spec', { css: css.trim() === '' ? null : css });
+      return null;
+    } catch (error) {
+      return error as TextSpecError;
+    }
+  },
+
+  // Méthode pour simuler les déficiences de la vision des couleurs
+  // Method to simulate colour vision deficiencies
+  async simulateCvd(this: UIStore, severity?: number) {
+    return invoke<CvdReport>('simulate_cvd', { severity });
+  },
+
+  // Méthode pour proposer les couleurs les plus proches qui atteignent la cible
+  // Method to suggest the closest colours reaching the target
+  async suggestFixes(this: UIStore, target: FixTarget) {
+    return invoke<FixSuggestions>('suggest_fixes', { target });
+  },
+
+  // Méthode pour appliquer une suggestion en un clic
+  // Method to apply a suggestion in one click
+  async applySuggestion(this: UIStore, suggestion: FixSuggestion) {
+    const [r, g, b] = suggestion.rgb;
+    try {
+      await invoke('update_store', { key: suggestion.key, r, g, b });
+    } catch (error) {
+      console.error('Error applying suggestion:', error);
+    }
+  },
+
+  // Méthode pour proposer les jetons les plus proches ; les erreurs sont propagées
+  // Method to suggest the nearest tokens; errors are propagated
+  async suggestTokens(this: UIStore, target?: FixTarget) {
+    return invoke<TokenSuggestions>('suggest_tokens', { target });
+  },
+
+  // Méthodes du thème sombre ; les erreurs de saisie (ColorParseError) sont propagées
+  // Dark theme methods; entry errors (ColorParseError) are propagated
+  async deriveDarkMode(this: UIStore) {
+    return invoke<DarkModeReport>('derive_dark_mode');
+  },
+
+  async verifyDarkMode(this: UIStore, foreground: string, background: string) {
+    return invoke<DarkModeReport>('verify_dark_mode', { foreground, background });
+  },
+
+  async loadImage(this: UIStore, image: Blob) {
+    // Octets bruts plutôt qu'un tableau JSON ; la taille passe par les en-têtes
+    // Raw bytes rather than a JSON array; the size goes through the headers
+    const { width, height, pixels } = await decodeImage(image);
+    await invoke('load_region_image', pixels, { headers: { width: String(width), height: String(height) } });
+  },
+
+  async analyzeImage(this: UIStore, useWorst?: boolean) {
+    return invoke<RegionReport>('analyze_region', { useWorst });
+  },
+
+  async detectImage(this: UIStore) {
+    return invoke<Detection>('detect_colors');
+  },
+
+  async scrimImage(this: UIStore, overlay: string, gradient?: GradientDirection) {
+    // Le premier plan garde son opacité / The foreground keeps its opacity
+    const text = `${this.foregroundHex}${Math.round(this.foregroundAlpha * 255).toString(16).padStart(2, '0')}`;
+    return invoke<ScrimReport>('scrim_opacity', { text, overlay, gradient });
+  },
+
+  async gradientContrast(this: UIStore, gradient: string) {
+    return invoke<GradientReport>('gradient_contrast', { gradient });
+  },
+
+  gradientErrorMessage(this: UIStore, error: GradientError) {
+    const message = this.t(`results.gradient_errors.${error.kind.replace(/-/g, '_')}`);
+    if (!('value' in error)) {
+      return message;
+    }
+    // Positions de caractère à partir de 1 / Character positions from 1
+    if (error.kind === 'invalid-color') {
+      return message.replace('{start}', String(error.value.start + 1)).replace('{end}', String(error.value.end));
+    }
+    return message.replace('{value}', error.value);
+  },
+
+  async softProof(this: UIStore, simulatePaper?: boolean) {
+    return invoke<SoftProofReport | null>('soft_proof', { simulatePaper });
+  },
+
+  // Méthodes de la matrice des états du composant ; les erreurs (StateError) sont propagées
+  // Component state matrix methods; errors (StateError) are propagated
+  async setComponentState(this: UIStore, name: string, foreground?: string, background?: string, indicator?: string, placement?: IndicatorPlacement) {
+    return invoke<StateMatrix>('set_component_state', { name, foreground, background, indicator, placement });
+  },
+
+  async removeComponentState(this: UIStore, name: string) {
+    return invoke<StateMatrix>('remove_component_state', { name });
+  },
+
+  async getStateMatrix(this: UIStore) {
+    return invoke<StateMatrix>('get_state_matrix');
+  },
+
+  async exportStateMatrix(this: UIStore, path?: string) {
+    return invoke<string>('export_state_matrix', { path });
+  },
+
+  // Méthode pour recalculer les noms de couleur dans la locale courante
+  // Method to recompute the colour names in the current locale
+  async refreshColorNames(this: UIStore) {
+    const locale = this.locale;
+    const foregroundHex = this.foregroundHex;
+    const backgroundHex = this.backgroundHex;
+
+    // Nom CSS exact (sinon descriptif) et nom le plus proche, dictionnaires de la locale d'abord
+    // Exact CSS name (otherwise descriptive) and nearest name, locale dictionaries first
+    const names = (value: string) => {
+      const [r, g, b] = value.split(',').map((c) => Number(c.trim()));
+      return Promise.all([
+        invoke<string>('get_color_name', { r, g, b, locale }),
+        invoke<NearestName[]>('nearest_color_names', { r, g, b, locale }).then((nearest) => nearest[0] ?? null),
+      ]);
+    };
+    const [[foregroundName, foregroundNearest], [backgroundName, backgroundNearest]] =
+      await Promise.all([names(this.foregroundRgb), names(this.backgroundRgb)]);
+
+    // Une réponse arrivée après un autre choix de couleur ou de langue est ignorée
+    // A response arriving after another colour or language choice is dropped
+    if (this.locale !== locale) {
+      return;
+    }
+    if (this.foregroundHex === foregroundHex) {
+      this.foregroundName = foregroundName;
+      this.foregroundNearest = foregroundNearest;
+    }
+    if (this.backgroundHex === backgroundHex) {
+      this.backgroundName = backgroundName;
+      this.backgroundNearest = backgroundNearest;
+    }
+  },
+
   // Méthode pour synchroniser le store Alpine avec le store Tauri
   // Method to synchronize Alpine store with Tauri store
   updateFromTauriStore(this: UIStore, store: BackendStore) {
@@ -296,11 +1225,6 @@ export const UIStore = {
     // Update foreground color (hex format)
     this.foregroundHex = store.foreground_hex;
 
-    // Recherche le nom CSS exact / Look up exact CSS name
-    invoke<string>('get_color_name', { r: fr, g: fg, b: fb }).then((name) => {
-      this.foregroundName = name;
-    });
-
     /// Si la couleur est sombre
     /// If the colour is dark
     this.foregroundIsDark = store.foreground_is_dark;
@@ -317,34 +1241,70 @@ export const UIStore = {
     // Update background color (hex format)
     this.backgroundHex = store.background_hex;
 
-    // Recherche le nom CSS exact / Look up exact CSS name
-    invoke<string>('get_color_name', { r: br, g: bg, b: bb }).then((name) => {
-      this.backgroundName = name;
-    });
-
     /// Si la couleur est sombre
     /// If the colour is dark
     this.backgroundIsDark = store.background_is_dark;
 
-    this.contrastRatio = `${store.contrast_ratio_rounded}`;
+    // Noms des deux couleurs, sans attendre / Names of both colours, without waiting
+    this.refreshColorNames();
+
+    // Couleurs dans tous les modèles
+    // Colours in every model
+    this.foregroundModels = store.foreground_models;
+    this.backgroundModels = store.background_models;
+
+    // Valeurs exactes et gamut / Exact values and gamut
+    this.foregroundPrecise = store.foreground_precise;
+    this.backgroundPrecise = store.background_precise;
+    this.foregroundOutOfSrgb = store.foreground_out_of_srgb;
+    this.backgroundOutOfSrgb = store.background_out_of_srgb;
+
+    // Opacité et couleurs composées
+    // Opacity and composited colours
+    this.foregroundAlpha = store.foreground_alpha;
+    this.backgroundAlpha = store.background_alpha;
+    this.pageHex = store.page_hex;
+    this.foregroundCompositedHex = store.foreground_composited_hex;
+    this.backgroundCompositedHex = store.background_composited_hex;
+    this.dependsOnBackdrop = store.depends_on_backdrop;
+    this.adjacentHex = store.adjacent_hex;
+    this.linkContrast = store.link_contrast;
+    this.surfaces = store.surfaces;
+    this.surfaceReport = store.surface_report;
+    this.referenceHex = store.reference_hex;
+    this.colorDifference = store.color_difference;
+    this.referenceDifferences = store.reference_differences;
 
-    // Update WCAG Level rules, based on contrast ratio
-    this.level143Regular = true;
-    this.level143Large = true;
-    this.level146Regular = true;
-    this.level146Large = true;
-    this.level1411 = true;
+    this.contrastRatio = `${store.contrast_ratio_rounded}`;
 
-    if (store.contrast_ratio_rounded < 7) {
-      this.level146Regular = false;
-    }
-    if (store.contrast_ratio_rounded < 4.5) {
-      this.level143Regular = false;
-      this.level146Large = false;
-    }
-    if (store.contrast_ratio_rounded < 3) {
-      this.level143Large = false;
-      this.level1411 = false;
+    // Contraste APCA / APCA contrast
+    this.apcaLc = `${store.apca_lc_rounded}`;
+    this.apcaLcReverse = `${store.apca_lc_reverse_rounded}`;
+    this.contrastRatioMapped = `${store.contrast_ratio_mapped_rounded}`;
+    this.apcaLcMapped = `${store.apca_lc_mapped_rounded}`;
+    this.apcaPolarity = store.apca_polarity;
+
+    // Niveaux évalués par le backend avec le ratio non arrondi et le profil actif
+    // Levels evaluated by the backend with the unrounded ratio and the active profile
+    if (store.profile_id !== this.profileId) {
+      // Le nom est résolu à partir de la liste des profils, seul l'identifiant est émis
+      // The name is resolved from the profile list, only the id is emitted
+      this.profileId = store.profile_id;
+      invoke<StandardsProfile[]>('list_standards_profiles')
+        .then((profiles) => {
+          this.profileName = profiles.find((p) => p.id === store.profile_id)?.name ?? store.profile_id;
+        })
+        .catch((error: unknown) => console.error('Error listing standards profiles:', error));
     }
+    this.textSpec = store.text_spec;
+    this.apcaFonts = store.apca_fonts;
+    this.compliance = store.compliance;
+    const passes = (id: string, content: CriterionResult['content']) =>
+      store.compliance.some((r) => r.id === id && r.content === content && r.pass);
+    this.level143Regular = passes('1.4.3', 'regular');
+    this.level143Large = passes('1.4.3', 'large');
+    this.level146Regular = passes('1.4.6', 'regular');
+    this.level146Large = passes('1.4.6', 'large');
+    this.level1411 = passes('1.4.11', 'non-text');
   }
 };
\ No newline at end of file